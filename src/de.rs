@@ -24,6 +24,14 @@
 //! Similarly, a `Vec<(...)>`, works as well, as long as the tuple
 //! members match the field list of the resultset.
 //!
+//! Structs get the values of a row as map entries, keyed by the column names,
+//! so the order of the columns does not matter, and attributes like `#[serde(alias)]`,
+//! `#[serde(default)]` and `#[serde(flatten)]` work as usual.
+//! Tuples get the values by position.
+//! Since serde implements `Deserialize` only for tuples with up to 16 elements,
+//! wider rows are deserialized into structs, arrays `[T; N]` (with up to 32 elements),
+//! or, when converting individual rows, into a `Vec<T>`.
+//!
 //! In addition, `serde_db` also supports structural simplification:
 //! depending on the dimension of the resultset, simplified target types can be
 //! chosen for deserialization:
@@ -66,6 +74,13 @@
 //! let vec_s: Vec<String> = resultset.try_into()?;
 //! ```
 //!
+//! Other collections, like `VecDeque`, `BinaryHeap`, or sets, work as well,
+//! e.g. for `SELECT DISTINCT` queries:
+//!
+//! ```rust,ignore
+//! let ids: std::collections::BTreeSet<i64> = resultset.try_into()?;
+//! ```
+//!
 //! ## Convert a 1×1 resultset into a single field:
 //!
 //! ```rust,ignore
@@ -73,6 +88,34 @@
 //! let s: String = resultset.try_into()?;
 //! ```
 //!
+//! ## Convert a resultset into a struct of Vecs:
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize)]
+//! struct MyColumns {
+//!   // fields ... are Vecs and should match the columns of the resultset
+//! }
+//!
+//! let data: MyColumns = resultset.try_into_columns()?;
+//! ```
+//!
+//! With feature `arrow`, a resultset can also be converted column by column into an
+//! Arrow `RecordBatch`, and with feature `polars` into a Polars `DataFrame`,
+//! without a target struct; the column types are then derived from the values:
+//!
+//! ```rust,ignore
+//! let batch: arrow::record_batch::RecordBatch = resultset.into_record_batch()?;
+//! let df: polars::frame::DataFrame = other_resultset.into_dataframe()?;
+//! ```
+//!
+//! ## Convert only some columns of a resultset:
+//!
+//! ```rust,ignore
+//! // the other columns are dropped without being converted
+//! let projection = resultset.project(&["ID", "NAME"])?;
+//! let data: Vec<MyNarrowStruct> = DeserializableResultset::try_into(projection)?;
+//! ```
+//!
 //! # Rows
 //!
 //! ## Convert rows into tuples or structs
@@ -93,6 +136,35 @@
 //! }
 //! ```
 //!
+//! ## Convert rows into internally tagged enums
+//!
+//! Rows can also be deserialized into enums with `#[serde(tag = "...")]`,
+//! where the named column contains the variant name and the other columns fill
+//! the variant struct; this is the natural mapping for single-table-inheritance schemas:
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize)]
+//! #[serde(tag = "KIND")]
+//! enum Party {
+//!     Person { NAME: String, NICKNAME: Option<String> },
+//!     Organization { NAME: String, CITY: String },
+//! }
+//! let parties: Vec<Party> = resultset.try_into()?;
+//! ```
+//!
+//! Structs with `#[serde(flatten)]` fields are supported as well.
+//! The columns that serde buffers for tagged enums and flattened fields are converted
+//! with `deserialize_any()`.
+//!
+//! Or, without intermediate collects, with an iterator that converts the rows lazily:
+//!
+//! ```rust,ignore
+//! let total: i64 = resultset
+//!     .typed_rows::<MyStruct>()
+//!     .map(|row| row.map(|data| data.amount))
+//!     .sum::<Result<i64, _>>()?;
+//! ```
+//!
 //! # Individual values
 //!
 //! When necessary, you can also convert individual values directly into an adequate rust type:
@@ -105,6 +177,18 @@
 //! }
 //! ```
 //!
+//! Text values can also be converted into `Cow<str>`, also with `#[serde(borrow)]`.
+//! Since the rows own their values, the text is then moved into a `Cow::Owned`,
+//! without being copied; targets of type `&str` are not supported.
+//!
+//! With feature `secrecy`, credentials can be deserialized directly into
+//! `secrecy::SecretString` or `SecretVec<u8>`, also as fields of a row struct,
+//! and every `DbValue` implements `DbValueInto` for these types;
+//! fields of type `SecretVec<u8>` need
+//! `#[serde(deserialize_with = "serde_db::de::deserialize_secret_bytes")]`.
+//! The secrets take over the text or the bytes that the driver provides; use [`DeserializationOptions::with_sensitive_columns()`](struct.DeserializationOptions.html#method.with_sensitive_columns)
+//! to keep the values of such columns also out of error messages.
+//!
 //! # Note for implementors
 //!
 //! Implementing DB drivers need
@@ -114,26 +198,174 @@
 //! (an example can be found in the tests of this crate), depending on the flexibility
 //! you want to offer.
 //!
+//! Drivers can also use the generic [`Row`](struct.Row.html) instead of implementing
+//! their own row type, and the generic [`BufferedResultset`](struct.BufferedResultset.html),
+//! for which they only need to implement the fetching of the next chunk of rows
+//! with [`FetchChunks`](trait.FetchChunks.html).
+//!
 //! We further recommend adding a method like `try_into()` directly on the
 //! driver's class for resultsets with a plain delegation to the _provided_ method
 //! [`DeserializableResultset::try_into()`](trait.DeserializableResultset.html#method.try_into).
 //! The same should be done for rows.
 //! By this, the deserialization functionality of `serde_db` can be provided
 //! to the users of the DB driver without forcing them to import `serde_db`.
+//!
+//! Code that is generic over drivers can use the free functions
+//! [`from_resultset()`](fn.from_resultset.html), [`from_row()`](fn.from_row.html),
+//! and [`from_value()`](fn.from_value.html) instead.
+//!
+//! When the columns of a resultset do not fit to a struct as expected,
+//! [`mapping_report()`](fn.mapping_report.html) shows how each column would be bound,
+//! without converting any values.
+//!
+//! Applications that only pass the data on, like services that return query results as JSON,
+//! can write a resultset with any `serde::Serializer`, without intermediate structs,
+//! using [`transcode()`](fn.transcode.html), or hand it to serialization functions like
+//! `serde_json::to_string()` as a [`SerializableResultset`](struct.SerializableResultset.html).
+//!
+//! Dump tools can write a resultset as CSV with [`write_csv()`](fn.write_csv.html).
+//!
+//! Tools that work with several drivers can hold resultsets type-erased as
+//! `Box<dyn DynResultset<V>>`, see [`DynResultset`](trait.DynResultset.html).
+//!
+//! Drivers whose statements can return several resultsets, like calls of stored procedures,
+//! can implement [`DeserializableMultiResultset`](trait.DeserializableMultiResultset.html),
+//! so that all resultsets can be converted into a tuple like `(Vec<Header>, Vec<Line>)`
+//! in a single call.
+//!
+//! Drivers that support output parameters of procedure calls can implement
+//! [`DeserializableOutputParameters`](trait.DeserializableOutputParameters.html),
+//! which converts them with the same machinery as rows.
+//!
+//! Drivers that support array columns should implement
+//! [`DbValue::is_array()`](trait.DbValue.html#method.is_array) and
+//! [`DbValue::into_array()`](trait.DbValue.html#method.into_array), so that
+//! such columns can be deserialized into fields of type `Vec<T>`.
+//!
+//! Drivers whose values can contain resultsets, like nested tables, should implement
+//! [`DbValue::is_nested_resultset()`](trait.DbValue.html#method.is_nested_resultset) and
+//! [`DbValue::into_nested_resultset()`](trait.DbValue.html#method.into_nested_resultset).
+//!
+//! Drivers that fetch rows asynchronously can implement
+//! [`ChunkedResultset`](trait.ChunkedResultset.html), so that applications can consume
+//! typed rows from a [`RowStream`](struct.RowStream.html) with
+//! `while let Some(row) = stream.next().await`.
+//!
+//! Drivers that support large objects should implement
+//! [`DbValue::is_lob()`](trait.DbValue.html#method.is_lob) and
+//! [`DbValue::into_lob_reader()`](trait.DbValue.html#method.into_lob_reader), so that
+//! applications can stream them with [`stream_into()`](fn.stream_into.html).
+//! Drivers that fetch large objects asynchronously implement
+//! [`DbValue::is_async_lob()`](trait.DbValue.html#method.is_async_lob) and
+//! [`DbValue::into_async_lob_reader()`](trait.DbValue.html#method.into_async_lob_reader)
+//! instead; such values are converted with [`write_lob_async()`](fn.write_lob_async.html).
+//!
+//! Drivers that support decimal values should implement
+//! [`DbValue::decimal_parts()`](trait.DbValue.html#method.decimal_parts).
+//! With feature `bigdecimal`, every `DbValue` then also implements
+//! `DbValueInto<bigdecimal::BigDecimal>`.
+//!
+//! Drivers that support JSON columns should implement
+//! [`DbValue::is_json()`](trait.DbValue.html#method.is_json);
+//! with feature `json`, such values can then be deserialized into arbitrary nested types, like
+//! `#[derive(Deserialize)]` structs, `Vec`s or `HashMap`s, also as fields of a row struct.
 
+#[cfg(feature = "arrow")]
+mod arrow_conversion;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal_conversions;
+mod buffered_resultset;
+mod columns_deserializer;
 mod conversion_error;
+mod conversion_estimate;
+mod csv;
+mod db_interval;
+mod db_number;
 mod db_value;
 mod db_value_into;
+mod decimal_backend;
+mod decimal_parts;
+mod deserializable_multi_resultset;
+mod deserializable_output_parameters;
 mod deserializable_resultset;
 mod deserializable_row;
 mod deserialization_error;
+mod deserialization_observer;
+mod deserialization_options;
+mod dyn_resultset;
+mod entry_points;
+pub mod epoch;
 mod field_deserializer;
+mod in_place;
+#[cfg(feature = "json")]
+mod json_deserializer;
+mod lob;
+mod mapping_report;
+mod metadata_diff;
+mod multi_rs_deserializer;
+mod nested_resultset;
+#[cfg(feature = "polars")]
+mod polars_conversion;
+mod projection;
+mod row;
 mod row_deserializer;
+mod row_range;
+mod row_stream;
 mod rs_deserializer;
+#[cfg(feature = "secrecy")]
+mod secrecy_conversions;
+mod struct_fields;
+mod text_decoder;
+mod thread_safety;
+mod timezone;
+mod transcode;
+#[cfg(any(feature = "arrow", feature = "polars"))]
+mod typed_columns;
+mod typed_rows;
+mod validation;
+mod value_kind;
 
+pub use self::buffered_resultset::{BufferedResultset, FetchChunks};
 pub use self::conversion_error::ConversionError;
+pub use self::conversion_estimate::ConversionEstimate;
+pub use self::csv::{write_csv, write_csv_with};
+pub use self::db_interval::DbInterval;
+pub use self::db_number::{DbNumber, NumericTarget};
 pub use self::db_value::DbValue;
 pub use self::db_value_into::DbValueInto;
+pub use self::decimal_backend::DecimalBackend;
+pub use self::decimal_parts::DecimalParts;
+pub use self::deserializable_multi_resultset::DeserializableMultiResultset;
+pub use self::deserializable_output_parameters::DeserializableOutputParameters;
 pub use self::deserializable_resultset::DeserializableResultset;
 pub use self::deserializable_row::DeserializableRow;
 pub use self::deserialization_error::{DeserializationError, DeserializationResult};
+pub use self::deserialization_observer::{
+    DeserializationObserver, DeserializationWarning, NullEvent, NullOutcome, ResultsetMetrics,
+};
+pub use self::deserialization_options::{
+    BoolCoercion, CharPadding, DeserializationOptions, FieldNameMatching, FieldnameFallback,
+    NonFinitePolicy, NullPolicy, NumericPolicy, RoundingMode, TimezonePolicy, UnknownColumnPolicy,
+    Utf8Policy,
+};
+pub use self::dyn_resultset::{DynResultset, DynRow};
+pub use self::entry_points::{
+    from_resultset, from_resultset_with, from_row, from_row_with, from_value, from_value_with,
+};
+pub use self::lob::{stream_into, write_lob, write_lob_async, AsyncLobReader};
+pub use self::mapping_report::{mapping_report, Binding, ColumnBinding, MappingReport};
+pub use self::metadata_diff::{metadata_diff, MetadataDiff, RetypedColumn};
+pub use self::nested_resultset::{NestedResultset, NestedRow};
+pub use self::projection::Projection;
+pub use self::row::{Row, RowMetadata};
+pub use self::row_deserializer::ROW_INDEX_FIELD;
+pub use self::row_range::RowRange;
+pub use self::row_stream::{ChunkedResultset, RowStream};
+#[cfg(feature = "secrecy")]
+pub use self::secrecy_conversions::deserialize_secret_bytes;
+pub use self::struct_fields::{assert_struct_fields, struct_fields};
+pub use self::text_decoder::TextDecoder;
+pub use self::timezone::timezone_aware;
+pub use self::transcode::{transcode, transcode_with, SerializableResultset, SerializableRow};
+pub use self::typed_rows::TypedRows;
+pub use self::value_kind::ValueKind;
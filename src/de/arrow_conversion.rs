@@ -0,0 +1,33 @@
+use crate::de::typed_columns::TypedColumn;
+use crate::de::{DeserializationError, DeserializationResult};
+use arrow_array::{
+    ArrayRef, BinaryArray, BooleanArray, Float64Array, Int64Array, NullArray, RecordBatch,
+    RecordBatchOptions, StringArray,
+};
+use arrow_schema::{Field, Schema};
+use std::sync::Arc;
+
+// Builds a record batch from the typed columns of a resultset.
+pub fn record_batch(columns: Vec<(String, TypedColumn)>) -> DeserializationResult<RecordBatch> {
+    let rows = columns.first().map_or(0, |(_, column)| column.len());
+    let (fields, arrays): (Vec<Field>, Vec<ArrayRef>) = columns
+        .into_iter()
+        .map(|(name, column)| {
+            let array: ArrayRef = match column {
+                TypedColumn::Null(len) => Arc::new(NullArray::new(len)),
+                TypedColumn::Bool(values) => Arc::new(BooleanArray::from(values)),
+                TypedColumn::Int(values) => Arc::new(Int64Array::from(values)),
+                TypedColumn::Float(values) => Arc::new(Float64Array::from(values)),
+                TypedColumn::Text(values) => Arc::new(StringArray::from(values)),
+                TypedColumn::Bytes(values) => Arc::new(BinaryArray::from_iter(values)),
+            };
+            (Field::new(name, array.data_type().clone(), true), array)
+        })
+        .unzip();
+    RecordBatch::try_new_with_options(
+        Arc::new(Schema::new(fields)),
+        arrays,
+        &RecordBatchOptions::new().with_row_count(Some(rows)),
+    )
+    .map_err(|e| DeserializationError::Usage(format!("cannot build the record batch: {e}")))
+}
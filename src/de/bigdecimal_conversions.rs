@@ -0,0 +1,34 @@
+use crate::de::{ConversionError, DbValue, DbValueInto, DecimalBackend, DecimalParts};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+impl From<DecimalParts> for BigDecimal {
+    fn from(decimal: DecimalParts) -> Self {
+        BigDecimal::new(
+            BigInt::from(decimal.mantissa()),
+            -i64::from(decimal.exponent()),
+        )
+    }
+}
+
+impl DecimalBackend for BigDecimal {
+    fn from_decimal_parts(decimal: DecimalParts) -> Result<Self, ConversionError> {
+        Ok(decimal.into())
+    }
+
+    fn from_decimal_str(s: &str) -> Result<Self, ConversionError> {
+        BigDecimal::from_str(s.trim()).map_err(|e| {
+            ConversionError::ValueType(format!("cannot convert {s:?} into a BigDecimal: {e}"))
+        })
+    }
+}
+
+/// Converts any `DbValue` into a `BigDecimal`.
+///
+/// See [`DecimalBackend::from_db_value()`](trait.DecimalBackend.html#method.from_db_value).
+impl<DBV: DbValue> DbValueInto<BigDecimal> for DBV {
+    fn try_into(self) -> Result<BigDecimal, ConversionError> {
+        BigDecimal::from_db_value(self)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::de::{
+    DbValue, DeserializableResultset, DeserializationError, DeserializationResult, Row, RowMetadata,
+};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Fetches the rows of a [`BufferedResultset`] chunk by chunk.
+///
+/// Is implemented for closures of type `FnMut() -> DeserializationResult<Option<Vec<Vec<V>>>>`.
+pub trait FetchChunks {
+    /// The value type of the database driver.
+    type V: DbValue;
+
+    /// Fetches the next chunk of rows, each given by its values in column order,
+    /// and returns `None` at the end.
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    #[allow(clippy::type_complexity)]
+    fn fetch_next_chunk(&mut self) -> DeserializationResult<Option<Vec<Vec<Self::V>>>>;
+}
+
+impl<V, F> FetchChunks for F
+where
+    V: DbValue,
+    F: FnMut() -> DeserializationResult<Option<Vec<Vec<V>>>>,
+{
+    type V = V;
+
+    fn fetch_next_chunk(&mut self) -> DeserializationResult<Option<Vec<Vec<V>>>> {
+        self()
+    }
+}
+
+/// A generic resultset, which buffers the rows that are fetched in chunks,
+/// and implements [`DeserializableResultset`](trait.DeserializableResultset.html)
+/// with rows of type [`Row`].
+///
+/// Drivers only need to plug in the fetching of the next chunk:
+///
+/// ```rust,ignore
+/// let metadata = Arc::new(vec!["ID".to_string(), "NAME".to_string()]);
+/// let rs = BufferedResultset::new(metadata, move || connection.fetch_chunk(cursor_id));
+/// let data: Vec<(u32, String)> = rs.try_into()?;
+/// ```
+#[derive(Debug)]
+pub struct BufferedResultset<F: FetchChunks, MD = Vec<String>, E = DeserializationError> {
+    metadata: Arc<MD>,
+    fetcher: F,
+    buffer: VecDeque<Vec<F::V>>,
+    exhausted: bool,
+    _error: PhantomData<fn() -> E>,
+}
+
+impl<F: FetchChunks, MD: RowMetadata, E> BufferedResultset<F, MD, E> {
+    /// Creates a resultset with the given metadata, whose rows are fetched with `fetcher`.
+    pub fn new(metadata: Arc<MD>, fetcher: F) -> Self {
+        BufferedResultset {
+            metadata,
+            fetcher,
+            buffer: VecDeque::new(),
+            exhausted: false,
+            _error: PhantomData,
+        }
+    }
+
+    /// Creates a resultset whose first chunk of rows is already available.
+    pub fn with_first_chunk(metadata: Arc<MD>, first_chunk: Vec<Vec<F::V>>, fetcher: F) -> Self {
+        let mut rs = Self::new(metadata, fetcher);
+        rs.buffer.extend(first_chunk);
+        rs
+    }
+
+    /// Returns the metadata of the resultset.
+    #[must_use]
+    pub fn metadata(&self) -> &Arc<MD> {
+        &self.metadata
+    }
+
+    /// Returns true if all chunks were fetched.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.exhausted
+    }
+
+    // Fetches chunks until more than `n` rows are buffered, or all chunks are fetched.
+    fn fill_buffer(&mut self, n: usize) -> DeserializationResult<()> {
+        while !self.exhausted && self.buffer.len() <= n {
+            match self.fetcher.fetch_next_chunk()? {
+                Some(chunk) => self.buffer.extend(chunk),
+                None => self.exhausted = true,
+            }
+        }
+        Ok(())
+    }
+
+    fn to_row(&self, values: Vec<F::V>) -> Row<F::V, MD, E> {
+        Row::new(Arc::clone(&self.metadata), values)
+    }
+}
+
+impl<F, MD, E> DeserializableResultset for BufferedResultset<F, MD, E>
+where
+    F: FetchChunks,
+    MD: RowMetadata,
+    E: From<DeserializationError>,
+{
+    type E = E;
+    type ROW = Row<F::V, MD, E>;
+
+    fn has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+        self.fill_buffer(1)?;
+        Ok(self.buffer.len() > 1)
+    }
+
+    fn next(&mut self) -> DeserializationResult<Option<Self::ROW>> {
+        self.fill_buffer(0)?;
+        Ok(self.buffer.pop_front().map(|values| self.to_row(values)))
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.metadata.number_of_fields()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.metadata.fieldname(field_idx)
+    }
+
+    fn remaining_rows(&self) -> Option<usize> {
+        if self.exhausted {
+            Some(self.buffer.len())
+        } else {
+            None
+        }
+    }
+
+    fn has_no_rows(&mut self) -> DeserializationResult<bool> {
+        self.fill_buffer(0)?;
+        Ok(self.buffer.is_empty())
+    }
+
+    fn take_row(&mut self, idx: usize) -> DeserializationResult<Option<Self::ROW>> {
+        self.fill_buffer(idx)?;
+        Ok(self.buffer.remove(idx).map(|values| self.to_row(values)))
+    }
+}
@@ -0,0 +1,223 @@
+use crate::de::field_deserializer::ArrayElements;
+use crate::de::rs_deserializer::{add_row_bytes, check_row_limit};
+use crate::de::{
+    DbValue, DeserializableResultset, DeserializableRow, DeserializationError,
+    DeserializationOptions, DeserializationResult,
+};
+#[cfg(feature = "trace")]
+use log::trace;
+use serde::de::value::{SeqAccessDeserializer, StrDeserializer};
+use serde::de::Visitor;
+use serde::forward_to_deserialize_any;
+
+type Column<V> = (Option<String>, Vec<V>);
+
+// Deserialize a resultset column by column, into a struct of `Vec`s or a tuple of `Vec`s.
+#[derive(Debug)]
+pub struct ColumnsDeserializer<'o, V> {
+    columns: Vec<Column<V>>,
+    options: &'o DeserializationOptions,
+}
+
+impl<'o, V: DbValue> ColumnsDeserializer<'o, V> {
+    pub fn try_new<RS>(
+        mut rs: RS,
+        options: &'o DeserializationOptions,
+    ) -> DeserializationResult<Self>
+    where
+        RS: DeserializableResultset,
+        RS::ROW: DeserializableRow<V = V>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("ColumnsDeserializer::try_new()");
+        let capacity = rs.remaining_rows().unwrap_or_default();
+        let mut columns: Vec<Column<V>> = (0..rs.number_of_columns())
+            .map(|idx| {
+                (
+                    rs.fieldname(idx).map(ToString::to_string),
+                    Vec::with_capacity(capacity),
+                )
+            })
+            .collect();
+        let mut rows = 0;
+        let mut bytes = 0;
+        while let Some(mut row) = rs.next()? {
+            check_row_limit(options, rows)?;
+            add_row_bytes(options, &mut bytes, &row)?;
+            rows += 1;
+            for (_, values) in &mut columns {
+                values.push(row.next().ok_or_else(|| {
+                    DeserializationError::Usage(String::from("row is shorter than expected"))
+                })?);
+            }
+        }
+        Ok(ColumnsDeserializer { columns, options })
+    }
+
+    // Returns the names and the values of the columns.
+    #[cfg(any(feature = "arrow", feature = "polars"))]
+    pub fn into_columns(self) -> Vec<Column<V>> {
+        self.columns
+    }
+}
+
+// Returns the struct field with which the column is to be paired.
+fn matching_field(
+    options: &DeserializationOptions,
+    column: &str,
+    fields: &[&'static str],
+) -> Option<&'static str> {
+    if let Some(field) = fields.iter().find(|f| **f == column) {
+        return Some(field);
+    }
+    let matching = options.field_name_matching();
+    fields
+        .iter()
+        .copied()
+        .find(|field| matching.matches(column, field))
+}
+
+impl<'x, V: DbValue> serde::Deserializer<'x> for ColumnsDeserializer<'_, V> {
+    type Error = DeserializationError;
+
+    fn deserialize_any<W: Visitor<'x>>(self, _visitor: W) -> DeserializationResult<W::Value> {
+        Err(DeserializationError::Usage(String::from(
+            "columns can only be deserialized into structs or tuples of sequences",
+        )))
+    }
+
+    fn deserialize_struct<W: Visitor<'x>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: W,
+    ) -> DeserializationResult<W::Value> {
+        #[cfg(feature = "trace")]
+        trace!("ColumnsDeserializer::deserialize_struct()");
+        let mut entries = Vec::with_capacity(self.columns.len());
+        for (name, values) in self.columns {
+            let name = name.as_deref().ok_or_else(|| {
+                DeserializationError::Usage(String::from(
+                    "columns without names cannot be deserialized into a struct",
+                ))
+            })?;
+            let Some(field) = matching_field(self.options, name, fields) else {
+                return Err(DeserializationError::UnknownField(name.to_string()));
+            };
+            if self.options.is_in_field_mask(field) {
+                entries.push((field, name.to_string(), values));
+            }
+        }
+        visitor.visit_map(ColumnsMapVisitor {
+            entries: entries.into_iter(),
+            pending: None,
+            options: self.options,
+        })
+    }
+
+    fn deserialize_tuple<W: Visitor<'x>>(
+        self,
+        _len: usize,
+        visitor: W,
+    ) -> DeserializationResult<W::Value> {
+        #[cfg(feature = "trace")]
+        trace!("ColumnsDeserializer::deserialize_tuple()");
+        let columns = self.columns.into_iter().map(|(_, values)| values);
+        visitor.visit_seq(ColumnsSeqVisitor {
+            columns: columns.collect::<Vec<_>>().into_iter(),
+            options: self.options,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.options.is_human_readable()
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'x>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+// Hands out the columns as values of the struct fields.
+struct ColumnsMapVisitor<'o, V> {
+    entries: std::vec::IntoIter<(&'static str, String, Vec<V>)>,
+    pending: Option<(String, Vec<V>)>,
+    options: &'o DeserializationOptions,
+}
+
+impl<'x, V: DbValue> serde::de::MapAccess<'x> for ColumnsMapVisitor<'_, V> {
+    type Error = DeserializationError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> DeserializationResult<Option<K::Value>>
+    where
+        K: serde::de::DeserializeSeed<'x>,
+    {
+        match self.entries.next() {
+            None => Ok(None),
+            Some((field, column, values)) => {
+                self.pending = Some((column, values));
+                seed.deserialize(StrDeserializer::<DeserializationError>::new(field))
+                    .map(Some)
+            }
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> DeserializationResult<S::Value>
+    where
+        S: serde::de::DeserializeSeed<'x>,
+    {
+        let (column, values) = self
+            .pending
+            .take()
+            .ok_or(DeserializationError::NotImplemented(
+                "ColumnsMapVisitor::next_value_seed() without key",
+            ))?;
+        seed.deserialize(SeqAccessDeserializer::new(ArrayElements::new(
+            values,
+            self.options,
+        )))
+        .map_err(|e| {
+            if self.options.is_sensitive_column(&column) {
+                e.redacted(&column, std::any::type_name::<S::Value>())
+            } else {
+                e
+            }
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+// Hands out the columns as elements of a tuple.
+struct ColumnsSeqVisitor<'o, V> {
+    columns: std::vec::IntoIter<Vec<V>>,
+    options: &'o DeserializationOptions,
+}
+
+impl<'x, V: DbValue> serde::de::SeqAccess<'x> for ColumnsSeqVisitor<'_, V> {
+    type Error = DeserializationError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> DeserializationResult<Option<T::Value>>
+    where
+        T: serde::de::DeserializeSeed<'x>,
+    {
+        match self.columns.next() {
+            None => Ok(None),
+            Some(values) => seed
+                .deserialize(SeqAccessDeserializer::new(ArrayElements::new(
+                    values,
+                    self.options,
+                )))
+                .map(Some),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.columns.len())
+    }
+}
@@ -1,28 +1,73 @@
+use crate::message::{ErrorMessage, MessageFormatter};
 use thiserror::Error;
 
 /// An error type for implementors of `DbValue`.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum ConversionError {
-    /// The DbValue cannot be converted into the desired rust type.
+    /// The `DbValue` cannot be converted into the desired rust type.
     #[error(
         "The DbValue cannot be converted into the desired rust type: value types do not match"
     )]
     ValueType(String),
 
-    /// The DbValue is to big or too small (negative) for conversion into the desired rust type.
+    /// The `DbValue` is to big or too small (negative) for conversion into the desired rust type.
     #[error(
         "The DbValue is too big or too small for the desired rust type: number range exceeded"
     )]
     NumberRange(String),
 
-    /// The DbValue was not yet completely loaded, and further loading is not possible anymore.
+    /// The `DbValue` was not yet completely loaded, and further loading is not possible anymore.
     #[error(
         "The DbValue was not yet completely loaded, and further loading is not possible anymore"
     )]
     Incomplete(String),
 
+    /// The `DbValue` is a JSON value, but its content is not a valid JSON document.
+    #[error("The DbValue is not a valid JSON document: {0}")]
+    Json(String),
+
+    /// The `DbValue` is a text value, but it is not valid UTF-8.
+    #[error("The DbValue is not valid UTF-8: invalid sequence at byte offset {valid_up_to}")]
+    InvalidUtf8 {
+        /// The length of the valid prefix of the value, in bytes.
+        valid_up_to: usize,
+    },
+
+    /// The `DbValue` is NaN or infinite, and the target does not accept such values, due to the
+    /// [`NonFinitePolicy`](enum.NonFinitePolicy.html), or because the target is a decimal.
+    #[error("The DbValue is not a finite number: {0}")]
+    NonFinite(f64),
+
+    /// The `DbValue` is zero, and the desired rust type is a non-zero integer,
+    /// like `NonZeroU32`; contains the primitive type of the target.
+    #[error("The DbValue is zero, but the desired rust type is a non-zero {0}")]
+    Zero(String),
+
     /// A custom error that describes another reason for a conversion failure
     #[error("Conversion fails due to given root cause")]
     Other(Box<dyn std::error::Error + Send + Sync>),
 }
+
+impl ConversionError {
+    /// Returns the structured data of the error.
+    #[must_use]
+    pub fn message(&self) -> ErrorMessage<'_> {
+        match *self {
+            Self::ValueType(ref s) => ErrorMessage::ValueType(s),
+            Self::NumberRange(ref s) => ErrorMessage::NumberRange(s),
+            Self::Incomplete(ref s) => ErrorMessage::Incomplete(s),
+            Self::Json(ref s) => ErrorMessage::InvalidJson(s),
+            Self::InvalidUtf8 { valid_up_to } => ErrorMessage::InvalidUtf8 { valid_up_to },
+            Self::NonFinite(f) => ErrorMessage::NonFinite(f),
+            Self::Zero(ref s) => ErrorMessage::Zero(s),
+            Self::Other(ref e) => ErrorMessage::ConversionCause(e.as_ref()),
+        }
+    }
+
+    /// Renders the error with the given formatter.
+    #[must_use]
+    pub fn render(&self, formatter: &dyn MessageFormatter) -> String {
+        formatter.format(&self.message())
+    }
+}
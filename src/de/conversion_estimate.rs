@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+/// The result of
+/// [`DeserializableResultset::estimate_conversion()`](trait.DeserializableResultset.html#method.estimate_conversion).
+///
+/// Contains the already converted sample rows, and the measurements taken while converting them,
+/// which allow estimating the cost of converting the complete resultset.
+#[derive(Debug)]
+pub struct ConversionEstimate<T> {
+    /// The rows that were fetched and converted as sample.
+    pub sample: Vec<T>,
+    /// The time that was spent on fetching and converting the sample.
+    pub sample_duration: Duration,
+    /// The number of bytes that the values of the sample rows occupied on the heap, as reported by
+    /// [`DeserializableRow::byte_len()`](trait.DeserializableRow.html#method.byte_len),
+    /// or 0 if the driver does not track them.
+    pub sample_bytes: usize,
+    /// The number of rows that are not yet converted, if the resultset knows it.
+    pub remaining_rows: Option<usize>,
+}
+
+impl<T> ConversionEstimate<T> {
+    /// Returns the average time that was spent per row of the sample,
+    /// or `None` if the sample is empty.
+    #[must_use]
+    pub fn duration_per_row(&self) -> Option<Duration> {
+        let len = u32::try_from(self.sample.len()).ok()?;
+        self.sample_duration.checked_div(len)
+    }
+
+    /// Returns the approximate number of bytes each converted row occupies.
+    ///
+    /// This is the inline size of `T`, plus the average heap size of the values
+    /// of the sample rows, which approximates heap allocations of `T` like
+    /// the content of `String`s.
+    #[must_use]
+    pub fn bytes_per_row(&self) -> usize {
+        std::mem::size_of::<T>()
+            + self
+                .sample_bytes
+                .checked_div(self.sample.len())
+                .unwrap_or(0)
+    }
+
+    /// Returns the total number of rows, if the resultset knows it.
+    #[must_use]
+    pub fn total_rows(&self) -> Option<usize> {
+        self.remaining_rows.map(|r| r + self.sample.len())
+    }
+
+    /// Returns the estimated time for converting the remaining rows.
+    #[must_use]
+    pub fn estimated_remaining_duration(&self) -> Option<Duration> {
+        let per_row = self.duration_per_row()?;
+        let remaining = u32::try_from(self.remaining_rows?).ok()?;
+        per_row.checked_mul(remaining)
+    }
+
+    /// Returns the estimated number of bytes needed for all converted rows.
+    #[must_use]
+    pub fn estimated_total_bytes(&self) -> Option<usize> {
+        self.total_rows()?.checked_mul(self.bytes_per_row())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::rs_deserializer::{add_row_bytes, check_row_limit};
+use crate::de::{
+    ConversionError, DeserializableResultset, DeserializationError, DeserializationOptions,
+    DeserializationResult,
+};
+use serde::Deserialize;
+use std::io::Write;
+
+/// Writes a resultset as CSV into the given writer, and returns the number of written rows.
+///
+/// The first line contains the column names. The values are converted into text with the same
+/// conversions as for `String` fields, NULL values are written as empty fields.
+/// Fields that contain a comma, a double quote, or a line break are quoted,
+/// as described in RFC 4180.
+/// The rows are fetched, converted, and written one by one, so that even huge resultsets
+/// can be dumped into a file:
+///
+/// ```rust,ignore
+/// let mut file = std::io::BufWriter::new(std::fs::File::create("dump.csv")?);
+/// let rows = serde_db::de::write_csv(resultset, &mut file)?;
+/// ```
+///
+/// # Errors
+///
+/// If fetching or converting a value fails, or if writing fails.
+pub fn write_csv<RS, W>(rs: RS, writer: &mut W) -> DeserializationResult<usize>
+where
+    RS: DeserializableResultset,
+    W: Write,
+{
+    write_csv_with(rs, writer, &DeserializationOptions::default())
+}
+
+/// Writes a resultset as CSV into the given writer, using the given options,
+/// and returns the number of written rows.
+///
+/// See [`write_csv()`](fn.write_csv.html).
+///
+/// # Errors
+///
+/// If fetching or converting a value fails, or if writing fails.
+pub fn write_csv_with<RS, W>(
+    mut rs: RS,
+    writer: &mut W,
+    options: &DeserializationOptions,
+) -> DeserializationResult<usize>
+where
+    RS: DeserializableResultset,
+    W: Write,
+{
+    let header: Vec<Option<String>> = (0..rs.number_of_columns())
+        .map(|idx| rs.fieldname(idx).map(ToString::to_string))
+        .collect();
+    write_record(writer, &header)?;
+    let mut rows = 0;
+    let mut bytes = 0;
+    while let Some(row) = rs.next()? {
+        if options.is_cancelled() {
+            return Err(DeserializationError::Cancelled);
+        }
+        check_row_limit(options, rows)?;
+        add_row_bytes(options, &mut bytes, &row)?;
+        let mut row_deserializer = RowDeserializer::new(row, options).with_row_index(rows);
+        let record = Vec::<Option<String>>::deserialize(&mut row_deserializer)?;
+        write_record(writer, &record)?;
+        rows += 1;
+    }
+    writer.flush().map_err(io_error)?;
+    Ok(rows)
+}
+
+// Writes a line with the given fields.
+fn write_record<W: Write>(writer: &mut W, fields: &[Option<String>]) -> DeserializationResult<()> {
+    let mut line = String::new();
+    for (idx, field) in fields.iter().enumerate() {
+        if idx > 0 {
+            line.push(',');
+        }
+        let field = field.as_deref().unwrap_or_default();
+        if field.contains([',', '"', '\n', '\r']) {
+            line.push('"');
+            line.push_str(&field.replace('"', "\"\""));
+            line.push('"');
+        } else {
+            line.push_str(field);
+        }
+    }
+    line.push_str("\r\n");
+    writer.write_all(line.as_bytes()).map_err(io_error)
+}
+
+fn io_error(e: std::io::Error) -> DeserializationError {
+    ConversionError::Other(Box::new(e)).into()
+}
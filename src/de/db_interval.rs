@@ -0,0 +1,107 @@
+use crate::de::ConversionError;
+use serde::de::{Deserialize, Deserializer, Visitor};
+
+// The name of the newtype struct with which `DbInterval` identifies itself as target.
+pub(crate) const DB_INTERVAL: &str = "$serde_db::DbInterval";
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// The content of an interval value, like a value of an SQL `INTERVAL` column,
+/// independent of its database type.
+///
+/// Drivers expose it with [`DbValue::interval()`](trait.DbValue.html#method.interval).
+/// Such values can then be deserialized into `std::time::Duration`, and into `DbInterval`,
+/// which converts into `std::time::Duration` and (with feature `chrono`) `chrono::Duration`.
+///
+/// ```rust
+/// use serde_db::de::DbInterval;
+/// let interval = DbInterval::new(0, 1, 30_000_000);
+/// assert_eq!(interval.to_std_duration().unwrap().as_secs(), 86_430);
+/// assert!(DbInterval::new(1, 0, 0).to_std_duration().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DbInterval {
+    /// The number of months.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of microseconds.
+    pub microseconds: i64,
+}
+
+impl DbInterval {
+    /// Creates an interval from its parts.
+    #[must_use]
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Returns the length of the interval in microseconds, counting days with 24 hours.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::ValueType` if the interval contains months, whose length is not fixed,
+    /// and `ConversionError::NumberRange` if the result does not fit into an `i64`.
+    pub fn total_microseconds(&self) -> Result<i64, ConversionError> {
+        if self.months != 0 {
+            return Err(ConversionError::ValueType(format!(
+                "the interval {self:?} contains months, which have no fixed duration"
+            )));
+        }
+        i64::from(self.days)
+            .checked_mul(MICROS_PER_DAY)
+            .and_then(|micros| micros.checked_add(self.microseconds))
+            .ok_or_else(|| ConversionError::NumberRange(format!("{self:?}")))
+    }
+
+    /// Converts the interval into a `std::time::Duration`.
+    ///
+    /// # Errors
+    ///
+    /// As with [`total_microseconds()`](#method.total_microseconds), and
+    /// `ConversionError::NumberRange` if the interval is negative.
+    pub fn to_std_duration(&self) -> Result<std::time::Duration, ConversionError> {
+        let micros = u64::try_from(self.total_microseconds()?).map_err(|_| {
+            ConversionError::NumberRange(format!("{self:?} is a negative interval"))
+        })?;
+        Ok(std::time::Duration::from_micros(micros))
+    }
+
+    /// Converts the interval into a `chrono::Duration`.
+    ///
+    /// # Errors
+    ///
+    /// As with [`total_microseconds()`](#method.total_microseconds).
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono_duration(&self) -> Result<chrono::Duration, ConversionError> {
+        Ok(chrono::Duration::microseconds(self.total_microseconds()?))
+    }
+}
+
+impl<'de> Deserialize<'de> for DbInterval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(DB_INTERVAL, DbIntervalVisitor)
+    }
+}
+
+struct DbIntervalVisitor;
+
+impl<'de> Visitor<'de> for DbIntervalVisitor {
+    type Value = DbInterval;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an interval value")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<DbInterval, D::Error> {
+        let (months, days, microseconds) = <(i32, i32, i64)>::deserialize(deserializer)?;
+        Ok(DbInterval::new(months, days, microseconds))
+    }
+}
@@ -0,0 +1,202 @@
+use crate::de::{ConversionError, DecimalParts, NumericPolicy, RoundingMode};
+
+/// The numeric content of a database value, independent of its database type.
+///
+/// Drivers expose it with [`DbValue::number()`](trait.DbValue.html#method.number),
+/// so that the conversion into the numeric rust types can be controlled by a
+/// [`NumericPolicy`](enum.NumericPolicy.html), rather than by the driver's `DbValueInto`
+/// implementations.
+///
+/// ```rust
+/// use serde_db::de::{DbNumber, NumericPolicy};
+/// assert_eq!(DbNumber::Float(3.7).convert::<i32>(NumericPolicy::Checked).unwrap(), 3);
+/// assert!(DbNumber::Float(3.7).convert::<i32>(NumericPolicy::Strict).is_err());
+/// assert!(DbNumber::Integer(300).convert::<u8>(NumericPolicy::Checked).is_err());
+/// assert_eq!(DbNumber::Integer(300).convert::<u8>(NumericPolicy::Lossy).unwrap(), 255);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DbNumber {
+    /// An integer value.
+    Integer(i128),
+    /// A floating point value.
+    Float(f64),
+}
+
+impl DbNumber {
+    /// Converts the number into the target type, following the given policy.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::NumberRange` if the value is out of the range of the target type,
+    /// and `ConversionError::ValueType` if information would be lost that the policy
+    /// does not allow to lose.
+    pub fn convert<T: NumericTarget>(self, policy: NumericPolicy) -> Result<T, ConversionError> {
+        self.convert_rounded(policy, RoundingMode::Truncate)
+    }
+
+    /// Converts the number into the target type, following the given policy, and
+    /// removing the fractional part of floating point values with the given rounding mode
+    /// if the target is an integer type.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::NumberRange` if the value is out of the range of the target type,
+    /// and `ConversionError::ValueType` if information would be lost that the policy
+    /// or the rounding mode does not allow to lose.
+    pub fn convert_rounded<T: NumericTarget>(
+        self,
+        policy: NumericPolicy,
+        rounding: RoundingMode,
+    ) -> Result<T, ConversionError> {
+        match self {
+            Self::Integer(i) => T::from_integer(i, policy),
+            Self::Float(f) if T::IS_INTEGER && policy != NumericPolicy::Strict && !f.is_nan() => {
+                let rounded = rounding.apply(f).ok_or_else(|| lossy(f, T::NAME))?;
+                T::from_float(rounded, policy)
+            }
+            Self::Float(f) => T::from_float(f, policy),
+        }
+    }
+}
+
+impl From<DecimalParts> for DbNumber {
+    fn from(decimal: DecimalParts) -> Self {
+        let mut mantissa = decimal.mantissa();
+        let mut exponent = decimal.exponent();
+        while exponent < 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        if exponent >= 0 {
+            let factor = u32::try_from(exponent)
+                .ok()
+                .and_then(|e| 10_i128.checked_pow(e));
+            if let Some(i) = factor.and_then(|f| mantissa.checked_mul(f)) {
+                return Self::Integer(i);
+            }
+        }
+        Self::Float(decimal.to_string().parse().unwrap_or(f64::NAN))
+    }
+}
+
+/// The numeric rust types into which a [`DbNumber`] can be converted.
+///
+/// This trait is sealed and implemented for all primitive integer and floating point types
+/// that are supported by [`DbValue`](trait.DbValue.html).
+pub trait NumericTarget: Sized + private::Sealed {
+    #[doc(hidden)]
+    const NAME: &'static str;
+    #[doc(hidden)]
+    const IS_INTEGER: bool;
+    #[doc(hidden)]
+    fn from_integer(i: i128, policy: NumericPolicy) -> Result<Self, ConversionError>;
+    #[doc(hidden)]
+    fn from_float(f: f64, policy: NumericPolicy) -> Result<Self, ConversionError>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_integer_target {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+        impl NumericTarget for $t {
+            const NAME: &'static str = stringify!($t);
+            const IS_INTEGER: bool = true;
+
+            fn from_integer(i: i128, policy: NumericPolicy) -> Result<Self, ConversionError> {
+                match <$t>::try_from(i) {
+                    Ok(v) => Ok(v),
+                    Err(_) if policy == NumericPolicy::Lossy => {
+                        Ok(if i < 0 { <$t>::MIN } else { <$t>::MAX })
+                    }
+                    Err(_) => Err(out_of_range(i, stringify!($t))),
+                }
+            }
+
+            #[allow(
+                clippy::cast_lossless,
+                clippy::cast_possible_truncation,
+                clippy::cast_precision_loss,
+                clippy::cast_sign_loss
+            )]
+            fn from_float(f: f64, policy: NumericPolicy) -> Result<Self, ConversionError> {
+                match policy {
+                    // saturating, NaN becomes 0
+                    NumericPolicy::Lossy => Ok(f as $t),
+                    NumericPolicy::Strict if f.fract() != 0.0 => {
+                        Err(lossy(f, stringify!($t)))
+                    }
+                    _ if f.is_finite()
+                        && f.trunc() >= <$t>::MIN as f64
+                        && f.trunc() < <$t>::MAX as f64 + 1.0 =>
+                    {
+                        Ok(f.trunc() as $t)
+                    }
+                    _ => Err(out_of_range(f, stringify!($t))),
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer_target!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl private::Sealed for f32 {}
+impl NumericTarget for f32 {
+    const NAME: &'static str = "f32";
+    const IS_INTEGER: bool = false;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn from_integer(i: i128, policy: NumericPolicy) -> Result<Self, ConversionError> {
+        let f = i as f32;
+        if policy == NumericPolicy::Strict && f as i128 != i {
+            Err(lossy(i, "f32"))
+        } else {
+            Ok(f)
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+    fn from_float(f: f64, policy: NumericPolicy) -> Result<Self, ConversionError> {
+        let v = f as f32;
+        match policy {
+            NumericPolicy::Strict if !f.is_nan() && f64::from(v) != f => Err(lossy(f, "f32")),
+            NumericPolicy::Checked if f.is_finite() && v.is_infinite() => {
+                Err(out_of_range(f, "f32"))
+            }
+            _ => Ok(v),
+        }
+    }
+}
+
+impl private::Sealed for f64 {}
+impl NumericTarget for f64 {
+    const NAME: &'static str = "f64";
+    const IS_INTEGER: bool = false;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn from_integer(i: i128, policy: NumericPolicy) -> Result<Self, ConversionError> {
+        let f = i as f64;
+        if policy == NumericPolicy::Strict && f as i128 != i {
+            Err(lossy(i, "f64"))
+        } else {
+            Ok(f)
+        }
+    }
+
+    fn from_float(f: f64, _policy: NumericPolicy) -> Result<Self, ConversionError> {
+        Ok(f)
+    }
+}
+
+fn out_of_range<N: std::fmt::Display>(n: N, target: &str) -> ConversionError {
+    ConversionError::NumberRange(format!("{n} does not fit into {target}"))
+}
+
+fn lossy<N: std::fmt::Display>(n: N, target: &str) -> ConversionError {
+    ConversionError::ValueType(format!(
+        "{n} cannot be converted into {target} without loss"
+    ))
+}
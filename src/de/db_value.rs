@@ -1,7 +1,9 @@
 use crate::de::field_deserializer::FieldDeserializer;
-use crate::de::{DbValueInto, DeserializationError};
+use crate::de::{
+    AsyncLobReader, ConversionError, DbInterval, DbNumber, DbValueInto, DecimalParts,
+    DeserializationError, DeserializationOptions, NestedResultset, ValueKind,
+};
 use std::marker::Sized;
-use std::{i16, i32, i8, u16, u32, u8};
 
 /// Provides the conversion of a database value into a standard rust type.
 pub trait DbValue:
@@ -23,6 +25,186 @@ pub trait DbValue:
     /// Returns true if this is a NULL value.
     fn is_null(&self) -> bool;
 
+    /// Returns the lossless representation of the value if it is a decimal value.
+    ///
+    /// Target types that use `deserialize_any()`, like most decimal types, are then fed with
+    /// the exact textual representation of the decimal, rather than with the result of
+    /// `DbValueInto<String>` or `DbValueInto<f64>`.
+    /// The default implementation returns `None`.
+    fn decimal_parts(&self) -> Option<DecimalParts> {
+        None
+    }
+
+    /// Returns the coarse kind of the value.
+    ///
+    /// Target types that use `deserialize_any()`, like `serde_json::Value` or untagged enums,
+    /// then get the value as `bool`, `i64`, `f64`, bytes, or text, according to its kind.
+    /// The default implementation returns `ValueKind::Unknown`, with which such targets
+    /// get the textual representation of the value.
+    fn value_kind(&self) -> ValueKind {
+        ValueKind::Unknown
+    }
+
+    /// Returns the numeric content of the value if it is a number.
+    ///
+    /// If a [`NumericPolicy`](enum.NumericPolicy.html) is set in the
+    /// [`DeserializationOptions`](struct.DeserializationOptions.html), numeric targets are then
+    /// converted from this number according to the policy, rather than with `DbValueInto`;
+    /// values with [`decimal_parts()`](#method.decimal_parts) are handled in the same way.
+    /// The default implementation returns `None`.
+    fn number(&self) -> Option<DbNumber> {
+        None
+    }
+
+    /// Returns the content of the value if it is an interval, like a value of an SQL `INTERVAL`
+    /// column.
+    ///
+    /// Such values are deserialized into targets of type `std::time::Duration` and
+    /// [`DbInterval`](struct.DbInterval.html) from this interval.
+    /// The default implementation returns `None`.
+    fn interval(&self) -> Option<DbInterval> {
+        None
+    }
+
+    /// Returns true if the value is a JSON document (e.g. from a `JSON` or `JSONB` column).
+    ///
+    /// With feature `json`, the deserializers then parse the textual content of the value
+    /// (as retrieved with `DbValueInto<String>`) with `serde_json` when the target type is
+    /// a struct, map, sequence, tuple, or enum,
+    /// so that JSON columns can be deserialized into arbitrary nested types.
+    /// Targets of type `String` still get the raw JSON text.
+    /// The default implementation returns `false`.
+    fn is_json(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the value is an array, like a value of a Postgres `int[]` column.
+    ///
+    /// Such values are deserialized into sequences, like `Vec<i32>`, or
+    /// tuples, by converting their elements (see [`into_array()`](#method.into_array)).
+    /// The default implementation returns `false`.
+    fn is_array(&self) -> bool {
+        false
+    }
+
+    /// Converts an array value into its elements.
+    ///
+    /// The default implementation produces an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not an array.
+    fn into_array(self) -> Result<Vec<Self>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value is not an array".to_string(),
+        ))
+    }
+
+    /// Returns true if the value contains a resultset, like a nested table in SAP HANA.
+    ///
+    /// Such values are deserialized like resultsets, e.g. into a `Vec<Child>`,
+    /// by converting the result of
+    /// [`into_nested_resultset()`](#method.into_nested_resultset).
+    /// The default implementation returns `false`.
+    fn is_nested_resultset(&self) -> bool {
+        false
+    }
+
+    /// Converts a value that contains a resultset into that resultset.
+    ///
+    /// The default implementation produces an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value does not contain a resultset.
+    fn into_nested_resultset(self) -> Result<NestedResultset<Self>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value does not contain a resultset".to_string(),
+        ))
+    }
+
+    /// Returns true if the value is text whose encoding was not yet validated.
+    ///
+    /// For such values, the deserializers retrieve the raw bytes with `DbValueInto<Vec<u8>>`
+    /// and apply the [`Utf8Policy`](enum.Utf8Policy.html) of the
+    /// [`DeserializationOptions`](struct.DeserializationOptions.html),
+    /// rather than using `DbValueInto<String>`.
+    /// The default implementation returns `false`.
+    fn is_raw_text(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the value is text from a fixed-width column, like `CHAR(n)`,
+    /// that is padded with blanks.
+    ///
+    /// The padding of such values is removed during string conversion if the
+    /// [`CharPadding`](enum.CharPadding.html) of the
+    /// [`DeserializationOptions`](struct.DeserializationOptions.html) asks for it.
+    /// The default implementation returns `false`.
+    fn is_fixed_width_text(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the value is a large object (CLOB, BLOB) whose content can be streamed
+    /// with [`into_lob_reader()`](#method.into_lob_reader).
+    ///
+    /// Such values are streamed in chunks into the targets of
+    /// [`stream_into()`](fn.stream_into.html);
+    /// all other targets still get the complete content with `DbValueInto`.
+    /// The default implementation returns `false`.
+    fn is_lob(&self) -> bool {
+        false
+    }
+
+    /// Converts a large object into a reader for its content.
+    ///
+    /// The default implementation produces an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not a large object.
+    fn into_lob_reader(self) -> Result<Box<dyn std::io::Read>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value is not a large object".to_string(),
+        ))
+    }
+
+    /// Returns true if the value is a large object whose content is fetched asynchronously
+    /// with [`into_async_lob_reader()`](#method.into_async_lob_reader).
+    ///
+    /// Such values can only be converted with
+    /// [`write_lob_async()`](fn.write_lob_async.html).
+    /// The default implementation returns `false`.
+    fn is_async_lob(&self) -> bool {
+        false
+    }
+
+    /// Converts an asynchronously fetched large object into a reader for its content.
+    ///
+    /// The default implementation produces an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not an asynchronously fetched large object.
+    fn into_async_lob_reader(self) -> Result<Box<dyn AsyncLobReader>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value is not an asynchronously fetched large object".to_string(),
+        ))
+    }
+
+    /// Returns true if the value is a timestamp without timezone information.
+    ///
+    /// Such values are deserialized into the targets of
+    /// [`timezone_aware()`](fn.timezone_aware.html) according to the
+    /// [`TimezonePolicy`](enum.TimezonePolicy.html) of the
+    /// [`DeserializationOptions`](struct.DeserializationOptions.html).
+    /// Their textual representation (as retrieved with `DbValueInto<String>`)
+    /// must be in ISO 8601 format.
+    /// The default implementation returns `false`.
+    fn is_naive_timestamp(&self) -> bool {
+        false
+    }
+
     /// Converts the `DbValue` into a plain rust value.
     ///
     /// The generic implementation of this method is based on to-be-provided
@@ -36,6 +218,22 @@ pub trait DbValue:
     {
         #[cfg(feature = "trace")]
         log::trace!("DbValue::try_into");
-        serde::Deserialize::deserialize(FieldDeserializer::new(self))
+        self.try_into_with(&DeserializationOptions::default())
+    }
+
+    /// Converts the `DbValue` into a plain rust value, using the given options.
+    ///
+    /// # Errors
+    /// `DeserializationError` if the value cannot be converted into the target type.
+    fn try_into_with<'de, T>(
+        self,
+        options: &DeserializationOptions,
+    ) -> Result<T, DeserializationError>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DbValue::try_into_with");
+        serde::Deserialize::deserialize(FieldDeserializer::new(self, options))
     }
 }
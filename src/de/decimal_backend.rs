@@ -0,0 +1,99 @@
+use crate::de::{ConversionError, DbNumber, DbValue, DbValueInto, DecimalParts};
+
+/// A decimal type into which database values can be converted without loss of precision.
+///
+/// Implementing this trait for a decimal type is all that is needed to convert any
+/// [`DbValue`](trait.DbValue.html) into it, using
+/// [`from_db_value()`](#method.from_db_value).
+/// `serde_db` implements it for [`DecimalParts`](struct.DecimalParts.html),
+/// so that a driver-native decimal is available without any dependency,
+/// and, with feature `bigdecimal`, for `bigdecimal::BigDecimal`.
+///
+/// ```rust
+/// use serde_db::de::{DecimalBackend, DecimalParts};
+/// let d = DecimalParts::from_decimal_str("-12.50").unwrap();
+/// assert_eq!(d, DecimalParts::new(-1250, -2));
+/// ```
+pub trait DecimalBackend: Sized {
+    /// Converts the lossless representation of a decimal value.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be represented.
+    fn from_decimal_parts(decimal: DecimalParts) -> Result<Self, ConversionError>;
+
+    /// Parses a textual representation of a decimal value, like `-12.345` or `1.5E-3`.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the text is not a decimal value, or if the value
+    /// cannot be represented.
+    fn from_decimal_str(s: &str) -> Result<Self, ConversionError>;
+
+    /// Converts a database value.
+    ///
+    /// Decimal values are converted losslessly using
+    /// [`DbValue::decimal_parts()`](trait.DbValue.html#method.decimal_parts),
+    /// all other values are converted by parsing the result of `DbValueInto<String>`.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::NonFinite` if the value is NaN or infinite,
+    /// and `ConversionError` if the value cannot be converted for other reasons.
+    fn from_db_value<DBV: DbValue>(value: DBV) -> Result<Self, ConversionError> {
+        if let Some(decimal) = value.decimal_parts() {
+            return Self::from_decimal_parts(decimal);
+        }
+        if let Some(DbNumber::Float(f)) = value.number() {
+            if !f.is_finite() {
+                return Err(ConversionError::NonFinite(f));
+            }
+        }
+        let s: String = DbValueInto::<String>::try_into(value)?;
+        Self::from_decimal_str(&s)
+    }
+}
+
+impl DecimalBackend for DecimalParts {
+    fn from_decimal_parts(decimal: DecimalParts) -> Result<Self, ConversionError> {
+        Ok(decimal)
+    }
+
+    fn from_decimal_str(s: &str) -> Result<Self, ConversionError> {
+        let invalid = || ConversionError::ValueType(format!("{s:?} is not a decimal value"));
+        let out_of_range = || ConversionError::NumberRange(format!("{s:?} is too big"));
+
+        let (number, exponent) = match s.trim().split_once(['e', 'E']) {
+            Some((number, exponent)) => (number, exponent.parse::<i32>().map_err(|_| invalid())?),
+            None => (s.trim(), 0),
+        };
+        let (int_part, frac_part) = number.split_once('.').unwrap_or((number, ""));
+        let (negative, int_part) = match int_part.as_bytes().first() {
+            Some(b'-') => (true, &int_part[1..]),
+            Some(b'+') => (false, &int_part[1..]),
+            _ => (false, int_part),
+        };
+        if int_part.is_empty() && frac_part.is_empty()
+            || !int_part
+                .bytes()
+                .chain(frac_part.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let mut mantissa: i128 = 0;
+        for b in int_part.bytes().chain(frac_part.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(b - b'0')))
+                .ok_or_else(out_of_range)?;
+        }
+        let scale = i32::try_from(frac_part.len()).map_err(|_| out_of_range())?;
+        let exponent = exponent.checked_sub(scale).ok_or_else(out_of_range)?;
+        Ok(DecimalParts::new(
+            if negative { -mantissa } else { mantissa },
+            exponent,
+        ))
+    }
+}
@@ -0,0 +1,65 @@
+/// A lossless representation of a decimal value, as `mantissa * 10^exponent`.
+///
+/// Drivers that have decimal values expose them with
+/// [`DbValue::decimal_parts()`](trait.DbValue.html#method.decimal_parts).
+/// The deserializers then hand the exact textual representation to the target type,
+/// so that e.g. `bigdecimal::BigDecimal` or `rust_decimal::Decimal` don't have to
+/// round-trip through `f64`.
+///
+/// ```rust
+/// use serde_db::de::DecimalParts;
+/// assert_eq!(DecimalParts::new(-12_345, -3).to_string(), "-12.345");
+/// assert_eq!(DecimalParts::new(5, -3).to_string(), "0.005");
+/// assert_eq!(DecimalParts::new(12, 2).to_string(), "1200");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalParts {
+    mantissa: i128,
+    exponent: i32,
+}
+
+impl DecimalParts {
+    /// Constructs the decimal value `mantissa * 10^exponent`.
+    #[must_use]
+    pub fn new(mantissa: i128, exponent: i32) -> Self {
+        Self { mantissa, exponent }
+    }
+
+    /// Returns the mantissa.
+    #[must_use]
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the (decimal) exponent.
+    #[must_use]
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+}
+
+impl std::fmt::Display for DecimalParts {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        if self.mantissa < 0 {
+            f.write_str("-")?;
+        }
+        if self.exponent >= 0 {
+            f.write_str(&digits)?;
+            if self.mantissa != 0 {
+                for _ in 0..self.exponent {
+                    f.write_str("0")?;
+                }
+            }
+            Ok(())
+        } else {
+            let scale = self.exponent.unsigned_abs() as usize;
+            if digits.len() > scale {
+                let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+                write!(f, "{int_part}.{frac_part}")
+            } else {
+                write!(f, "0.{digits:0>scale$}")
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::de::multi_rs_deserializer::MultiRsDeserializer;
+use crate::de::{
+    DeserializableResultset, DeserializationError, DeserializationOptions, DeserializationResult,
+};
+
+/// Interface for the results of a database statement that can return several resultsets,
+/// like a call of a stored procedure, to support deserialization.
+///
+/// The resultsets are deserialized into a tuple or a sequence, whose elements are the
+/// targets of the individual resultsets; each resultset is converted like with
+/// [`DeserializableResultset::try_into()`](trait.DeserializableResultset.html#method.try_into).
+pub trait DeserializableMultiResultset: Sized {
+    /// Error type of the database driver.
+    type E: From<DeserializationError> + Sized;
+    /// Concrete type for the individual resultsets.
+    type RS: DeserializableResultset;
+
+    /// Removes the next resultset and returns it, or None if there is none left, or an error.
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    fn next_resultset(&mut self) -> DeserializationResult<Option<Self::RS>>;
+
+    /// A _provided method_ that translates the resultsets into a tuple or a sequence of
+    /// rust types that implement `serde::Deserialize`.
+    ///
+    /// ```ignore
+    /// let (headers, lines): (Vec<Header>, Vec<Line>) = multi_resultset.try_into()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// e.g. if the number of resultsets does not match the length of the tuple.
+    fn try_into<'de, T>(self) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that translates the resultsets into a tuple or a sequence of
+    /// rust types that implement `serde::Deserialize`, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// e.g. if the number of resultsets does not match the length of the tuple.
+    fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableMultiResultset::try_into_with()");
+        Ok(serde::Deserialize::deserialize(
+            &mut MultiRsDeserializer::new(self, options),
+        )?)
+    }
+}
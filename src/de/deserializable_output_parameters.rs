@@ -0,0 +1,99 @@
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::{DbValue, DeserializableRow, DeserializationError, DeserializationOptions};
+
+/// Interface for the output parameters of a database procedure call to support
+/// deserialization.
+///
+/// The output parameters are converted like a row: into a struct whose fields match the
+/// parameter names, into a tuple, or, if there is only one parameter, into a plain rust value.
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct Totals {
+///     NET: f64,
+///     TAX: f64,
+/// }
+/// let totals: Totals = output_parameters.try_into()?;
+/// ```
+pub trait DeserializableOutputParameters: Sized {
+    /// The error type used by the database driver.
+    type E: From<DeserializationError> + Sized;
+    /// The value type used by the database driver.
+    type V: DbValue;
+
+    /// Returns the number of output parameters.
+    fn number_of_parameters(&self) -> usize;
+
+    /// Returns the name of the output parameter at the specified index.
+    fn parameter_name(&self, idx: usize) -> Option<&str>;
+
+    /// Removes and returns the value of the next output parameter.
+    fn next_value(&mut self) -> Option<Self::V>;
+
+    /// Converts the output parameters into a struct, a tuple, or (if applicable)
+    /// into a plain rust value.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into<'de, T>(self) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_with(&DeserializationOptions::default())
+    }
+
+    /// Converts the output parameters into a struct, a tuple, or (if applicable)
+    /// into a plain rust value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        Ok(serde::Deserialize::deserialize(&mut RowDeserializer::new(
+            ParametersRow::new(self),
+            options,
+        ))?)
+    }
+}
+
+// Presents the output parameters as a row, so that they are deserialized like one.
+struct ParametersRow<P> {
+    parameters: P,
+    consumed: usize,
+}
+
+impl<P> ParametersRow<P> {
+    fn new(parameters: P) -> Self {
+        ParametersRow {
+            parameters,
+            consumed: 0,
+        }
+    }
+}
+
+impl<P: DeserializableOutputParameters> DeserializableRow for ParametersRow<P> {
+    type E = P::E;
+    type V = P::V;
+
+    fn len(&self) -> usize {
+        self.parameters.number_of_parameters() - self.consumed
+    }
+
+    fn next(&mut self) -> Option<P::V> {
+        let value = self.parameters.next_value()?;
+        self.consumed += 1;
+        Some(value)
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.parameters.number_of_parameters()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.parameters.parameter_name(field_idx)
+    }
+}
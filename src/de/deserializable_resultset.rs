@@ -1,6 +1,21 @@
+#[cfg(feature = "arrow")]
+use crate::de::arrow_conversion::record_batch;
+use crate::de::columns_deserializer::ColumnsDeserializer;
+use crate::de::in_place::InPlace;
+#[cfg(feature = "polars")]
+use crate::de::polars_conversion::dataframe;
+use crate::de::row_deserializer::RowDeserializer;
 use crate::de::rs_deserializer::RsDeserializer;
-use crate::de::{DeserializableRow, DeserializationError, DeserializationResult};
-use std::marker::Sized;
+#[cfg(any(feature = "arrow", feature = "polars"))]
+use crate::de::typed_columns::typed_columns;
+use crate::de::validation::ValidatingSeed;
+use crate::de::{
+    ConversionEstimate, DbValue, DeserializableRow, DeserializationError, DeserializationOptions,
+    DeserializationResult, Projection, ResultsetMetrics, RowRange, TypedRows,
+};
+use std::marker::{PhantomData, Sized};
+use std::ops::{Bound, RangeBounds};
+use std::time::Instant;
 
 /// Interface for a database resultset to support deserialization.
 pub trait DeserializableResultset: Sized {
@@ -16,6 +31,13 @@ pub trait DeserializableResultset: Sized {
     /// E.g. fetching can fail.
     fn has_multiple_rows(&mut self) -> DeserializationResult<bool>;
 
+    /// Returns true if no more rows are contained, including eventually not yet fetched rows.
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    fn has_no_rows(&mut self) -> DeserializationResult<bool>;
+
     /// Removes the next row and returns it, or None if the result set is empty, or an error.
     ///
     /// # Errors
@@ -29,9 +51,63 @@ pub trait DeserializableResultset: Sized {
     /// Returns the name of the column at the specified index.
     fn fieldname(&self, field_idx: usize) -> Option<&str>;
 
+    /// Returns a descriptor of the database type of the column at the specified index.
+    ///
+    /// The descriptor is driver-specific; it is only used for comparing
+    /// the metadata of resultsets (see [`metadata_diff`](fn.metadata_diff.html)).
+    /// The default implementation returns `None`.
+    fn fieldtype(&self, _field_idx: usize) -> Option<&str> {
+        None
+    }
+
+    /// Returns the number of rows that `next()` will still deliver,
+    /// if this is known without fetching them.
+    ///
+    /// The default implementation returns `None`.
+    fn remaining_rows(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the number of columns of each row.
+    ///
+    /// The default implementation uses `number_of_fields()`.
+    fn number_of_columns(&self) -> usize {
+        self.number_of_fields()
+    }
+
+    /// Removes the row at the specified index and returns it, or None if there is no such row,
+    /// or an error.
+    ///
+    /// The index counts the rows that were not yet removed, so `take_row(0)` is equivalent to
+    /// `next()`; the rows before the specified index remain in the resultset.
+    /// Drivers that hold their rows in a container can override this method to move
+    /// the row out of the container without cloning it.
+    /// The default implementation supports only index 0.
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail, or the index is not supported.
+    fn take_row(&mut self, idx: usize) -> DeserializationResult<Option<Self::ROW>> {
+        if idx == 0 {
+            self.next()
+        } else {
+            Err(DeserializationError::NotImplemented(
+                "DeserializableResultset::take_row() with index > 0",
+            ))
+        }
+    }
+
     /// A _provided method_ that translates a resultset into a given rust type
     /// that implements `serde::Deserialize`.
     ///
+    /// Since `DeserializableResultset` is also implemented for `&mut` references,
+    /// a resultset can be converted without giving it away; rows that were not converted,
+    /// e.g. because of an error, remain in the resultset:
+    ///
+    /// ```ignore
+    /// let typed_result: Vec<MyStruct> = DeserializableResultset::try_into(&mut resultset)?;
+    /// ```
+    ///
     /// The type of the target variable needs to be specified explicitly, so that
     /// `try_into()` can derive the type it needs to serialize into:
     ///
@@ -53,8 +129,997 @@ pub trait DeserializableResultset: Sized {
     {
         #[cfg(feature = "trace")]
         log::trace!("DeserializableResultset::try_into()");
+        self.try_into_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that translates a resultset into a given rust type
+    /// that implements `serde::Deserialize`, using the given options.
+    ///
+    /// ```ignore
+    /// let options = DeserializationOptions::default().with_utf8_policy(Utf8Policy::Lossy);
+    /// let typed_result: Vec<MyStruct> = resultset.try_into_with(&options)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::try_into_with()");
+        self.try_into_seed_with(PhantomData::<T>, options)
+    }
+
+    /// A _provided method_ that translates a clone of the resultset into a given rust type,
+    /// so that the resultset can be converted again, e.g. into another target type.
+    ///
+    /// This is only available for resultsets that can be cloned,
+    /// which usually requires that all rows are already fetched.
+    ///
+    /// ```ignore
+    /// let summaries: Vec<Summary> = resultset.try_to()?;
+    /// let details: Vec<Details> = resultset.try_into()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_to<'de, T>(&self) -> Result<T, Self::E>
+    where
+        Self: Clone,
+        T: serde::Deserialize<'de>,
+    {
+        self.clone().try_into()
+    }
+
+    /// A _provided method_ that translates a clone of the resultset into a given rust type,
+    /// using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_to_with<'de, T>(&self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        Self: Clone,
+        T: serde::Deserialize<'de>,
+    {
+        self.clone().try_into_with(options)
+    }
+
+    /// A _provided method_ that translates a resultset into an existing value,
+    /// reusing its allocations where possible.
+    ///
+    /// If the target is a `Vec`, its elements are reused for the rows,
+    /// which avoids allocating a fresh target for each row.
+    ///
+    /// ```ignore
+    /// let mut lines: Vec<MyStruct> = Vec::new();
+    /// for resultset in resultsets {
+    ///     resultset.try_into_in_place(&mut lines)?;
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_in_place<'de, T>(self, place: &mut T) -> Result<(), Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_in_place_with(place, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that translates a resultset into an existing value,
+    /// using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_in_place_with<'de, T>(
+        self,
+        place: &mut T,
+        options: &DeserializationOptions,
+    ) -> Result<(), Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_seed_with(InPlace(place), options)
+    }
+
+    /// A _provided method_ that translates a resultset with the given
+    /// `serde::de::DeserializeSeed`, for target types that need external state
+    /// during deserialization, like arena-allocated or interner-backed types.
+    ///
+    /// ```ignore
+    /// let typed_result: Vec<Symbol> = resultset.try_into_seed(SymbolsSeed(&mut interner))?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_seed<'de, S>(self, seed: S) -> Result<S::Value, Self::E>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        self.try_into_seed_with(seed, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that translates a resultset with the given
+    /// `serde::de::DeserializeSeed`, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_seed_with<'de, S>(
+        self,
+        seed: S,
+        options: &DeserializationOptions,
+    ) -> Result<S::Value, Self::E>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "deserialize_resultset",
+            target = std::any::type_name::<S::Value>(),
+            rows = tracing::field::Empty,
+            succeeded = tracing::field::Empty,
+        )
+        .entered();
+        let start = Instant::now();
+        let mut rs_deserializer = RsDeserializer::try_new(self, options)?;
+        let result = seed.deserialize(&mut rs_deserializer);
+        let metrics = ResultsetMetrics {
+            rows: rs_deserializer.rows(),
+            bytes: rs_deserializer.bytes(),
+            elapsed: start.elapsed(),
+            succeeded: result.is_ok(),
+        };
+        #[cfg(feature = "tracing")]
+        span.record("rows", metrics.rows)
+            .record("succeeded", metrics.succeeded);
+        #[cfg(feature = "trace")]
+        log::debug!(
+            "deserialization of {} rows {} after {:?}",
+            metrics.rows,
+            if metrics.succeeded {
+                "succeeded"
+            } else {
+                "failed"
+            },
+            metrics.elapsed
+        );
+        if let Some(observer) = options.observer() {
+            observer.resultset_deserialized(&metrics);
+        }
+        Ok(result?)
+    }
+
+    /// A _provided method_ that translates a resultset column by column into a
+    /// "struct of arrays", whose fields are `Vec`s with one entry per row,
+    /// or into a tuple of `Vec`s.
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct Prices {
+    ///     symbol: Vec<String>,
+    ///     price: Vec<f64>,
+    /// }
+    /// let prices: Prices = resultset.try_into_columns()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// e.g. if a column has no corresponding field, or if fetching fails.
+    fn try_into_columns<'de, T>(self) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_columns_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that translates a resultset column by column into a
+    /// "struct of arrays" or into a tuple of `Vec`s, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// e.g. if a column has no corresponding field, or if fetching fails.
+    fn try_into_columns_with<'de, T>(self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::try_into_columns_with()");
         Ok(serde::Deserialize::deserialize(
-            &mut RsDeserializer::try_new(self)?,
+            ColumnsDeserializer::try_new(self, options)?,
         )?)
     }
+
+    /// A _provided method_ that converts a resultset column by column into an Arrow
+    /// `RecordBatch`; is only available with feature `arrow`.
+    ///
+    /// The Arrow type of each column is derived from the
+    /// [`ValueKind`](enum.ValueKind.html) of its first non-NULL value:
+    /// booleans, integers, and floating point values become `Boolean`, `Int64`, and `Float64`,
+    /// binary strings become `Binary`, columns with only NULLs become `Null`,
+    /// and all other values become `Utf8`, with their textual representation.
+    /// Numbers are converted with the [`NumericPolicy`](enum.NumericPolicy.html) of the options,
+    /// by default with `NumericPolicy::Checked`.
+    ///
+    /// ```ignore
+    /// let batch: RecordBatch = resultset.into_record_batch()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a value cannot be converted into the type of its column,
+    /// or if fetching fails.
+    #[cfg(feature = "arrow")]
+    fn into_record_batch(self) -> Result<arrow_array::RecordBatch, Self::E> {
+        self.into_record_batch_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts a resultset column by column into an Arrow
+    /// `RecordBatch`, using the given options; is only available with feature `arrow`.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a value cannot be converted into the type of its column,
+    /// or if fetching fails.
+    #[cfg(feature = "arrow")]
+    fn into_record_batch_with(
+        self,
+        options: &DeserializationOptions,
+    ) -> Result<arrow_array::RecordBatch, Self::E> {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::into_record_batch_with()");
+        Ok(record_batch(typed_columns(self, options)?)?)
+    }
+
+    /// A _provided method_ that converts a resultset column by column into a Polars
+    /// `DataFrame`; is only available with feature `polars`.
+    ///
+    /// The columns get their type in the same way as with
+    /// [`into_record_batch()`](#method.into_record_batch), i.e. `Boolean`, `Int64`, `Float64`,
+    /// `Binary`, `Null`, or `String`.
+    ///
+    /// ```ignore
+    /// let df: DataFrame = resultset.into_dataframe()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a value cannot be converted into the type of its column,
+    /// or if fetching fails.
+    #[cfg(feature = "polars")]
+    fn into_dataframe(self) -> Result<polars::frame::DataFrame, Self::E> {
+        self.into_dataframe_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts a resultset column by column into a Polars
+    /// `DataFrame`, using the given options; is only available with feature `polars`.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a value cannot be converted into the type of its column,
+    /// or if fetching fails.
+    #[cfg(feature = "polars")]
+    fn into_dataframe_with(
+        self,
+        options: &DeserializationOptions,
+    ) -> Result<polars::frame::DataFrame, Self::E> {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::into_dataframe_with()");
+        Ok(dataframe(typed_columns(self, options)?)?)
+    }
+
+    /// A _provided method_ that returns a view on the resultset whose rows only expose
+    /// the columns with the given names, in the given order.
+    ///
+    /// ```ignore
+    /// let projection = resultset.project(&["ID", "NAME"])?;
+    /// let data: Vec<MyNarrowStruct> = DeserializableResultset::try_into(projection)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a column does not exist or is given more than once.
+    fn project(self, names: &[&str]) -> Result<Projection<Self>, Self::E> {
+        let indices = names
+            .iter()
+            .map(|name| {
+                (0..self.number_of_columns())
+                    .find(|idx| self.fieldname(*idx) == Some(*name))
+                    .ok_or_else(|| {
+                        DeserializationError::Usage(format!("no column with name {name}"))
+                    })
+            })
+            .collect::<Result<Vec<usize>, DeserializationError>>()?;
+        self.project_at(&indices)
+    }
+
+    /// A _provided method_ that returns a view on the resultset whose rows only expose
+    /// the columns with the given indices, in the given order.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a column does not exist or is given more than once.
+    fn project_at(self, indices: &[usize]) -> Result<Projection<Self>, Self::E> {
+        Ok(Projection::try_new(self, indices.to_vec())?)
+    }
+
+    /// A _provided method_ that returns a view on the resultset that skips the first `n` rows.
+    ///
+    /// The skipped rows are dropped without being converted.
+    ///
+    /// ```ignore
+    /// let page = resultset.skip_rows(100).take_rows(50);
+    /// let data: Vec<MyStruct> = DeserializableResultset::try_into(page)?;
+    /// ```
+    fn skip_rows(self, n: usize) -> RowRange<Self> {
+        RowRange::new(self, n, None)
+    }
+
+    /// A _provided method_ that returns a view on the resultset that exposes
+    /// at most the first `n` rows.
+    fn take_rows(self, n: usize) -> RowRange<Self> {
+        RowRange::new(self, 0, Some(n))
+    }
+
+    /// A _provided method_ that translates the rows in the given range of row indices
+    /// into a given rust type.
+    ///
+    /// ```ignore
+    /// let data: Vec<MyStruct> = resultset.try_into_range(100..200)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_range<'de, T, R>(self, range: R) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => Some(end.saturating_add(1)),
+            Bound::Excluded(end) => Some(*end),
+            Bound::Unbounded => None,
+        };
+        let rows = RowRange::new(self, start, end.map(|end| end.saturating_sub(start)));
+        DeserializableResultset::try_into(rows)
+    }
+
+    /// A _provided method_ that converts the values of the column with the given name
+    /// into a `Vec<T>`, with one entry per row.
+    ///
+    /// The values of the other columns are dropped without being converted.
+    ///
+    /// ```ignore
+    /// let names: Vec<String> = resultset.column_into("NAME")?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn column_into<'de, T>(self, name: &str) -> Result<Vec<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.column_into_with(name, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the values of the column with the given name
+    /// into a `Vec<T>`, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn column_into_with<'de, T>(
+        self,
+        name: &str,
+        options: &DeserializationOptions,
+    ) -> Result<Vec<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        let col = (0..self.number_of_columns())
+            .find(|idx| self.fieldname(*idx) == Some(name))
+            .ok_or_else(|| DeserializationError::Usage(format!("no column with name {name}")))?;
+        self.column_at_into_with(col, options)
+    }
+
+    /// A _provided method_ that converts the values of the column with the given index
+    /// into a `Vec<T>`, with one entry per row.
+    ///
+    /// The values of the other columns are dropped without being converted.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn column_at_into<'de, T>(self, col: usize) -> Result<Vec<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.column_at_into_with(col, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the values of the column with the given index
+    /// into a `Vec<T>`, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn column_at_into_with<'de, T>(
+        mut self,
+        col: usize,
+        options: &DeserializationOptions,
+    ) -> Result<Vec<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::column_at_into_with()");
+        let mut values = Vec::with_capacity(self.remaining_rows().unwrap_or_default());
+        while let Some(row) = self.next()? {
+            values.push(DbValue::try_into_with(column_value(row, col)?, options)?);
+        }
+        Ok(values)
+    }
+
+    /// A _provided method_ that converts the single value of a resultset with
+    /// exactly one row and one column into `T`.
+    ///
+    /// ```ignore
+    /// let count: i64 = resultset.scalar()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::NoRows`, `DeserializationError::MultipleRows`, or
+    /// `DeserializationError::MultipleColumns` if the resultset does not have exactly
+    /// one row and one column; other errors if the conversion fails or fetching fails.
+    fn scalar<'de, T>(self) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.scalar_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the single value of a resultset with
+    /// exactly one row and one column into `T`, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::NoRows`, `DeserializationError::MultipleRows`, or
+    /// `DeserializationError::MultipleColumns` if the resultset does not have exactly
+    /// one row and one column; other errors if the conversion fails or fetching fails.
+    fn scalar_with<'de, T>(mut self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::scalar_with()");
+        if self.number_of_columns() > 1 {
+            return Err(DeserializationError::MultipleColumns.into());
+        }
+        if self.has_multiple_rows()? {
+            return Err(DeserializationError::MultipleRows.into());
+        }
+        let row = self.next()?.ok_or(DeserializationError::NoRows)?;
+        Ok(DbValue::try_into_with(column_value(row, 0)?, options)?)
+    }
+
+    /// A _provided method_ that converts the first row into `T` and ignores the other rows.
+    ///
+    /// ```ignore
+    /// let newest: Order = resultset.first_into()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::NoRows` if the resultset is empty; other errors if the
+    /// conversion fails or fetching fails.
+    fn first_into<'de, T>(self) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.first_into_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the first row into `T`, using the given options,
+    /// and ignores the other rows.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::NoRows` if the resultset is empty; other errors if the
+    /// conversion fails or fetching fails.
+    fn first_into_with<'de, T>(mut self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::first_into_with()");
+        let row = self.next()?.ok_or(DeserializationError::NoRows)?;
+        Ok(serde::Deserialize::deserialize(&mut RowDeserializer::new(
+            row, options,
+        ))?)
+    }
+
+    /// A _provided method_ that converts the only row into `T`, or returns `None`
+    /// if the resultset is empty.
+    ///
+    /// ```ignore
+    /// let customer: Option<Customer> = resultset.at_most_one_into()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::MultipleRows` if the resultset contains more than one row;
+    /// other errors if the conversion fails or fetching fails.
+    fn at_most_one_into<'de, T>(self) -> Result<Option<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.at_most_one_into_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the only row into `T`, using the given options,
+    /// or returns `None` if the resultset is empty.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::MultipleRows` if the resultset contains more than one row;
+    /// other errors if the conversion fails or fetching fails.
+    fn at_most_one_into_with<'de, T>(
+        mut self,
+        options: &DeserializationOptions,
+    ) -> Result<Option<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::at_most_one_into_with()");
+        if self.has_multiple_rows()? {
+            return Err(DeserializationError::MultipleRows.into());
+        }
+        match self.next()? {
+            None => Ok(None),
+            Some(row) => Ok(Some(serde::Deserialize::deserialize(
+                &mut RowDeserializer::new(row, options),
+            )?)),
+        }
+    }
+
+    /// A _provided method_ that converts all rows one by one into `T`, and collects
+    /// the conversion errors together with the index of the failing row,
+    /// rather than aborting on the first failure.
+    ///
+    /// ```ignore
+    /// let (good, bad): (Vec<Line>, _) = resultset.try_into_collect_errors()?;
+    /// for (idx, e) in bad {
+    ///     eprintln!("row {idx} was skipped: {e}");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is only produced if fetching fails.
+    #[allow(clippy::type_complexity)]
+    fn try_into_collect_errors<'de, T>(
+        self,
+    ) -> Result<(Vec<T>, Vec<(usize, DeserializationError)>), Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_collect_errors_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts all rows one by one into `T`, using the given options,
+    /// and collects the conversion errors together with the index of the failing row.
+    ///
+    /// # Errors
+    ///
+    /// An error is only produced if fetching fails.
+    #[allow(clippy::type_complexity)]
+    fn try_into_collect_errors_with<'de, T>(
+        mut self,
+        options: &DeserializationOptions,
+    ) -> Result<(Vec<T>, Vec<(usize, DeserializationError)>), Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::try_into_collect_errors_with()");
+        let mut values = Vec::with_capacity(self.remaining_rows().unwrap_or_default());
+        let mut errors = Vec::new();
+        let mut idx = 0;
+        while let Some(row) = self.next()? {
+            match serde::Deserialize::deserialize(
+                &mut RowDeserializer::new(row, options).with_row_index(idx),
+            ) {
+                Ok(value) => values.push(value),
+                Err(e) => errors.push((idx, e)),
+            }
+            idx += 1;
+        }
+        Ok((values, errors))
+    }
+
+    /// A _provided method_ that converts all rows into `T` and validates each of them
+    /// in the same pass with the given closure, which returns the reason for rejecting
+    /// a value.
+    ///
+    /// ```ignore
+    /// let bookings: Vec<Booking> = resultset.try_into_validated(|b: &Booking| {
+    ///     if b.end_date >= b.start_date {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("end_date is before start_date".to_string())
+    ///     }
+    /// })?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::ValidationFailed`, with the indices of all rejected rows
+    /// and the reasons, or any other error if deserialization into the target type
+    /// is not possible, or if fetching fails.
+    fn try_into_validated<'de, T, F>(self, validate: F) -> Result<Vec<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        F: FnMut(&T) -> Result<(), String>,
+    {
+        self.try_into_validated_with(validate, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts all rows into `T` and validates each of them,
+    /// using the given options.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError::ValidationFailed`, with the indices of all rejected rows
+    /// and the reasons, or any other error if deserialization into the target type
+    /// is not possible, or if fetching fails.
+    fn try_into_validated_with<'de, T, F>(
+        self,
+        validate: F,
+        options: &DeserializationOptions,
+    ) -> Result<Vec<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        F: FnMut(&T) -> Result<(), String>,
+    {
+        let (values, failures) = self.try_into_seed_with(ValidatingSeed::new(validate), options)?;
+        if failures.is_empty() {
+            Ok(values)
+        } else {
+            Err(DeserializationError::ValidationFailed(failures).into())
+        }
+    }
+
+    /// A _provided method_ that returns an iterator which converts the rows
+    /// lazily one by one into `T`.
+    ///
+    /// ```ignore
+    /// for line in resultset.typed_rows::<Line>() {
+    ///     let line = line?;
+    ///     // ...
+    /// }
+    /// ```
+    fn typed_rows<T>(self) -> TypedRows<Self, T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.typed_rows_with(&DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that returns an iterator which converts the rows
+    /// lazily one by one into `T`, using the given options.
+    fn typed_rows_with<T>(self, options: &DeserializationOptions) -> TypedRows<Self, T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        TypedRows::new(self, options.clone())
+    }
+
+    /// A _provided method_ that fetches and converts up to `sample_size` rows,
+    /// and measures the time that was spent.
+    ///
+    /// The returned [`ConversionEstimate`](struct.ConversionEstimate.html) contains
+    /// the converted rows and allows estimating the cost for converting the complete resultset,
+    /// so that an application can decide to continue, to paginate, or to give up,
+    /// before committing to a huge conversion.
+    /// The not yet converted rows remain in the resultset.
+    ///
+    /// ```ignore
+    /// let estimate = resultset.estimate_conversion::<MyStruct>(100)?;
+    /// if estimate.estimated_total_bytes().unwrap_or(0) > MAX_BYTES {
+    ///     return Err(...);
+    /// }
+    /// let mut data = estimate.sample;
+    /// data.append(&mut resultset.try_into::<Vec<MyStruct>>()?);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn estimate_conversion<'de, T>(
+        &mut self,
+        sample_size: usize,
+    ) -> Result<ConversionEstimate<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.estimate_conversion_with(sample_size, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that fetches and converts up to `sample_size` rows,
+    /// using the given options, and measures the time that was spent.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn estimate_conversion_with<'de, T>(
+        &mut self,
+        sample_size: usize,
+        options: &DeserializationOptions,
+    ) -> Result<ConversionEstimate<T>, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::estimate_conversion_with()");
+        let start = Instant::now();
+        let mut sample = Vec::with_capacity(sample_size);
+        let mut sample_bytes = 0;
+        while sample.len() < sample_size {
+            match self.next()? {
+                Some(row) => {
+                    sample_bytes += row.byte_len().unwrap_or_default();
+                    sample.push(serde::Deserialize::deserialize(&mut RowDeserializer::new(
+                        row, options,
+                    ))?);
+                }
+                None => break,
+            }
+        }
+        Ok(ConversionEstimate {
+            sample,
+            sample_duration: start.elapsed(),
+            sample_bytes,
+            remaining_rows: self.remaining_rows(),
+        })
+    }
+
+    /// A _provided method_ that converts the remaining rows one by one into `T`,
+    /// and folds them with `f`, without materializing all rows at once.
+    ///
+    /// ```ignore
+    /// let total: u64 = resultset.try_fold_rows(0, |sum, order: Order| sum + order.amount)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_fold_rows<'de, T, B, F>(&mut self, init: B, f: F) -> Result<B, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        F: FnMut(B, T) -> B,
+    {
+        self.try_fold_rows_with(init, f, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the remaining rows one by one into `T`,
+    /// using the given options, and folds them with `f`.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_fold_rows_with<'de, T, B, F>(
+        &mut self,
+        init: B,
+        mut f: F,
+        options: &DeserializationOptions,
+    ) -> Result<B, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        F: FnMut(B, T) -> B,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::try_fold_rows_with()");
+        let mut acc = init;
+        while let Some(row) = self.next()? {
+            acc = f(
+                acc,
+                serde::Deserialize::deserialize(&mut RowDeserializer::new(row, options))?,
+            );
+        }
+        Ok(acc)
+    }
+
+    /// A _provided method_ that converts the values of the specified column of the remaining
+    /// rows one by one into `Option<T>`, and folds them with `f`.
+    ///
+    /// The values of the other columns are dropped without being converted.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn try_fold_column<'de, T, B, F>(&mut self, col: usize, init: B, f: F) -> Result<B, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        F: FnMut(B, Option<T>) -> B,
+    {
+        self.try_fold_column_with(col, init, f, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that converts the values of the specified column of the remaining
+    /// rows one by one into `Option<T>`, using the given options, and folds them with `f`.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn try_fold_column_with<'de, T, B, F>(
+        &mut self,
+        col: usize,
+        init: B,
+        mut f: F,
+        options: &DeserializationOptions,
+    ) -> Result<B, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+        F: FnMut(B, Option<T>) -> B,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultset::try_fold_column_with()");
+        let mut acc = init;
+        while let Some(row) = self.next()? {
+            acc = f(
+                acc,
+                DbValue::try_into_with(column_value(row, col)?, options)?,
+            );
+        }
+        Ok(acc)
+    }
+
+    /// A _provided method_ that sums up the values of the specified column of the remaining
+    /// rows, ignoring NULL values.
+    ///
+    /// ```ignore
+    /// let total = resultset.sum_column_ignoring_nulls::<f64>(2)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn sum_column_ignoring_nulls<'de, T>(&mut self, col: usize) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de> + Default + std::ops::Add<Output = T>,
+    {
+        self.sum_column_ignoring_nulls_with(col, &DeserializationOptions::default())
+    }
+
+    /// A _provided method_ that sums up the values of the specified column of the remaining
+    /// rows, using the given options, and ignoring NULL values.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, if deserialization into
+    /// the target type is not possible, or if fetching fails.
+    fn sum_column_ignoring_nulls_with<'de, T>(
+        &mut self,
+        col: usize,
+        options: &DeserializationOptions,
+    ) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de> + Default + std::ops::Add<Output = T>,
+    {
+        self.try_fold_column_with(
+            col,
+            T::default(),
+            |sum, value: Option<T>| match value {
+                Some(value) => sum + value,
+                None => sum,
+            },
+            options,
+        )
+    }
+
+    /// A _provided method_ that counts the NULL values in the specified column
+    /// of the remaining rows, without converting any values.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the column does not exist, or if fetching fails.
+    fn count_nulls(&mut self, col: usize) -> Result<usize, Self::E> {
+        let mut count = 0;
+        while let Some(row) = self.next()? {
+            if column_value(row, col)?.is_null() {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+// Returns the value of the specified column, and drops the other values of the row.
+fn column_value<ROW: DeserializableRow>(mut row: ROW, col: usize) -> DeserializationResult<ROW::V> {
+    let idx = row.number_of_columns() - row.len();
+    for _ in idx..col {
+        row.next();
+    }
+    row.next()
+        .filter(|_| col >= idx)
+        .ok_or_else(|| DeserializationError::Usage(format!("no column with index {col}")))
+}
+
+impl<RS: DeserializableResultset> DeserializableResultset for &mut RS {
+    type E = RS::E;
+    type ROW = RS::ROW;
+
+    fn has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+        (**self).has_multiple_rows()
+    }
+
+    fn next(&mut self) -> DeserializationResult<Option<Self::ROW>> {
+        (**self).next()
+    }
+
+    fn number_of_fields(&self) -> usize {
+        (**self).number_of_fields()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        (**self).fieldname(field_idx)
+    }
+
+    fn fieldtype(&self, field_idx: usize) -> Option<&str> {
+        (**self).fieldtype(field_idx)
+    }
+
+    fn remaining_rows(&self) -> Option<usize> {
+        (**self).remaining_rows()
+    }
+
+    fn has_no_rows(&mut self) -> DeserializationResult<bool> {
+        (**self).has_no_rows()
+    }
+
+    fn number_of_columns(&self) -> usize {
+        (**self).number_of_columns()
+    }
+
+    fn take_row(&mut self, idx: usize) -> DeserializationResult<Option<Self::ROW>> {
+        (**self).take_row(idx)
+    }
 }
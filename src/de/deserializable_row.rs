@@ -1,17 +1,17 @@
+use crate::de::in_place::InPlace;
 use crate::de::row_deserializer::RowDeserializer;
-use crate::de::{DbValue, DeserializationError};
+use crate::de::{DbValue, DeserializationError, DeserializationOptions};
 use std::convert::From;
-use std::marker::Sized;
+use std::marker::{PhantomData, Sized};
 
 /// A minimal interface for the Row type to support the deserialization.
-#[allow(clippy::len_without_is_empty)]
 pub trait DeserializableRow: Sized {
     /// The error type used by the database driver.
     type E: From<DeserializationError> + Sized;
     /// The value type used by the database driver.
     type V: DbValue;
 
-    /// Returns the current length of the row (which is decremented with each call to next()).
+    /// Returns the current length of the row (which is decremented with each call to `next()`).
     fn len(&self) -> usize;
 
     /// Removes and returns the next value.
@@ -23,6 +23,31 @@ pub trait DeserializableRow: Sized {
     /// Returns the name of the column at the specified index.
     fn fieldname(&self, field_idx: usize) -> Option<&str>;
 
+    /// Returns true if all values of the row were consumed.
+    ///
+    /// The default implementation uses `len()`.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of columns of a complete row.
+    ///
+    /// The default implementation uses `number_of_fields()`.
+    fn number_of_columns(&self) -> usize {
+        self.number_of_fields()
+    }
+
+    /// Returns the approximate number of bytes that the remaining values of the row occupy
+    /// on the heap, like the lengths of texts and binary values,
+    /// or `None` if the driver does not track them.
+    ///
+    /// This is used for estimating the memory need of conversions, and is required for
+    /// [`DeserializationOptions::with_max_bytes()`](struct.DeserializationOptions.html#method.with_max_bytes).
+    /// The default implementation returns `None`.
+    fn byte_len(&self) -> Option<usize> {
+        None
+    }
+
     /// Converts the row into a struct, a tuple, or (if applicable) into a plain rust value.
     ///
     /// # Errors
@@ -32,8 +57,114 @@ pub trait DeserializableRow: Sized {
     where
         T: serde::Deserialize<'de>,
     {
-        Ok(serde::Deserialize::deserialize(&mut RowDeserializer::new(
-            self,
-        ))?)
+        self.try_into_with(&DeserializationOptions::default())
+    }
+
+    /// Converts the row into a struct, a tuple, or (if applicable) into a plain rust value,
+    /// using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_seed_with(PhantomData::<T>, options)
+    }
+
+    /// Converts a clone of the row into a struct, a tuple, or (if applicable) into a plain
+    /// rust value, so that the row can be converted again, e.g. into another target type,
+    /// or be kept for logging.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_to<'de, T>(&self) -> Result<T, Self::E>
+    where
+        Self: Clone,
+        T: serde::Deserialize<'de>,
+    {
+        self.clone().try_into()
+    }
+
+    /// Converts a clone of the row, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_to_with<'de, T>(&self, options: &DeserializationOptions) -> Result<T, Self::E>
+    where
+        Self: Clone,
+        T: serde::Deserialize<'de>,
+    {
+        self.clone().try_into_with(options)
+    }
+
+    /// Converts the row into an existing value, reusing its allocations where possible,
+    /// e.g. the buffers of `String` fields, when a struct is reused for all rows in a loop.
+    ///
+    /// Structs reuse their fields only if their `Deserialize` implementation
+    /// supports `deserialize_in_place()`, otherwise they are simply replaced.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_in_place<'de, T>(self, place: &mut T) -> Result<(), Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_in_place_with(place, &DeserializationOptions::default())
+    }
+
+    /// Converts the row into an existing value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_in_place_with<'de, T>(
+        self,
+        place: &mut T,
+        options: &DeserializationOptions,
+    ) -> Result<(), Self::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        self.try_into_seed_with(InPlace(place), options)
+    }
+
+    /// Converts the row with the given `serde::de::DeserializeSeed`, for target types
+    /// that need external state during deserialization.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_seed<'de, S>(self, seed: S) -> Result<S::Value, Self::E>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        self.try_into_seed_with(seed, &DeserializationOptions::default())
+    }
+
+    /// Converts the row with the given `serde::de::DeserializeSeed`, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_seed_with<'de, S>(
+        self,
+        seed: S,
+        options: &DeserializationOptions,
+    ) -> Result<S::Value, Self::E>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "deserialize_row",
+            target = std::any::type_name::<S::Value>()
+        )
+        .entered();
+        Ok(seed.deserialize(&mut RowDeserializer::new(self, options))?)
     }
 }
@@ -1,4 +1,5 @@
 use crate::de::ConversionError;
+use crate::message::{ErrorMessage, MessageFormatter};
 use thiserror::Error;
 
 /// The errors that can arise while deserializing with `serde_db::de`.
@@ -33,12 +34,165 @@ pub enum DeserializationError {
     /// The deserialization cannot consume all existing columns.
     #[error("cannot consume all existing columns")]
     TrailingCols,
+
+    /// A single value was requested, but the resultset is empty.
+    #[error("the resultset contains no rows")]
+    NoRows,
+
+    /// A single value or row was requested, but the resultset contains more than one row.
+    #[error("the resultset contains more than one row")]
+    MultipleRows,
+
+    /// A single value was requested, but the resultset contains more than one column.
+    #[error("the resultset contains more than one column")]
+    MultipleColumns,
+
+    /// The deserialization was aborted, because the cancellation flag was raised.
+    #[error("the deserialization was cancelled")]
+    Cancelled,
+
+    /// The resultset contains more rows than the configured limit allows.
+    #[error("the resultset exceeds the limit of {0} rows")]
+    RowLimitExceeded(usize),
+
+    /// The values of the resultset occupy more bytes than the configured budget allows.
+    #[error("the resultset exceeds the budget of {0} bytes")]
+    ByteLimitExceeded(usize),
+
+    /// The values of some columns were not used by the target struct, although
+    /// [`DeserializationOptions::with_deny_unused_columns()`](struct.DeserializationOptions.html#method.with_deny_unused_columns)
+    /// was set.
+    #[error("the target does not use the columns {0:?}")]
+    UnusedColumns(Vec<String>),
+
+    /// Some rows were rejected by the validation closure of
+    /// [`DeserializableResultset::try_into_validated()`](trait.DeserializableResultset.html#method.try_into_validated);
+    /// contains the indices of the rows and the reasons.
+    #[error("the validation failed for {} rows", .0.len())]
+    ValidationFailed(Vec<(usize, String)>),
+
+    /// The deserialization of a field failed; is only produced if
+    /// [`DeserializationOptions::with_field_paths()`](struct.DeserializationOptions.html#method.with_field_paths)
+    /// is set.
+    #[error("the deserialization of field {path} failed")]
+    AtField {
+        /// The path of the field, like `address.lines[2]`.
+        path: String,
+        /// The underlying error.
+        source: Box<DeserializationError>,
+    },
+}
+
+impl DeserializationError {
+    /// Returns the structured data of the error.
+    #[must_use]
+    pub fn message(&self) -> ErrorMessage<'_> {
+        match *self {
+            Self::ConversionError(ref e) => e.message(),
+            Self::SerdeError(ref s) => ErrorMessage::Serde(s),
+            Self::Usage(ref s) => ErrorMessage::Usage(s),
+            Self::NotImplemented(s) => ErrorMessage::NotImplemented(s),
+            Self::UnknownField(ref s) => ErrorMessage::UnknownField(s),
+            Self::TrailingRows => ErrorMessage::TrailingRows,
+            Self::TrailingCols => ErrorMessage::TrailingCols,
+            Self::NoRows => ErrorMessage::NoRows,
+            Self::MultipleRows => ErrorMessage::MultipleRows,
+            Self::MultipleColumns => ErrorMessage::MultipleColumns,
+            Self::Cancelled => ErrorMessage::Cancelled,
+            Self::RowLimitExceeded(max_rows) => ErrorMessage::RowLimitExceeded(max_rows),
+            Self::ByteLimitExceeded(max_bytes) => ErrorMessage::ByteLimitExceeded(max_bytes),
+            Self::UnusedColumns(ref columns) => ErrorMessage::UnusedColumns(columns),
+            Self::ValidationFailed(ref failures) => ErrorMessage::ValidationFailed(failures),
+            Self::AtField {
+                ref path,
+                ref source,
+            } => ErrorMessage::AtField {
+                path,
+                cause: Box::new(source.message()),
+            },
+        }
+    }
+
+    /// Returns the path of the field whose deserialization failed, if known.
+    #[must_use]
+    pub fn field_path(&self) -> Option<&str> {
+        match *self {
+            Self::AtField { ref path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error of the failing field, or the error itself.
+    #[must_use]
+    pub fn leaf(&self) -> &DeserializationError {
+        match *self {
+            Self::AtField { ref source, .. } => source,
+            _ => self,
+        }
+    }
+
+    // Adds the given name or `[index]` in front of the field path.
+    pub(crate) fn at_field(self, segment: &str) -> Self {
+        match self {
+            Self::AtField { path, source } => {
+                let separator = if path.starts_with('[') { "" } else { "." };
+                Self::AtField {
+                    path: format!("{segment}{separator}{path}"),
+                    source,
+                }
+            }
+            source => Self::AtField {
+                path: segment.to_string(),
+                source: Box::new(source),
+            },
+        }
+    }
+
+    // Replaces all descriptions that might contain the value of the given column.
+    pub(crate) fn redacted(self, column: &str, target: &str) -> Self {
+        let text = || format!("the value of column {column} cannot be converted into {target}");
+        match self {
+            Self::ConversionError(e) => Self::ConversionError(match e {
+                ConversionError::ValueType(_) => ConversionError::ValueType(text()),
+                ConversionError::NumberRange(_) => ConversionError::NumberRange(text()),
+                ConversionError::Incomplete(_) => ConversionError::Incomplete(text()),
+                ConversionError::Json(_) => ConversionError::Json(text()),
+                ConversionError::Other(_) => ConversionError::Other(text().into()),
+                e => e,
+            }),
+            Self::SerdeError(_) => Self::SerdeError(text()),
+            Self::Usage(_) => Self::Usage(text()),
+            Self::AtField { path, source } => Self::AtField {
+                path,
+                source: Box::new(source.redacted(column, target)),
+            },
+            e => e,
+        }
+    }
+
+    /// Renders the error with the given formatter,
+    /// e.g. for producing localized error messages.
+    #[must_use]
+    pub fn render(&self, formatter: &dyn MessageFormatter) -> String {
+        formatter.format(&self.message())
+    }
 }
 
 impl serde::de::Error for DeserializationError {
     fn custom<T: std::fmt::Display>(msg: T) -> Self {
         DeserializationError::SerdeError(msg.to_string())
     }
+
+    fn invalid_value(unexp: serde::de::Unexpected, exp: &dyn serde::de::Expected) -> Self {
+        // serde's impls for the non-zero integers, like NonZeroU32, report zero like this
+        let expected = exp.to_string();
+        match expected.strip_prefix("a nonzero ") {
+            Some(primitive) if unexp == serde::de::Unexpected::Unsigned(0) => {
+                ConversionError::Zero(primitive.to_string()).into()
+            }
+            _ => Self::custom(format_args!("invalid value: {unexp}, expected {expected}")),
+        }
+    }
 }
 
 impl std::fmt::Debug for DeserializationError {
@@ -49,7 +203,17 @@ impl std::fmt::Debug for DeserializationError {
             Self::SerdeError(ref s) | Self::UnknownField(ref s) | Self::Usage(ref s) => {
                 write!(formatter, "{self}: {s}")
             }
-            Self::TrailingRows | Self::TrailingCols => write!(formatter, "{self}"),
+            Self::TrailingRows
+            | Self::TrailingCols
+            | Self::NoRows
+            | Self::MultipleRows
+            | Self::MultipleColumns
+            | Self::Cancelled
+            | Self::RowLimitExceeded(_)
+            | Self::ByteLimitExceeded(_)
+            | Self::UnusedColumns(_) => write!(formatter, "{self}"),
+            Self::ValidationFailed(ref failures) => write!(formatter, "{self}: {failures:?}"),
+            Self::AtField { ref source, .. } => write!(formatter, "{self}: {source:?}"),
         }
     }
 }
@@ -0,0 +1,88 @@
+/// Receives structured events during deserialization.
+///
+/// Register an observer with
+/// [`DeserializationOptions::with_observer()`](struct.DeserializationOptions.html#method.with_observer),
+/// e.g. for collecting NULL rates per column during normal operation of an application,
+/// or for tracking the deserialization cost per statement.
+/// All methods have default implementations that do nothing.
+///
+/// Events are only emitted for values that are deserialized as part of a row.
+pub trait DeserializationObserver: Send + Sync {
+    /// Is called whenever a NULL value is deserialized.
+    fn null_encountered(&self, _event: &NullEvent) {}
+
+    /// Is called when the deserialization deviates from the normal behavior.
+    fn warning(&self, _warning: &DeserializationWarning) {}
+
+    /// Is called during the conversion of a resultset after every n-th row, with
+    /// the number of rows deserialized so far, if a progress interval is set with
+    /// [`DeserializationOptions::with_progress_interval()`](struct.DeserializationOptions.html#method.with_progress_interval).
+    fn progress(&self, _rows: usize) {}
+
+    /// Is called after a row was deserialized into a struct, with the names of the columns
+    /// whose values were dropped without being converted, e.g. due to
+    /// [`UnknownColumnPolicy::Ignore`](enum.UnknownColumnPolicy.html#variant.Ignore)
+    /// or a field mask, if there are such columns.
+    ///
+    /// When a resultset is converted, this is only called for its first row.
+    fn unused_columns(&self, _columns: &[&str]) {}
+
+    /// Is called when the conversion of a complete resultset has ended.
+    fn resultset_deserialized(&self, _metrics: &ResultsetMetrics) {}
+}
+
+/// Describes the cost of the conversion of a resultset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResultsetMetrics {
+    /// The number of rows that were deserialized.
+    pub rows: usize,
+    /// The number of bytes that the values of these rows occupied, as reported by
+    /// [`DeserializableRow::byte_len()`](trait.DeserializableRow.html#method.byte_len),
+    /// or 0 if the driver does not track them.
+    pub bytes: usize,
+    /// The time the conversion took, including the time for fetching rows.
+    pub elapsed: std::time::Duration,
+    /// Whether the conversion succeeded.
+    pub succeeded: bool,
+}
+
+/// Describes a NULL value that was encountered during deserialization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NullEvent<'a> {
+    /// The index of the column.
+    pub column_index: usize,
+    /// The name of the column, if available.
+    pub column_name: Option<&'a str>,
+    /// What happened to the NULL value.
+    pub outcome: NullOutcome,
+}
+
+/// What happened to a NULL value during deserialization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NullOutcome {
+    /// The target accepted the NULL, usually an `Option` that is set to `None`.
+    Accepted,
+    /// The NULL was replaced with the default value of the target type, due to
+    /// [`NullPolicy::TypeDefault`](enum.NullPolicy.html#variant.TypeDefault).
+    Defaulted,
+    /// The conversion failed, because the target cannot represent NULL.
+    Error,
+    /// The column was omitted from the row struct, due to
+    /// [`NullPolicy::Omit`](enum.NullPolicy.html#variant.Omit).
+    Omitted,
+}
+
+/// Describes a deviation from the normal deserialization behavior.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializationWarning {
+    /// The row has no field names, so its values were assigned to the fields of the struct
+    /// by position, due to
+    /// [`FieldnameFallback::Positional`](enum.FieldnameFallback.html#variant.Positional).
+    PositionalFallback {
+        /// The name of the target struct.
+        struct_name: &'static str,
+    },
+}
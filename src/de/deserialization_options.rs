@@ -0,0 +1,878 @@
+use crate::de::{ConversionError, DeserializationObserver, TextDecoder};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Options that control how database values are converted during deserialization.
+///
+/// The plain `try_into()` methods use the default options;
+/// use the `try_into_with()` methods of
+/// [`DeserializableResultset`](trait.DeserializableResultset.html#method.try_into_with),
+/// [`DeserializableRow`](trait.DeserializableRow.html#method.try_into_with), or
+/// [`DbValue`](trait.DbValue.html#method.try_into_with) to apply other options.
+///
+/// ```rust
+/// use serde_db::de::{DeserializationOptions, Utf8Policy};
+/// let options = DeserializationOptions::default().with_utf8_policy(Utf8Policy::Lossy);
+/// assert_eq!(options.utf8_policy(), Utf8Policy::Lossy);
+/// ```
+#[derive(Clone, Default)]
+pub struct DeserializationOptions {
+    utf8_policy: Utf8Policy,
+    observer: Option<Arc<dyn DeserializationObserver>>,
+    prefix_separator: Option<String>,
+    field_mask: Option<Vec<String>>,
+    sensitive_columns: Vec<String>,
+    field_name_matching: FieldNameMatching,
+    null_policy: NullPolicy,
+    unknown_column_policy: UnknownColumnPolicy,
+    deny_unused_columns: bool,
+    field_paths: bool,
+    human_readable: Option<bool>,
+    numeric_policy: Option<NumericPolicy>,
+    bool_coercion: Option<BoolCoercion>,
+    fieldname_fallback: FieldnameFallback,
+    timezone_policy: TimezonePolicy,
+    char_padding: CharPadding,
+    text_decoder: Option<Arc<dyn TextDecoder>>,
+    non_finite_policy: NonFinitePolicy,
+    rounding_mode: RoundingMode,
+    progress_interval: Option<usize>,
+    cancellation_flag: Option<Arc<AtomicBool>>,
+    max_rows: Option<usize>,
+    max_bytes: Option<usize>,
+}
+
+impl DeserializationOptions {
+    /// Sets the policy for text values that are not valid UTF-8.
+    #[must_use]
+    pub fn with_utf8_policy(mut self, utf8_policy: Utf8Policy) -> Self {
+        self.utf8_policy = utf8_policy;
+        self
+    }
+
+    /// Returns the policy for text values that are not valid UTF-8.
+    #[must_use]
+    pub fn utf8_policy(&self) -> Utf8Policy {
+        self.utf8_policy
+    }
+
+    /// Sets a decoder for raw text values that are not encoded in UTF-8.
+    ///
+    /// If a decoder is set, it is used instead of the UTF-8 policy.
+    #[must_use]
+    pub fn with_text_decoder(mut self, text_decoder: Arc<dyn TextDecoder>) -> Self {
+        self.text_decoder = Some(text_decoder);
+        self
+    }
+
+    /// Returns the decoder for raw text values, if one is set.
+    #[must_use]
+    pub fn text_decoder(&self) -> Option<&dyn TextDecoder> {
+        self.text_decoder.as_deref()
+    }
+
+    /// Sets an observer that is informed about events during deserialization.
+    #[must_use]
+    pub fn with_observer(mut self, observer: Arc<dyn DeserializationObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Returns the observer, if one is set.
+    #[must_use]
+    pub fn observer(&self) -> Option<&dyn DeserializationObserver> {
+        self.observer.as_deref()
+    }
+
+    /// Lets the observer be informed about the progress of resultset conversions.
+    ///
+    /// [`DeserializationObserver::progress()`](trait.DeserializationObserver.html#method.progress)
+    /// is then called after every `rows` rows that were deserialized into the elements of
+    /// a sequence, like a `Vec<MyStruct>`; `0` disables the progress reports.
+    #[must_use]
+    pub fn with_progress_interval(mut self, rows: usize) -> Self {
+        self.progress_interval = if rows == 0 { None } else { Some(rows) };
+        self
+    }
+
+    /// Returns the number of rows after which the progress is reported, if set.
+    #[must_use]
+    pub fn progress_interval(&self) -> Option<usize> {
+        self.progress_interval
+    }
+
+    /// Sets a flag with which a running resultset conversion can be aborted cooperatively,
+    /// e.g. from another thread.
+    ///
+    /// The flag is checked before each row of a sequence is fetched; once it is set,
+    /// the conversion fails with
+    /// [`DeserializationError::Cancelled`](enum.DeserializationError.html#variant.Cancelled).
+    #[must_use]
+    pub fn with_cancellation_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancellation_flag = Some(flag);
+        self
+    }
+
+    /// Returns true if a cancellation flag is set and was raised.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
+    /// Limits the number of rows that a resultset conversion may materialize.
+    ///
+    /// Conversions of resultsets with more rows fail with
+    /// [`DeserializationError::RowLimitExceeded`](enum.DeserializationError.html#variant.RowLimitExceeded)
+    /// as soon as the first surplus row is fetched,
+    /// which protects services from accidentally loading a runaway SELECT into memory.
+    #[must_use]
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Returns the maximal number of rows, if a limit is set.
+    #[must_use]
+    pub fn max_rows(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    /// Limits the number of bytes that the values of the rows of a resultset conversion
+    /// may occupy, as reported by
+    /// [`DeserializableRow::byte_len()`](trait.DeserializableRow.html#method.byte_len).
+    ///
+    /// Conversions of resultsets with more data fail with
+    /// [`DeserializationError::ByteLimitExceeded`](enum.DeserializationError.html#variant.ByteLimitExceeded)
+    /// as soon as the row is fetched that exceeds the budget.
+    ///
+    /// The budget requires a driver whose rows implement `byte_len()`;
+    /// conversions of rows that do not report their size fail with
+    /// `DeserializationError::Usage`, rather than ignoring the budget.
+    /// Like the limit of [`with_max_rows()`](#method.with_max_rows), the budget applies
+    /// to the conversions of complete resultsets, i.e. `try_into_with()`,
+    /// `try_into_columns_with()`, and `write_csv_with()`.
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Returns the maximal number of bytes, if a budget is set.
+    #[must_use]
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    /// Enables nested structs via column-name prefixes.
+    ///
+    /// With separator `"_"`, a row with columns `ID`, `ADDRESS_STREET`, `ADDRESS_CITY`
+    /// can be deserialized into a struct with fields `ID` and `ADDRESS`,
+    /// where `ADDRESS` is a struct with fields `STREET` and `CITY`.
+    /// The columns of a nested struct must be consecutive.
+    /// Columns that match a field of the target struct exactly are not treated as prefixed.
+    #[must_use]
+    pub fn with_prefix_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.prefix_separator = Some(separator.into());
+        self
+    }
+
+    /// Returns the separator for column-name prefixes, if nesting is enabled.
+    #[must_use]
+    pub fn prefix_separator(&self) -> Option<&str> {
+        self.prefix_separator.as_deref()
+    }
+
+    /// Restricts the conversion of rows into structs to the given fields.
+    ///
+    /// The columns of all other fields are skipped without being converted,
+    /// so that a single struct can serve both cheap "list views" and full "detail views"
+    /// of very wide rows.
+    /// The skipped fields are handled by serde like missing fields,
+    /// so they must be of type `Option`, or have a `#[serde(default)]` attribute.
+    ///
+    /// The mask applies to the top-level fields of row structs; a nested struct
+    /// (see [`with_prefix_separator`](#method.with_prefix_separator)) is converted completely
+    /// if its field is contained in the mask.
+    #[must_use]
+    pub fn with_field_mask<S: AsRef<str>>(mut self, fields: &[S]) -> Self {
+        self.field_mask = Some(fields.iter().map(|f| f.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Returns the field mask, if one is set.
+    #[must_use]
+    pub fn field_mask(&self) -> Option<&[String]> {
+        self.field_mask.as_deref()
+    }
+
+    /// Marks columns whose values must not appear in error messages, like passwords or tokens.
+    ///
+    /// If the conversion of a value of such a column fails, the error only names the column
+    /// and the target type; the description of the driver, which may contain the value,
+    /// is dropped. The names are compared with the column names like field names, i.e.,
+    /// also with the configured [`FieldNameMatching`](enum.FieldNameMatching.html).
+    #[must_use]
+    pub fn with_sensitive_columns<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.sensitive_columns = columns.iter().map(|c| c.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Returns the columns whose values must not appear in error messages.
+    #[must_use]
+    pub fn sensitive_columns(&self) -> &[String] {
+        &self.sensitive_columns
+    }
+
+    /// Sets the strategy for pairing column names with the fields of row structs.
+    #[must_use]
+    pub fn with_field_name_matching(mut self, field_name_matching: FieldNameMatching) -> Self {
+        self.field_name_matching = field_name_matching;
+        self
+    }
+
+    /// Returns the strategy for pairing column names with the fields of row structs.
+    #[must_use]
+    pub fn field_name_matching(&self) -> &FieldNameMatching {
+        &self.field_name_matching
+    }
+
+    /// Sets the policy for NULL values whose target cannot represent NULL.
+    #[must_use]
+    pub fn with_null_policy(mut self, null_policy: NullPolicy) -> Self {
+        self.null_policy = null_policy;
+        self
+    }
+
+    /// Returns the policy for NULL values whose target cannot represent NULL.
+    #[must_use]
+    pub fn null_policy(&self) -> NullPolicy {
+        self.null_policy
+    }
+
+    /// Sets the policy for columns that have no corresponding field in the target struct.
+    #[must_use]
+    pub fn with_unknown_column_policy(mut self, policy: UnknownColumnPolicy) -> Self {
+        self.unknown_column_policy = policy;
+        self
+    }
+
+    /// Returns the policy for columns that have no corresponding field in the target struct.
+    #[must_use]
+    pub fn unknown_column_policy(&self) -> UnknownColumnPolicy {
+        self.unknown_column_policy
+    }
+
+    /// Lets the deserialization of a row into a struct fail with
+    /// `DeserializationError::UnusedColumns` if the values of some columns were not used,
+    /// like serde's `#[serde(deny_unknown_fields)]`.
+    ///
+    /// This is stricter than [`UnknownColumnPolicy::Error`](enum.UnknownColumnPolicy.html),
+    /// as it also covers columns that are excluded by a field mask or
+    /// that are left over when values are assigned by position.
+    #[must_use]
+    pub fn with_deny_unused_columns(mut self, deny: bool) -> Self {
+        self.deny_unused_columns = deny;
+        self
+    }
+
+    /// Returns true if unused columns let the deserialization fail.
+    #[must_use]
+    pub fn deny_unused_columns(&self) -> bool {
+        self.deny_unused_columns
+    }
+
+    /// Lets errors that occur in a field of the target be wrapped into
+    /// `DeserializationError::AtField`, which contains the path of the field,
+    /// like `address.lines[2]`, also for fields of nested structs and arrays.
+    /// Errors within JSON documents have the path of the JSON column, and their message
+    /// contains the position within the document.
+    #[must_use]
+    pub fn with_field_paths(mut self, field_paths: bool) -> Self {
+        self.field_paths = field_paths;
+        self
+    }
+
+    /// Returns true if errors contain the path of the failing field.
+    #[must_use]
+    pub fn field_paths(&self) -> bool {
+        self.field_paths
+    }
+
+    /// Sets what the deserializers report with `serde::Deserializer::is_human_readable()`.
+    ///
+    /// Types like `std::net::IpAddr`, `uuid::Uuid` or the date and time types of `chrono`
+    /// and `time` expect their textual representation if this is true (the default),
+    /// and a compact binary representation otherwise;
+    /// which one fits depends on how the database driver provides such values.
+    #[must_use]
+    pub fn with_human_readable(mut self, human_readable: bool) -> Self {
+        self.human_readable = Some(human_readable);
+        self
+    }
+
+    /// Returns true if the deserializers report to be human readable.
+    #[must_use]
+    pub fn is_human_readable(&self) -> bool {
+        self.human_readable.unwrap_or(true)
+    }
+
+    /// Sets the policy for converting numbers into numeric types of a different kind or size.
+    ///
+    /// The policy applies to values that expose their content with
+    /// [`DbValue::number()`](trait.DbValue.html#method.number) or
+    /// [`DbValue::decimal_parts()`](trait.DbValue.html#method.decimal_parts).
+    /// Without a policy, the conversion is done by the `DbValueInto` implementations
+    /// of the driver.
+    #[must_use]
+    pub fn with_numeric_policy(mut self, numeric_policy: NumericPolicy) -> Self {
+        self.numeric_policy = Some(numeric_policy);
+        self
+    }
+
+    /// Returns the policy for numeric conversions, if one is set.
+    #[must_use]
+    pub fn numeric_policy(&self) -> Option<NumericPolicy> {
+        self.numeric_policy
+    }
+
+    /// Sets how fractional values are converted into integer types.
+    ///
+    /// The rounding mode is only applied if a
+    /// [numeric policy](#method.with_numeric_policy) is set, and it is ignored with
+    /// [`NumericPolicy::Strict`](enum.NumericPolicy.html#variant.Strict),
+    /// which never converts fractional values into integers.
+    #[must_use]
+    pub fn with_rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+
+    /// Returns how fractional values are converted into integer types.
+    #[must_use]
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    /// Enables the coercion of integers and text tokens like `'Y'` or `'N'` into `bool`.
+    ///
+    /// ```rust
+    /// use serde_db::de::{BoolCoercion, DeserializationOptions};
+    /// let options = DeserializationOptions::default()
+    ///     .with_bool_coercion(BoolCoercion::default().with_tokens(&["J", "Y"], &["N"]));
+    /// ```
+    #[must_use]
+    pub fn with_bool_coercion(mut self, bool_coercion: BoolCoercion) -> Self {
+        self.bool_coercion = Some(bool_coercion);
+        self
+    }
+
+    /// Returns the coercion rules for `bool`, if coercion is enabled.
+    #[must_use]
+    pub fn bool_coercion(&self) -> Option<&BoolCoercion> {
+        self.bool_coercion.as_ref()
+    }
+
+    /// Sets what happens if a row without field names is deserialized into a struct.
+    #[must_use]
+    pub fn with_fieldname_fallback(mut self, fieldname_fallback: FieldnameFallback) -> Self {
+        self.fieldname_fallback = fieldname_fallback;
+        self
+    }
+
+    /// Returns what happens if a row without field names is deserialized into a struct.
+    #[must_use]
+    pub fn fieldname_fallback(&self) -> FieldnameFallback {
+        self.fieldname_fallback
+    }
+
+    /// Sets how naive timestamps are interpreted when they are deserialized into
+    /// timezone-aware types with [`timezone_aware()`](fn.timezone_aware.html).
+    #[must_use]
+    pub fn with_timezone_policy(mut self, timezone_policy: TimezonePolicy) -> Self {
+        self.timezone_policy = timezone_policy;
+        self
+    }
+
+    /// Returns how naive timestamps are interpreted for timezone-aware types.
+    #[must_use]
+    pub fn timezone_policy(&self) -> TimezonePolicy {
+        self.timezone_policy
+    }
+
+    /// Sets whether the blank padding of fixed-width text values (like `CHAR(n)`)
+    /// is removed during string conversion.
+    #[must_use]
+    pub fn with_char_padding(mut self, char_padding: CharPadding) -> Self {
+        self.char_padding = char_padding;
+        self
+    }
+
+    /// Returns whether the blank padding of fixed-width text values is removed.
+    #[must_use]
+    pub fn char_padding(&self) -> CharPadding {
+        self.char_padding
+    }
+
+    /// Sets how NaN and infinite values are handled for floating point targets.
+    #[must_use]
+    pub fn with_non_finite_policy(mut self, non_finite_policy: NonFinitePolicy) -> Self {
+        self.non_finite_policy = non_finite_policy;
+        self
+    }
+
+    /// Returns how NaN and infinite values are handled for floating point targets.
+    #[must_use]
+    pub fn non_finite_policy(&self) -> NonFinitePolicy {
+        self.non_finite_policy
+    }
+
+    // Returns true if errors must not contain the values of the column.
+    pub(crate) fn is_sensitive_column(&self, column: &str) -> bool {
+        self.sensitive_columns
+            .iter()
+            .any(|name| name == column || self.field_name_matching.matches(column, name))
+    }
+
+    // Returns true if the field is to be converted.
+    pub(crate) fn is_in_field_mask(&self, field: &str) -> bool {
+        self.field_mask
+            .as_ref()
+            .is_none_or(|mask| mask.iter().any(|f| f == field))
+    }
+}
+
+impl std::fmt::Debug for DeserializationOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DeserializationOptions")
+            .field("utf8_policy", &self.utf8_policy)
+            .field("observer", &self.observer.as_ref().map(|_| ".."))
+            .field("progress_interval", &self.progress_interval)
+            .field("cancellation_flag", &self.cancellation_flag)
+            .field("max_rows", &self.max_rows)
+            .field("max_bytes", &self.max_bytes)
+            .field("prefix_separator", &self.prefix_separator)
+            .field("field_mask", &self.field_mask)
+            .field("sensitive_columns", &self.sensitive_columns)
+            .field("field_name_matching", &self.field_name_matching)
+            .field("null_policy", &self.null_policy)
+            .field("unknown_column_policy", &self.unknown_column_policy)
+            .field("deny_unused_columns", &self.deny_unused_columns)
+            .field("field_paths", &self.field_paths)
+            .field("human_readable", &self.is_human_readable())
+            .field("numeric_policy", &self.numeric_policy)
+            .field("rounding_mode", &self.rounding_mode)
+            .field("bool_coercion", &self.bool_coercion)
+            .field("fieldname_fallback", &self.fieldname_fallback)
+            .field("timezone_policy", &self.timezone_policy)
+            .field("char_padding", &self.char_padding)
+            .field("text_decoder", &self.text_decoder.as_ref().map(|_| ".."))
+            .field("non_finite_policy", &self.non_finite_policy)
+            .finish()
+    }
+}
+
+/// Describes how column names are paired with the fields of row structs.
+///
+/// A column whose name equals a field name is always paired with that field;
+/// the strategy is only applied to the other columns.
+///
+/// ```rust
+/// use serde_db::de::FieldNameMatching;
+/// assert!(FieldNameMatching::CaseInsensitive.matches("CUSTOMER_ID", "customer_id"));
+/// assert!(FieldNameMatching::SnakeCase.matches("customerId", "customer_id"));
+/// assert!(!FieldNameMatching::Exact.matches("ID", "id"));
+/// ```
+#[derive(Clone, Default)]
+pub enum FieldNameMatching {
+    /// Column names must be equal to the field names.
+    #[default]
+    Exact,
+    /// Column names and field names are compared case-insensitively.
+    CaseInsensitive,
+    /// Column names and field names are compared after normalizing them to `snake_case`,
+    /// so that e.g. `CUSTOMER_ID`, `CustomerId`, and `customerId` match `customer_id`.
+    SnakeCase,
+    /// A custom function decides if a column name (first argument)
+    /// matches a field name (second argument).
+    Custom(Arc<MatchFn>),
+}
+
+type MatchFn = dyn Fn(&str, &str) -> bool + Send + Sync;
+
+impl FieldNameMatching {
+    /// Returns true if the column name matches the field name.
+    #[must_use]
+    pub fn matches(&self, column: &str, field: &str) -> bool {
+        match self {
+            Self::Exact => column == field,
+            Self::CaseInsensitive => column.eq_ignore_ascii_case(field),
+            Self::SnakeCase => SnakeCaseChars::new(column).eq(SnakeCaseChars::new(field)),
+            Self::Custom(f) => f(column, field),
+        }
+    }
+}
+
+impl std::fmt::Debug for FieldNameMatching {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Exact => f.write_str("Exact"),
+            Self::CaseInsensitive => f.write_str("CaseInsensitive"),
+            Self::SnakeCase => f.write_str("SnakeCase"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+// Yields the characters of the `snake_case` form of a name,
+// so that names can be compared without allocating.
+struct SnakeCaseChars<'a> {
+    chars: std::str::Chars<'a>,
+    pending: Option<std::char::ToLowercase>,
+    prev_is_lower_or_digit: bool,
+}
+
+impl<'a> SnakeCaseChars<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            chars: s.chars(),
+            pending: None,
+            prev_is_lower_or_digit: false,
+        }
+    }
+}
+
+impl Iterator for SnakeCaseChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.pending.as_mut().and_then(Iterator::next) {
+            return Some(c);
+        }
+        let c = self.chars.next()?;
+        if c == '-' || c == ' ' || c == '_' {
+            self.prev_is_lower_or_digit = false;
+            Some('_')
+        } else if c.is_uppercase() {
+            let mut lowercase = c.to_lowercase();
+            let underscore = std::mem::take(&mut self.prev_is_lower_or_digit);
+            let first = if underscore {
+                Some('_')
+            } else {
+                lowercase.next()
+            };
+            self.pending = Some(lowercase);
+            first
+        } else {
+            self.prev_is_lower_or_digit = c.is_lowercase() || c.is_ascii_digit();
+            Some(c)
+        }
+    }
+}
+
+/// Describes how NULL values are handled whose target is not an `Option`.
+///
+/// ```rust
+/// use serde_db::de::{DeserializationOptions, NullPolicy};
+/// let options = DeserializationOptions::default().with_null_policy(NullPolicy::Omit);
+/// assert_eq!(options.null_policy(), NullPolicy::Omit);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// NULL values can only be deserialized into `Option`s; other targets produce an error.
+    #[default]
+    Strict,
+    /// NULL values are deserialized into the default value of the target type,
+    /// i.e., `false`, `0`, an empty string, an empty `Vec`, or an empty map.
+    /// Structs, tuples, and enums still produce an error.
+    TypeDefault,
+    /// Columns with NULL values are omitted when a row is deserialized into a struct,
+    /// so that serde treats the fields as missing: `Option` fields are set to `None`,
+    /// fields with `#[serde(default)]` get their `Default` value, and fields with
+    /// `#[serde(default = "path")]` get the value of the given function.
+    /// Other fields produce an error.
+    Omit,
+}
+
+/// Describes how columns are handled that have no corresponding field in the target struct.
+///
+/// ```rust
+/// use serde_db::de::{DeserializationOptions, UnknownColumnPolicy};
+/// let options =
+///     DeserializationOptions::default().with_unknown_column_policy(UnknownColumnPolicy::Ignore);
+/// assert_eq!(options.unknown_column_policy(), UnknownColumnPolicy::Ignore);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnknownColumnPolicy {
+    /// The deserialization fails with `DeserializationError::UnknownField`.
+    #[default]
+    Error,
+    /// The values of such columns are dropped without being converted, and the observer
+    /// is informed with
+    /// [`DeserializationObserver::unused_columns()`](trait.DeserializationObserver.html#method.unused_columns).
+    Ignore,
+}
+
+/// Describes which numeric conversions are allowed, e.g. from `f64` to `i32`,
+/// or from `i64` to `i8`.
+///
+/// See [`DbNumber::convert()`](enum.DbNumber.html#method.convert).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumericPolicy {
+    /// Only lossless conversions are allowed: values must be in the range of the target type,
+    /// fractional values cannot be converted into integers, and integers can only be converted
+    /// into floating point types if they are represented exactly.
+    Strict,
+    /// Values must be in the range of the target type, but the fractional part of values
+    /// and the precision of floating point values can be lost.
+    Checked,
+    /// All conversions succeed: values out of the range of the target type are
+    /// saturated, fractional parts are removed according to the
+    /// [`RoundingMode`], and NaN becomes 0.
+    Lossy,
+}
+
+/// Describes how fractional values are converted into integer types.
+///
+/// See [`DbNumber::convert_rounded()`](enum.DbNumber.html#method.convert_rounded).
+///
+/// ```rust
+/// use serde_db::de::{DbNumber, NumericPolicy, RoundingMode};
+/// let n = DbNumber::Float(2.5);
+/// assert_eq!(n.convert_rounded::<i32>(NumericPolicy::Checked, RoundingMode::Truncate).unwrap(), 2);
+/// assert_eq!(n.convert_rounded::<i32>(NumericPolicy::Checked, RoundingMode::HalfEven).unwrap(), 2);
+/// assert_eq!(DbNumber::Float(3.5).convert_rounded::<i32>(NumericPolicy::Checked, RoundingMode::HalfEven).unwrap(), 4);
+/// assert!(n.convert_rounded::<i32>(NumericPolicy::Checked, RoundingMode::Reject).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// The fractional part is discarded, i.e., values are rounded towards zero.
+    #[default]
+    Truncate,
+    /// Values are rounded to the nearest integer, and to the even one if they are
+    /// exactly halfway between two integers ("banker's rounding").
+    HalfEven,
+    /// Fractional values produce an error.
+    Reject,
+}
+
+impl RoundingMode {
+    // Removes the fractional part of a value.
+    pub(crate) fn apply(self, f: f64) -> Option<f64> {
+        match self {
+            Self::Truncate => Some(f.trunc()),
+            Self::HalfEven => Some(f.round_ties_even()),
+            Self::Reject if f.fract() != 0.0 => None,
+            Self::Reject => Some(f),
+        }
+    }
+}
+
+/// Describes how database values are coerced into `bool`.
+///
+/// Values that expose their numeric content with
+/// [`DbValue::number()`](trait.DbValue.html#method.number) are coerced from `0` and `1`,
+/// if integer coercion is enabled.
+/// All other values are converted into text, which is trimmed and compared case-insensitively
+/// with the configured tokens.
+/// By default, integer coercion is enabled, and the tokens are
+/// `Y`/`N`, `T`/`F`, `true`/`false`, and `1`/`0`.
+///
+/// ```rust
+/// use serde_db::de::BoolCoercion;
+/// let coercion = BoolCoercion::default();
+/// assert_eq!(coercion.coerce_text(" yes "), None);
+/// assert_eq!(coercion.coerce_text("y"), Some(true));
+/// assert_eq!(coercion.coerce_text("False"), Some(false));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoolCoercion {
+    true_tokens: Vec<String>,
+    false_tokens: Vec<String>,
+    integers: bool,
+}
+
+impl Default for BoolCoercion {
+    fn default() -> Self {
+        Self {
+            true_tokens: ["Y", "T", "true", "1"].map(String::from).to_vec(),
+            false_tokens: ["N", "F", "false", "0"].map(String::from).to_vec(),
+            integers: true,
+        }
+    }
+}
+
+impl BoolCoercion {
+    /// Replaces the accepted text tokens.
+    #[must_use]
+    pub fn with_tokens<S: AsRef<str>>(mut self, true_tokens: &[S], false_tokens: &[S]) -> Self {
+        self.true_tokens = true_tokens.iter().map(|t| t.as_ref().to_string()).collect();
+        self.false_tokens = false_tokens
+            .iter()
+            .map(|t| t.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Enables or disables the coercion of the integers `0` and `1`.
+    #[must_use]
+    pub fn with_integers(mut self, integers: bool) -> Self {
+        self.integers = integers;
+        self
+    }
+
+    /// Returns true if the integers `0` and `1` are coerced.
+    #[must_use]
+    pub fn integers(&self) -> bool {
+        self.integers
+    }
+
+    /// Returns the boolean value of the text, or `None` if it is not one of the tokens.
+    #[must_use]
+    pub fn coerce_text(&self, text: &str) -> Option<bool> {
+        let text = text.trim();
+        if self
+            .true_tokens
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(text))
+        {
+            Some(true)
+        } else if self
+            .false_tokens
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(text))
+        {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Describes how rows are deserialized into structs if the driver provides no field names,
+/// i.e., if [`DeserializableRow::fieldname()`](trait.DeserializableRow.html#tymethod.fieldname)
+/// returns `None` for all columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FieldnameFallback {
+    /// The values are assigned to the fields of the struct by position, and the observer
+    /// gets a [`DeserializationWarning::PositionalFallback`](enum.DeserializationWarning.html).
+    #[default]
+    Positional,
+    /// The deserialization fails with `DeserializationError::Usage`.
+    Error,
+}
+
+/// Describes how naive timestamps are interpreted when they are deserialized into
+/// timezone-aware types with [`timezone_aware()`](fn.timezone_aware.html).
+///
+/// The timezone-aware types get the textual representation of the timestamp,
+/// with the offset appended in RFC 3339 format.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimezonePolicy {
+    /// Naive timestamps produce an error.
+    #[default]
+    Reject,
+    /// Naive timestamps are interpreted as UTC.
+    AssumeUtc,
+    /// Naive timestamps are interpreted as local time of the given offset,
+    /// in seconds east of UTC; e.g. `AssumeOffset(3600)` represents `+01:00`.
+    AssumeOffset(i32),
+}
+
+/// Describes whether trailing blanks are removed from text values during string conversion.
+///
+/// Fixed-width columns, like `CHAR(n)` or `NCHAR(n)`, are padded with blanks to their full
+/// width; drivers mark such values with
+/// [`DbValue::is_fixed_width_text()`](trait.DbValue.html#method.is_fixed_width_text).
+///
+/// ```rust
+/// use serde_db::de::CharPadding;
+/// assert_eq!(CharPadding::TrimFixedWidth.apply("ABC    ".to_string(), true), "ABC");
+/// assert_eq!(CharPadding::TrimFixedWidth.apply("ABC    ".to_string(), false), "ABC    ");
+/// assert_eq!(CharPadding::TrimAll.apply("ABC \t ".to_string(), false), "ABC \t");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CharPadding {
+    /// Text values are handed over unchanged.
+    #[default]
+    Keep,
+    /// Trailing blanks are removed from fixed-width text values.
+    TrimFixedWidth,
+    /// Trailing blanks are removed from all text values;
+    /// useful with drivers that cannot distinguish fixed-width text.
+    TrimAll,
+}
+
+impl CharPadding {
+    /// Applies the policy to a text value, which is fixed-width text or not.
+    #[must_use]
+    pub fn apply(self, mut text: String, fixed_width: bool) -> String {
+        if self == Self::TrimAll || (self == Self::TrimFixedWidth && fixed_width) {
+            text.truncate(text.trim_end_matches(' ').len());
+        }
+        text
+    }
+}
+
+/// Describes how NaN and infinite values are handled for floating point targets.
+///
+/// Decimal targets (see [`DecimalBackend`](trait.DecimalBackend.html)) cannot represent
+/// such values and always produce
+/// [`ConversionError::NonFinite`](enum.ConversionError.html#variant.NonFinite).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// NaN and infinite values are handed to the target.
+    #[default]
+    PassThrough,
+    /// `Option` targets get `None`, other targets produce an error.
+    ///
+    /// This requires the driver to implement
+    /// [`DbValue::number()`](trait.DbValue.html#method.number).
+    NoneForOption,
+    /// NaN and infinite values produce
+    /// [`ConversionError::NonFinite`](enum.ConversionError.html#variant.NonFinite).
+    Error,
+}
+
+/// Describes how text values are handled that are not valid UTF-8.
+///
+/// The policy only applies to values for which
+/// [`DbValue::is_raw_text()`](trait.DbValue.html#method.is_raw_text) returns true.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Utf8Policy {
+    /// Invalid UTF-8 produces a
+    /// [`ConversionError::InvalidUtf8`](enum.ConversionError.html#variant.InvalidUtf8)
+    /// that contains the byte offset of the first invalid sequence.
+    #[default]
+    Strict,
+    /// Invalid sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    Lossy,
+    /// Invalid UTF-8 is handed to the target type as bytes (with `visit_byte_buf()`),
+    /// so that targets that accept bytes can still consume the value.
+    Bytes,
+}
+
+impl Utf8Policy {
+    // Converts raw text bytes according to the policy.
+    pub(crate) fn decode(self, bytes: Vec<u8>) -> Result<DecodedText, ConversionError> {
+        match String::from_utf8(bytes) {
+            Ok(s) => Ok(DecodedText::String(s)),
+            Err(e) => match self {
+                Self::Strict => Err(ConversionError::InvalidUtf8 {
+                    valid_up_to: e.utf8_error().valid_up_to(),
+                }),
+                Self::Lossy => Ok(DecodedText::String(
+                    String::from_utf8_lossy(e.as_bytes()).into_owned(),
+                )),
+                Self::Bytes => Ok(DecodedText::Bytes(e.into_bytes())),
+            },
+        }
+    }
+}
+
+// The result of applying a `Utf8Policy`.
+pub(crate) enum DecodedText {
+    String(String),
+    Bytes(Vec<u8>),
+}
@@ -0,0 +1,188 @@
+use crate::de::{
+    DbValue, DeserializableResultset, DeserializableRow, DeserializationError,
+    DeserializationResult,
+};
+
+/// A dyn-compatible variant of [`DeserializableResultset`], for a given value type `V`.
+///
+/// It is implemented for all resultsets, and `Box<dyn DynResultset<V>>` implements
+/// `DeserializableResultset` in turn, with rows of type `Box<dyn DynRow<V>>`,
+/// so that tools that work with several drivers can hold their resultsets type-erased,
+/// and still deserialize them:
+///
+/// ```rust,ignore
+/// let rs: Box<dyn DynResultset<MyValue>> = Box::new(driver_resultset);
+/// let data: Vec<MyStruct> = DeserializableResultset::try_into(rs)?;
+/// ```
+///
+/// Errors are reported as `DeserializationError`.
+pub trait DynResultset<V> {
+    /// See [`DeserializableResultset::has_multiple_rows()`].
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    fn dyn_has_multiple_rows(&mut self) -> DeserializationResult<bool>;
+
+    /// See [`DeserializableResultset::has_no_rows()`].
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    fn dyn_has_no_rows(&mut self) -> DeserializationResult<bool>;
+
+    /// See [`DeserializableResultset::next()`].
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    fn dyn_next(&mut self) -> DeserializationResult<Option<Box<dyn DynRow<V>>>>;
+
+    /// See [`DeserializableResultset::number_of_fields()`].
+    fn dyn_number_of_fields(&self) -> usize;
+
+    /// See [`DeserializableResultset::fieldname()`].
+    fn dyn_fieldname(&self, field_idx: usize) -> Option<&str>;
+
+    /// See [`DeserializableResultset::remaining_rows()`].
+    fn dyn_remaining_rows(&self) -> Option<usize>;
+}
+
+/// A dyn-compatible variant of [`DeserializableRow`], for a given value type `V`.
+///
+/// It is implemented for all rows, and `Box<dyn DynRow<V>>` implements
+/// `DeserializableRow` in turn.
+pub trait DynRow<V> {
+    /// See [`DeserializableRow::len()`].
+    fn dyn_len(&self) -> usize;
+
+    /// See [`DeserializableRow::next()`].
+    fn dyn_next(&mut self) -> Option<V>;
+
+    /// See [`DeserializableRow::number_of_fields()`].
+    fn dyn_number_of_fields(&self) -> usize;
+
+    /// See [`DeserializableRow::fieldname()`].
+    fn dyn_fieldname(&self, field_idx: usize) -> Option<&str>;
+
+    /// See [`DeserializableRow::byte_len()`].
+    fn dyn_byte_len(&self) -> Option<usize>;
+}
+
+impl<RS> DynResultset<<RS::ROW as DeserializableRow>::V> for RS
+where
+    RS: DeserializableResultset,
+    RS::ROW: 'static,
+{
+    fn dyn_has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+        self.has_multiple_rows()
+    }
+
+    fn dyn_has_no_rows(&mut self) -> DeserializationResult<bool> {
+        self.has_no_rows()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn dyn_next(
+        &mut self,
+    ) -> DeserializationResult<Option<Box<dyn DynRow<<RS::ROW as DeserializableRow>::V>>>> {
+        Ok(self
+            .next()?
+            .map(|row| Box::new(row) as Box<dyn DynRow<<RS::ROW as DeserializableRow>::V>>))
+    }
+
+    fn dyn_number_of_fields(&self) -> usize {
+        self.number_of_fields()
+    }
+
+    fn dyn_fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.fieldname(field_idx)
+    }
+
+    fn dyn_remaining_rows(&self) -> Option<usize> {
+        self.remaining_rows()
+    }
+}
+
+impl<ROW: DeserializableRow> DynRow<ROW::V> for ROW {
+    fn dyn_len(&self) -> usize {
+        self.len()
+    }
+
+    fn dyn_next(&mut self) -> Option<ROW::V> {
+        self.next()
+    }
+
+    fn dyn_number_of_fields(&self) -> usize {
+        self.number_of_fields()
+    }
+
+    fn dyn_fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.fieldname(field_idx)
+    }
+
+    fn dyn_byte_len(&self) -> Option<usize> {
+        self.byte_len()
+    }
+}
+
+macro_rules! impl_deserializable_resultset {
+    ($($bounds:tt)*) => {
+        impl<V: DbValue + 'static> DeserializableResultset for Box<dyn DynResultset<V> $($bounds)*> {
+            type E = DeserializationError;
+            type ROW = Box<dyn DynRow<V>>;
+
+            fn has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+                (**self).dyn_has_multiple_rows()
+            }
+
+            fn has_no_rows(&mut self) -> DeserializationResult<bool> {
+                (**self).dyn_has_no_rows()
+            }
+
+            fn next(&mut self) -> DeserializationResult<Option<Self::ROW>> {
+                (**self).dyn_next()
+            }
+
+            fn number_of_fields(&self) -> usize {
+                (**self).dyn_number_of_fields()
+            }
+
+            fn fieldname(&self, field_idx: usize) -> Option<&str> {
+                (**self).dyn_fieldname(field_idx)
+            }
+
+            fn remaining_rows(&self) -> Option<usize> {
+                (**self).dyn_remaining_rows()
+            }
+        }
+    };
+}
+
+impl_deserializable_resultset!();
+impl_deserializable_resultset!(+ Send);
+
+impl<V: DbValue> DeserializableRow for Box<dyn DynRow<V>> {
+    type E = DeserializationError;
+    type V = V;
+
+    fn len(&self) -> usize {
+        (**self).dyn_len()
+    }
+
+    fn next(&mut self) -> Option<V> {
+        (**self).dyn_next()
+    }
+
+    fn number_of_fields(&self) -> usize {
+        (**self).dyn_number_of_fields()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        (**self).dyn_fieldname(field_idx)
+    }
+
+    fn byte_len(&self) -> Option<usize> {
+        (**self).dyn_byte_len()
+    }
+}
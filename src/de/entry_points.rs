@@ -0,0 +1,101 @@
+use crate::de::{
+    DbValue, DeserializableResultset, DeserializableRow, DeserializationError,
+    DeserializationOptions,
+};
+
+/// Converts a resultset into a rust type that implements `serde::Deserialize`.
+///
+/// This is equivalent to
+/// [`DeserializableResultset::try_into()`](trait.DeserializableResultset.html#method.try_into),
+/// but can be called without bringing the trait into scope, and reads naturally in
+/// generic code:
+///
+/// ```rust,ignore
+/// let data: Vec<MyStruct> = serde_db::de::from_resultset(resultset)?;
+/// ```
+///
+/// # Errors
+///
+/// An error is produced if deserialization into the target type is not possible.
+pub fn from_resultset<'de, RS, T>(rs: RS) -> Result<T, RS::E>
+where
+    RS: DeserializableResultset,
+    T: serde::Deserialize<'de>,
+{
+    DeserializableResultset::try_into(rs)
+}
+
+/// Converts a resultset into a rust type that implements `serde::Deserialize`,
+/// using the given options.
+///
+/// # Errors
+///
+/// An error is produced if deserialization into the target type is not possible.
+pub fn from_resultset_with<'de, RS, T>(rs: RS, options: &DeserializationOptions) -> Result<T, RS::E>
+where
+    RS: DeserializableResultset,
+    T: serde::Deserialize<'de>,
+{
+    DeserializableResultset::try_into_with(rs, options)
+}
+
+/// Converts a row into a struct, a tuple, or (if applicable) into a plain rust value.
+///
+/// This is equivalent to
+/// [`DeserializableRow::try_into()`](trait.DeserializableRow.html#method.try_into).
+///
+/// # Errors
+///
+/// An error is produced if deserialization into the target type is not possible.
+pub fn from_row<'de, R, T>(row: R) -> Result<T, R::E>
+where
+    R: DeserializableRow,
+    T: serde::Deserialize<'de>,
+{
+    DeserializableRow::try_into(row)
+}
+
+/// Converts a row into a struct, a tuple, or (if applicable) into a plain rust value,
+/// using the given options.
+///
+/// # Errors
+///
+/// An error is produced if deserialization into the target type is not possible.
+pub fn from_row_with<'de, R, T>(row: R, options: &DeserializationOptions) -> Result<T, R::E>
+where
+    R: DeserializableRow,
+    T: serde::Deserialize<'de>,
+{
+    DeserializableRow::try_into_with(row, options)
+}
+
+/// Converts a database value into a plain rust value.
+///
+/// This is equivalent to [`DbValue::try_into()`](trait.DbValue.html#method.try_into).
+///
+/// # Errors
+///
+/// `DeserializationError` if the value cannot be converted into the target type.
+pub fn from_value<'de, DBV, T>(value: DBV) -> Result<T, DeserializationError>
+where
+    DBV: DbValue,
+    T: serde::Deserialize<'de>,
+{
+    DbValue::try_into(value)
+}
+
+/// Converts a database value into a plain rust value, using the given options.
+///
+/// # Errors
+///
+/// `DeserializationError` if the value cannot be converted into the target type.
+pub fn from_value_with<'de, DBV, T>(
+    value: DBV,
+    options: &DeserializationOptions,
+) -> Result<T, DeserializationError>
+where
+    DBV: DbValue,
+    T: serde::Deserialize<'de>,
+{
+    DbValue::try_into_with(value, options)
+}
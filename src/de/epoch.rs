@@ -0,0 +1,167 @@
+//! Deserialization of temporal values from integers that count the time since the Unix epoch.
+//!
+//! Many schemas store timestamps as `BIGINT` columns. The functions of this module can be used
+//! with serde's `deserialize_with` attribute to convert such columns into every type that
+//! implements `From<SystemTime>`, like `chrono::DateTime<Utc>`, `chrono::DateTime<Local>`,
+//! `time::OffsetDateTime`, or `std::time::SystemTime` itself.
+//! The unit is chosen with the function.
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize)]
+//! struct Event {
+//!     #[serde(deserialize_with = "serde_db::de::epoch::millis")]
+//!     created_at: chrono::DateTime<chrono::Utc>,
+//!     #[serde(default, deserialize_with = "serde_db::de::epoch::option_seconds")]
+//!     deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The unit of an integer that counts the time since the Unix epoch.
+///
+/// ```rust
+/// use serde_db::de::epoch::EpochUnit;
+/// use std::time::{Duration, UNIX_EPOCH};
+/// assert_eq!(
+///     EpochUnit::Millis.to_system_time(-1_500),
+///     Some(UNIX_EPOCH - Duration::from_millis(1_500))
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EpochUnit {
+    /// Seconds.
+    Seconds,
+    /// Milliseconds.
+    Millis,
+    /// Microseconds.
+    Micros,
+    /// Nanoseconds.
+    Nanos,
+}
+
+impl EpochUnit {
+    /// Returns the point in time that is `value` units after (or before, if negative)
+    /// the Unix epoch, or `None` if it cannot be represented.
+    #[must_use]
+    pub fn to_system_time(self, value: i64) -> Option<SystemTime> {
+        let magnitude = value.unsigned_abs();
+        let duration = match self {
+            Self::Seconds => Duration::from_secs(magnitude),
+            Self::Millis => Duration::from_millis(magnitude),
+            Self::Micros => Duration::from_micros(magnitude),
+            Self::Nanos => Duration::from_nanos(magnitude),
+        };
+        if value < 0 {
+            UNIX_EPOCH.checked_sub(duration)
+        } else {
+            UNIX_EPOCH.checked_add(duration)
+        }
+    }
+
+    /// Deserializes an integer with this unit into `T`.
+    ///
+    /// # Errors
+    ///
+    /// If the value is not an integer, or if it is out of the representable range.
+    pub fn deserialize<'de, D, T>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<SystemTime>,
+    {
+        let value = i64::deserialize(deserializer)?;
+        self.to_system_time(value).map(T::from).ok_or_else(|| {
+            serde::de::Error::custom(format!("{value} {self:?} since the epoch is out of range"))
+        })
+    }
+
+    /// Deserializes a nullable integer with this unit into `Option<T>`.
+    ///
+    /// # Errors
+    ///
+    /// If the value is not NULL and not an integer, or if it is out of the representable range.
+    pub fn deserialize_option<'de, D, T>(self, deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<SystemTime>,
+    {
+        match Option::<i64>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(value) => self
+                .to_system_time(value)
+                .map(|t| Some(T::from(t)))
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "{value} {self:?} since the epoch is out of range"
+                    ))
+                }),
+        }
+    }
+}
+
+/// Deserializes seconds since the Unix epoch.
+///
+/// # Errors
+///
+/// If the value is not an integer, or if it is out of the representable range.
+pub fn seconds<'de, D: Deserializer<'de>, T: From<SystemTime>>(d: D) -> Result<T, D::Error> {
+    EpochUnit::Seconds.deserialize(d)
+}
+
+/// Deserializes milliseconds since the Unix epoch.
+///
+/// # Errors
+///
+/// If the value is not an integer, or if it is out of the representable range.
+pub fn millis<'de, D: Deserializer<'de>, T: From<SystemTime>>(d: D) -> Result<T, D::Error> {
+    EpochUnit::Millis.deserialize(d)
+}
+
+/// Deserializes microseconds since the Unix epoch.
+///
+/// # Errors
+///
+/// If the value is not an integer, or if it is out of the representable range.
+pub fn micros<'de, D: Deserializer<'de>, T: From<SystemTime>>(d: D) -> Result<T, D::Error> {
+    EpochUnit::Micros.deserialize(d)
+}
+
+/// Deserializes nullable seconds since the Unix epoch.
+///
+/// # Errors
+///
+/// If the value is not NULL and not an integer, or if it is out of the representable range.
+pub fn option_seconds<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<SystemTime>,
+{
+    EpochUnit::Seconds.deserialize_option(d)
+}
+
+/// Deserializes nullable milliseconds since the Unix epoch.
+///
+/// # Errors
+///
+/// If the value is not NULL and not an integer, or if it is out of the representable range.
+pub fn option_millis<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<SystemTime>,
+{
+    EpochUnit::Millis.deserialize_option(d)
+}
+
+/// Deserializes nullable microseconds since the Unix epoch.
+///
+/// # Errors
+///
+/// If the value is not NULL and not an integer, or if it is out of the representable range.
+pub fn option_micros<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<SystemTime>,
+{
+    EpochUnit::Micros.deserialize_option(d)
+}
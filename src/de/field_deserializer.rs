@@ -1,22 +1,152 @@
-use crate::de::{DbValue, DbValueInto, DeserializationError, DeserializationResult};
+use crate::de::db_interval::DB_INTERVAL;
+use crate::de::deserialization_options::DecodedText;
+#[cfg(feature = "json")]
+use crate::de::json_deserializer::deserialize_json;
+use crate::de::lob::{async_lob_error, LobChunks, LOB_STREAM};
+use crate::de::rs_deserializer::RsDeserializer;
+use crate::de::timezone::{TimezoneAwareDeserializer, TIMEZONE_AWARE};
+use crate::de::{
+    BoolCoercion, ConversionError, DbNumber, DbValue, DbValueInto, DeserializationError,
+    DeserializationOptions, DeserializationResult, NestedResultset, NonFinitePolicy, NullPolicy,
+    NumericTarget, ValueKind,
+};
 #[cfg(feature = "trace")]
 use log::trace;
+use serde::de::value::{MapDeserializer, SeqDeserializer, StrDeserializer, U32Deserializer};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Deserialize a single `DbValue` into a normal rust type.
-pub struct FieldDeserializer<DBV>(DBV);
+pub struct FieldDeserializer<'o, DBV>(DBV, &'o DeserializationOptions, Option<&'o AtomicBool>);
 
-impl<DBV> FieldDeserializer<DBV>
+impl<'o, DBV> FieldDeserializer<'o, DBV>
 where
     DBV: DbValue,
 {
-    pub fn new(value: DBV) -> FieldDeserializer<DBV> {
+    pub fn new(value: DBV, options: &'o DeserializationOptions) -> FieldDeserializer<'o, DBV> {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::new()");
-        FieldDeserializer(value)
+        FieldDeserializer(value, options, None)
+    }
+
+    // Sets a flag that is raised when a NULL value is replaced with the default value
+    // of the target type.
+    pub fn with_default_flag(mut self, flag: &'o AtomicBool) -> Self {
+        self.2 = Some(flag);
+        self
+    }
+
+    // Retrieves the textual content of the value, applying the text decoder or the UTF-8 policy
+    // to raw text,
+    // and the CHAR padding policy.
+    fn into_text(self) -> DeserializationResult<DecodedText> {
+        self.reject_async_lob()?;
+        let fixed_width = self.0.is_fixed_width_text();
+        let text = if self.0.is_raw_text() {
+            let bytes: Vec<u8> = DbValueInto::try_into(self.0)?;
+            match self.1.text_decoder() {
+                Some(decoder) => DecodedText::String(decoder.decode(&bytes)?),
+                None => self.1.utf8_policy().decode(bytes)?,
+            }
+        } else {
+            DecodedText::String(DbValueInto::try_into(self.0)?)
+        };
+        Ok(match text {
+            DecodedText::String(s) => {
+                DecodedText::String(self.1.char_padding().apply(s, fixed_width))
+            }
+            bytes @ DecodedText::Bytes(_) => bytes,
+        })
+    }
+
+    // Creates a deserializer for the resultset that is contained in the value.
+    fn into_nested(self) -> DeserializationResult<RsDeserializer<'o, NestedResultset<DBV>>> {
+        RsDeserializer::try_new(self.0.into_nested_resultset()?, self.1)
+    }
+
+    // Asynchronously fetched LOBs cannot be deserialized synchronously.
+    fn reject_async_lob(&self) -> DeserializationResult<()> {
+        if self.0.is_async_lob() {
+            Err(async_lob_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    // Returns true if the value is NULL and the default value of the target type is to be used.
+    fn is_defaulted_null(&self) -> bool {
+        let defaulted = self.0.is_null() && self.1.null_policy() == NullPolicy::TypeDefault;
+        if let (true, Some(flag)) = (defaulted, self.2) {
+            flag.store(true, Ordering::Relaxed);
+        }
+        defaulted
+    }
+
+    // Converts the value according to the numeric policy and the rounding mode,
+    // if a policy is set and the value exposes its numeric content.
+    fn convert_number<T: NumericTarget>(&self) -> Option<DeserializationResult<T>> {
+        let policy = self.1.numeric_policy()?;
+        let number = self
+            .0
+            .number()
+            .or_else(|| self.0.decimal_parts().map(DbNumber::from))?;
+        Some(
+            number
+                .convert_rounded(policy, self.1.rounding_mode())
+                .map_err(DeserializationError::from),
+        )
+    }
+
+    // Returns true if the value is NaN or infinite.
+    fn is_non_finite(&self) -> bool {
+        matches!(self.0.number(), Some(DbNumber::Float(f)) if !f.is_finite())
+    }
+
+    // Applies the non-finite policy to a floating point value.
+    fn check_finite(options: &DeserializationOptions, f: f64) -> DeserializationResult<()> {
+        if f.is_finite() || options.non_finite_policy() == NonFinitePolicy::PassThrough {
+            Ok(())
+        } else {
+            Err(ConversionError::NonFinite(f).into())
+        }
+    }
+
+    // Coerces the value into a bool, from an integer or a text token.
+    fn coerce_bool(self, coercion: &BoolCoercion) -> DeserializationResult<bool> {
+        if coercion.integers() {
+            if let Some(number) = self.0.number() {
+                return match number {
+                    DbNumber::Integer(0) => Ok(false),
+                    DbNumber::Integer(1) => Ok(true),
+                    number => Err(ConversionError::ValueType(format!(
+                        "{number:?} cannot be coerced into bool"
+                    ))
+                    .into()),
+                };
+            }
+        }
+        match self.into_text()? {
+            DecodedText::String(text) => coercion.coerce_text(&text).ok_or_else(|| {
+                ConversionError::ValueType(format!("\"{text}\" cannot be coerced into bool")).into()
+            }),
+            DecodedText::Bytes(_) => Err(ConversionError::ValueType(
+                "invalid UTF-8 cannot be coerced into bool".to_string(),
+            )
+            .into()),
+        }
+    }
+
+    fn visit_text<'x, V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        match self.into_text()? {
+            DecodedText::String(s) => visitor.visit_string(s),
+            DecodedText::Bytes(b) => visitor.visit_byte_buf(b),
+        }
     }
 }
 
-impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
+impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<'_, DBV> {
     type Error = DeserializationError;
 
     fn deserialize_any<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -25,7 +155,46 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_any()");
-        visitor.visit_string(DbValueInto::try_into(self.0)?)
+        self.reject_async_lob()?;
+        if self.0.is_null() {
+            return visitor.visit_none();
+        }
+        if self.0.is_array() {
+            return visitor.visit_seq(ArrayElements::new(self.0.into_array()?, self.1));
+        }
+        if self.0.is_nested_resultset() {
+            return self.into_nested()?.deserialize_any(visitor);
+        }
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_any(visitor));
+        }
+        if let Some(decimal) = self.0.decimal_parts() {
+            return visitor.visit_string(decimal.to_string());
+        }
+        match self.0.value_kind() {
+            ValueKind::Null => visitor.visit_none(),
+            ValueKind::Bool => visitor.visit_bool(DbValueInto::try_into(self.0)?),
+            ValueKind::Int => match self.0.number() {
+                Some(DbNumber::Integer(i)) => {
+                    if let Ok(i) = i64::try_from(i) {
+                        visitor.visit_i64(i)
+                    } else if let Ok(u) = u64::try_from(i) {
+                        visitor.visit_u64(u)
+                    } else {
+                        visitor.visit_i128(i)
+                    }
+                }
+                _ => visitor.visit_i64(DbValueInto::try_into(self.0)?),
+            },
+            ValueKind::Float => match self.0.number() {
+                Some(DbNumber::Float(f)) => visitor.visit_f64(f),
+                _ => visitor.visit_f64(DbValueInto::try_into(self.0)?),
+            },
+            ValueKind::Bytes => visitor.visit_byte_buf(DbValueInto::try_into(self.0)?),
+            _ => self.visit_text(visitor),
+        }
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -34,6 +203,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_bool()");
+        if self.is_defaulted_null() {
+            return visitor.visit_bool(false);
+        }
+        if let Some(coercion) = self.1.bool_coercion() {
+            return visitor.visit_bool(self.coerce_bool(coercion)?);
+        }
         visitor.visit_bool(DbValueInto::try_into(self.0)?)
     }
 
@@ -43,6 +218,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u8()");
+        if self.is_defaulted_null() {
+            return visitor.visit_u8(0);
+        }
+        if let Some(result) = self.convert_number::<u8>() {
+            return visitor.visit_u8(result?);
+        }
         visitor.visit_u8(DbValueInto::try_into(self.0)?)
     }
 
@@ -52,6 +233,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u16()");
+        if self.is_defaulted_null() {
+            return visitor.visit_u16(0);
+        }
+        if let Some(result) = self.convert_number::<u16>() {
+            return visitor.visit_u16(result?);
+        }
         visitor.visit_u16(DbValueInto::try_into(self.0)?)
     }
 
@@ -61,6 +248,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u32()");
+        if self.is_defaulted_null() {
+            return visitor.visit_u32(0);
+        }
+        if let Some(result) = self.convert_number::<u32>() {
+            return visitor.visit_u32(result?);
+        }
         visitor.visit_u32(DbValueInto::try_into(self.0)?)
     }
 
@@ -70,6 +263,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u64()");
+        if self.is_defaulted_null() {
+            return visitor.visit_u64(0);
+        }
+        if let Some(result) = self.convert_number::<u64>() {
+            return visitor.visit_u64(result?);
+        }
         visitor.visit_u64(DbValueInto::try_into(self.0)?)
     }
 
@@ -79,6 +278,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i8()");
+        if self.is_defaulted_null() {
+            return visitor.visit_i8(0);
+        }
+        if let Some(result) = self.convert_number::<i8>() {
+            return visitor.visit_i8(result?);
+        }
         visitor.visit_i8(DbValueInto::try_into(self.0)?)
     }
 
@@ -88,6 +293,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i16()");
+        if self.is_defaulted_null() {
+            return visitor.visit_i16(0);
+        }
+        if let Some(result) = self.convert_number::<i16>() {
+            return visitor.visit_i16(result?);
+        }
         visitor.visit_i16(DbValueInto::try_into(self.0)?)
     }
 
@@ -97,6 +308,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i32()");
+        if self.is_defaulted_null() {
+            return visitor.visit_i32(0);
+        }
+        if let Some(result) = self.convert_number::<i32>() {
+            return visitor.visit_i32(result?);
+        }
         visitor.visit_i32(DbValueInto::try_into(self.0)?)
     }
 
@@ -106,6 +323,12 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i64()");
+        if self.is_defaulted_null() {
+            return visitor.visit_i64(0);
+        }
+        if let Some(result) = self.convert_number::<i64>() {
+            return visitor.visit_i64(result?);
+        }
         visitor.visit_i64(DbValueInto::try_into(self.0)?)
     }
 
@@ -115,7 +338,16 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_f32()");
-        visitor.visit_f32(DbValueInto::try_into(self.0)?)
+        if self.is_defaulted_null() {
+            return visitor.visit_f32(0.0);
+        }
+        let options = self.1;
+        let value: f32 = match self.convert_number::<f32>() {
+            Some(result) => result?,
+            None => DbValueInto::try_into(self.0)?,
+        };
+        Self::check_finite(options, f64::from(value))?;
+        visitor.visit_f32(value)
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -124,16 +356,42 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_f64()");
-        visitor.visit_f64(DbValueInto::try_into(self.0)?)
+        if self.is_defaulted_null() {
+            return visitor.visit_f64(0.0);
+        }
+        let options = self.1;
+        let value: f64 = match self.convert_number::<f64>() {
+            Some(result) => result?,
+            None => DbValueInto::try_into(self.0)?,
+        };
+        Self::check_finite(options, value)?;
+        visitor.visit_f64(value)
     }
 
-    fn deserialize_char<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_char<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_char()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_char()");
+        if self.is_defaulted_null() {
+            return visitor.visit_char(char::default());
+        }
+        let DecodedText::String(text) = self.into_text()? else {
+            return Err(ConversionError::ValueType(
+                "a char cannot be deserialized from bytes".to_string(),
+            )
+            .into());
+        };
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(ConversionError::ValueType(format!(
+                "a char needs a text with exactly one character, not {} characters ({text:?})",
+                text.chars().count()
+            ))
+            .into()),
+        }
     }
 
     fn deserialize_str<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -151,16 +409,29 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_string()");
-        visitor.visit_string(DbValueInto::try_into(self.0)?)
+        if self.is_defaulted_null() {
+            return visitor.visit_str("");
+        }
+        self.visit_text(visitor)
     }
 
-    fn deserialize_unit<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_unit<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_unit()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_unit()");
+        if self.0.is_null() {
+            return visitor.visit_unit();
+        }
+        match self.into_text() {
+            Ok(DecodedText::String(text)) if text.is_empty() => visitor.visit_unit(),
+            Ok(DecodedText::Bytes(bytes)) if bytes.is_empty() => visitor.visit_unit(),
+            _ => Err(ConversionError::ValueType(
+                "a unit can only be deserialized from NULL or an empty value".to_string(),
+            )
+            .into()),
+        }
     }
 
     fn deserialize_option<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -169,26 +440,55 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_option()");
-        if self.0.is_null() {
+        if self.0.is_null()
+            || (self.1.non_finite_policy() == NonFinitePolicy::NoneForOption
+                && self.is_non_finite())
+        {
             visitor.visit_none()
         } else {
             visitor.visit_some(self)
         }
     }
 
-    fn deserialize_seq<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_seq<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_seq()");
+        if self.is_defaulted_null() {
+            return visitor.visit_seq(SeqDeserializer::new(std::iter::empty::<()>()));
+        }
+        if self.0.is_array() {
+            return visitor.visit_seq(ArrayElements::new(self.0.into_array()?, self.1));
+        }
+        if self.0.is_nested_resultset() {
+            return self.into_nested()?.deserialize_seq(visitor);
+        }
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_seq(visitor));
+        }
         Err(DeserializationError::NotImplemented(
             "FieldDeserializer::deserialize_seq()",
         ))
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_map<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_map()");
+        if self.is_defaulted_null() {
+            return visitor.visit_map(MapDeserializer::new(std::iter::empty::<((), ())>()));
+        }
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_map(visitor));
+        }
         Err(DeserializationError::NotImplemented(
             "FieldDeserializer::deserialize_map()",
         ))
@@ -197,19 +497,17 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     fn deserialize_unit_struct<V>(
         self,
         _name: &'static str,
-        _visitor: V,
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_unit_struct()",
-        ))
+        self.deserialize_unit(visitor)
     }
 
     fn deserialize_newtype_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> DeserializationResult<V::Value>
     where
@@ -217,18 +515,49 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_newtype_struct()");
+        if name == TIMEZONE_AWARE {
+            return visitor.visit_newtype_struct(TimezoneAwareDeserializer::new(self.0, self.1));
+        }
+        if name == DB_INTERVAL {
+            let Some(interval) = self.0.interval() else {
+                return Err(
+                    ConversionError::ValueType("the value is not an interval".to_string()).into(),
+                );
+            };
+            let parts = [
+                i64::from(interval.months),
+                i64::from(interval.days),
+                interval.microseconds,
+            ];
+            return visitor.visit_newtype_struct(SeqDeserializer::new(parts.into_iter()));
+        }
+        if name == LOB_STREAM && self.0.is_async_lob() {
+            return Err(async_lob_error());
+        }
+        if name == LOB_STREAM && self.0.is_lob() {
+            return visitor.visit_seq(LobChunks::new(self.0.into_lob_reader()?));
+        }
         visitor.visit_newtype_struct(self)
     }
 
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
-        _len: usize,
-        _visitor: V,
+        name: &'static str,
+        len: usize,
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_tuple_struct()");
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_tuple_struct(name, len, visitor));
+        }
+        #[cfg(not(feature = "json"))]
+        let _ = (name, len, visitor);
         Err(DeserializationError::NotImplemented(
             "FieldDeserializer::deserialize_tuple_struct()",
         ))
@@ -236,13 +565,30 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
 
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
-        _visitor: V,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_struct()");
+        if self.0.is_nested_resultset() {
+            return self
+                .into_nested()?
+                .deserialize_struct(name, fields, visitor);
+        }
+        if let Some(interval) = self.0.interval().filter(|_| is_std_duration(name, fields)) {
+            let duration = interval.to_std_duration()?;
+            let parts = [duration.as_secs(), u64::from(duration.subsec_nanos())];
+            return visitor.visit_seq(SeqDeserializer::new(parts.into_iter()));
+        }
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_struct(name, fields, visitor));
+        }
         Err(DeserializationError::NotImplemented(
             "FieldDeserializer::deserialize_struct()",
         ))
@@ -254,6 +600,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_bytes()");
+        if self.is_defaulted_null() {
+            return visitor.visit_bytes(&[]);
+        }
+        self.reject_async_lob()?;
         visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.0)?)
     }
 
@@ -262,14 +612,31 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!("FieldDeserializer::deserialize_bytes()");
+        trace!("FieldDeserializer::deserialize_byte_buf()");
+        if self.is_defaulted_null() {
+            return visitor.visit_bytes(&[]);
+        }
+        self.reject_async_lob()?;
         visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.0)?)
     }
 
-    fn deserialize_tuple<V>(self, _len: usize, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_tuple()");
+        if self.0.is_array() {
+            return visitor.visit_seq(ArrayElements::new(self.0.into_array()?, self.1));
+        }
+        if self.0.is_nested_resultset() {
+            return self.into_nested()?.deserialize_tuple(len, visitor);
+        }
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_tuple(len, visitor));
+        }
         Err(DeserializationError::NotImplemented(
             "FieldDeserializer::deserialize_tuple()",
         ))
@@ -277,33 +644,253 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
 
     fn deserialize_enum<V>(
         self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
-        _visitor: V,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_enum()");
+        #[cfg(feature = "json")]
+        if self.0.is_json() {
+            let json: String = DbValueInto::try_into(self.0)?;
+            return deserialize_json(&json, |de| de.deserialize_enum(name, variants, visitor));
+        }
+        if name == "OsString" && variants == ["Unix", "Windows"] {
+            // serde represents OsString as an enum with the platform-specific encoding
+            let DecodedText::String(text) = self.into_text()? else {
+                return Err(DeserializationError::Usage(
+                    "OsString cannot be deserialized from bytes".to_string(),
+                ));
+            };
+            return visitor.visit_enum(OsStringVariant(text));
+        }
+        // unit variants, identified by name or by index
+        let DecodedText::String(text) = self.into_text()? else {
+            return Err(DeserializationError::Usage(format!(
+                "enum {name} cannot be deserialized from bytes"
+            )));
+        };
+        let tag = match text.trim().parse::<u32>() {
+            Ok(idx) if !variants.contains(&text.as_str()) => VariantTag::Index(idx),
+            _ => VariantTag::Name(text),
+        };
+        visitor.visit_enum(UnitVariant { name, tag })
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_identifier()");
+        self.visit_text(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_ignored_any()");
+        // the value is dropped without being converted
+        visitor.visit_unit()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.1.is_human_readable()
+    }
+}
+
+// Returns true if the struct is serde's representation of `std::time::Duration`.
+fn is_std_duration(name: &str, fields: &[&str]) -> bool {
+    name == "Duration" && fields == ["secs", "nanos"]
+}
+
+// The variant of an enum, as identified by a plain value.
+enum VariantTag {
+    Name(String),
+    Index(u32),
+}
+
+// Provides a unit variant of an enum; variants with data cannot be taken from a plain value.
+struct UnitVariant {
+    name: &'static str,
+    tag: VariantTag,
+}
+
+impl UnitVariant {
+    fn data_variant_error(&self) -> DeserializationError {
+        let variant = match self.tag {
+            VariantTag::Name(ref name) => name.clone(),
+            VariantTag::Index(idx) => format!("#{idx}"),
+        };
+        DeserializationError::Usage(format!(
+            "variant {variant} of enum {} has data, which a plain value cannot provide; \
+             use a JSON value or #[serde(untagged)]",
+            self.name
+        ))
+    }
+}
+
+impl<'x> serde::de::EnumAccess<'x> for UnitVariant {
+    type Error = DeserializationError;
+    type Variant = Self;
+
+    fn variant_seed<S>(self, seed: S) -> DeserializationResult<(S::Value, Self)>
+    where
+        S: serde::de::DeserializeSeed<'x>,
+    {
+        let value = match self.tag {
+            VariantTag::Name(ref name) => {
+                seed.deserialize(StrDeserializer::<DeserializationError>::new(name))?
+            }
+            VariantTag::Index(idx) => {
+                seed.deserialize(U32Deserializer::<DeserializationError>::new(idx))?
+            }
+        };
+        Ok((value, self))
+    }
+}
+
+impl<'x> serde::de::VariantAccess<'x> for UnitVariant {
+    type Error = DeserializationError;
+
+    fn unit_variant(self) -> DeserializationResult<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, _seed: T) -> DeserializationResult<T::Value>
+    where
+        T: serde::de::DeserializeSeed<'x>,
+    {
+        Err(self.data_variant_error())
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        Err(self.data_variant_error())
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        Err(self.data_variant_error())
+    }
+}
+
+// Provides a text as the variant of serde's OsString representation for the current platform.
+struct OsStringVariant(String);
+
+impl<'x> serde::de::EnumAccess<'x> for OsStringVariant {
+    type Error = DeserializationError;
+    type Variant = Self;
+
+    fn variant_seed<S>(self, seed: S) -> DeserializationResult<(S::Value, Self)>
+    where
+        S: serde::de::DeserializeSeed<'x>,
+    {
+        let variant = if cfg!(windows) { "Windows" } else { "Unix" };
+        let value = seed.deserialize(StrDeserializer::<DeserializationError>::new(variant))?;
+        Ok((value, self))
+    }
+}
+
+impl<'x> serde::de::VariantAccess<'x> for OsStringVariant {
+    type Error = DeserializationError;
+
+    fn unit_variant(self) -> DeserializationResult<()> {
         Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_enum()",
+            "OsStringVariant::unit_variant()",
         ))
     }
 
-    fn deserialize_identifier<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn newtype_variant_seed<T>(self, seed: T) -> DeserializationResult<T::Value>
+    where
+        T: serde::de::DeserializeSeed<'x>,
+    {
+        if cfg!(windows) {
+            seed.deserialize(SeqDeserializer::new(self.0.encode_utf16()))
+        } else {
+            seed.deserialize(SeqDeserializer::new(self.0.into_bytes().into_iter()))
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_identifier()",
+            "OsStringVariant::tuple_variant()",
         ))
     }
 
-    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_ignored_any()",
+            "OsStringVariant::struct_variant()",
         ))
     }
 }
+
+// Deserializes the elements of an array value.
+pub(crate) struct ArrayElements<'o, DBV> {
+    elements: std::vec::IntoIter<DBV>,
+    options: &'o DeserializationOptions,
+    idx: usize,
+}
+
+impl<'o, DBV> ArrayElements<'o, DBV> {
+    pub fn new(elements: Vec<DBV>, options: &'o DeserializationOptions) -> Self {
+        ArrayElements {
+            elements: elements.into_iter(),
+            options,
+            idx: 0,
+        }
+    }
+}
+
+impl<'x, DBV: DbValue> serde::de::SeqAccess<'x> for ArrayElements<'_, DBV> {
+    type Error = DeserializationError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> DeserializationResult<Option<T::Value>>
+    where
+        T: serde::de::DeserializeSeed<'x>,
+    {
+        match self.elements.next() {
+            None => Ok(None),
+            Some(element) => {
+                let idx = self.idx;
+                self.idx += 1;
+                seed.deserialize(FieldDeserializer::new(element, self.options))
+                    .map(Some)
+                    .map_err(|e| {
+                        if self.options.field_paths() {
+                            e.at_field(&format!("[{idx}]"))
+                        } else {
+                            e
+                        }
+                    })
+            }
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
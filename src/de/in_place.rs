@@ -0,0 +1,19 @@
+use serde::de::{DeserializeSeed, Deserializer};
+
+// A seed that deserializes into an existing value with `Deserialize::deserialize_in_place()`,
+// so that its allocations can be reused.
+pub(crate) struct InPlace<'a, T>(pub(crate) &'a mut T);
+
+impl<'de, T> DeserializeSeed<'de> for InPlace<'_, T>
+where
+    T: serde::Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_in_place(deserializer, self.0)
+    }
+}
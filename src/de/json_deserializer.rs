@@ -0,0 +1,27 @@
+use crate::de::{ConversionError, DeserializationError, DeserializationResult};
+#[cfg(feature = "trace")]
+use log::trace;
+
+// The deserializer for the JSON documents that are contained in JSON columns.
+//
+// The document is read through `io::Read` rather than with `from_str()`,
+// because the visitors of the target types must not borrow from the temporary text.
+pub(crate) type JsonDeserializer<'a> = serde_json::Deserializer<serde_json::de::IoRead<&'a [u8]>>;
+
+// Deserializes the JSON document `json` with `f`,
+// and ensures that the document is completely consumed.
+pub(crate) fn deserialize_json<T, F>(json: &str, f: F) -> DeserializationResult<T>
+where
+    F: FnOnce(&mut JsonDeserializer) -> Result<T, serde_json::Error>,
+{
+    #[cfg(feature = "trace")]
+    trace!("deserialize_json()");
+    let mut deserializer = serde_json::Deserializer::from_reader(json.as_bytes());
+    let value = f(&mut deserializer).map_err(|e| json_error(&e))?;
+    deserializer.end().map_err(|e| json_error(&e))?;
+    Ok(value)
+}
+
+fn json_error(e: &serde_json::Error) -> DeserializationError {
+    DeserializationError::ConversionError(ConversionError::Json(e.to_string()))
+}
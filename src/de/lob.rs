@@ -0,0 +1,200 @@
+use crate::de::field_deserializer::FieldDeserializer;
+use crate::de::{
+    ConversionError, DbValue, DeserializationError, DeserializationOptions, DeserializationResult,
+};
+use serde::de::value::BytesDeserializer;
+use serde::de::{DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use std::future::poll_fn;
+use std::io::{Read, Write};
+use std::task::{Context, Poll};
+
+// The name of the newtype struct with which `stream_into()` requests streaming.
+pub(crate) const LOB_STREAM: &str = "$serde_db::LobStream";
+
+// The maximal size of the chunks in which LOBs are streamed.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams the content of a LOB value into the given writer, and returns the number of
+/// written bytes.
+///
+/// Use it in a `deserialize_with` function or in a manual `Deserialize` implementation of a
+/// streaming wrapper type, so that large values are never completely held in memory:
+///
+/// ```rust,ignore
+/// fn to_temp_file<'de, D: serde::Deserializer<'de>>(d: D) -> Result<TempFile, D::Error> {
+///     let mut file = TempFile::new().map_err(serde::de::Error::custom)?;
+///     serde_db::de::stream_into(d, &mut file)?;
+///     Ok(file)
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Document {
+///     id: i32,
+///     #[serde(deserialize_with = "to_temp_file")]
+///     content: TempFile,
+/// }
+/// ```
+///
+/// Values for which [`DbValue::is_lob()`](trait.DbValue.html#method.is_lob) returns true are
+/// read in chunks from [`DbValue::into_lob_reader()`](trait.DbValue.html#method.into_lob_reader);
+/// other text or binary values are written in one piece.
+///
+/// # Errors
+///
+/// If the value is NULL or not text or binary, or if reading or writing fails.
+pub fn stream_into<'de, D, W>(deserializer: D, writer: &mut W) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+    W: Write,
+{
+    deserializer.deserialize_newtype_struct(LOB_STREAM, WriteVisitor(writer))
+}
+
+/// Streams the content of a LOB value into the given writer, and returns the number of
+/// written bytes.
+///
+/// See [`stream_into()`](fn.stream_into.html).
+///
+/// # Errors
+///
+/// If the value is NULL or not text or binary, or if reading or writing fails.
+pub fn write_lob<DBV: DbValue, W: Write>(value: DBV, writer: &mut W) -> DeserializationResult<u64> {
+    stream_into(
+        FieldDeserializer::new(value, &DeserializationOptions::default()),
+        writer,
+    )
+}
+
+/// A reader for large objects whose chunks are fetched asynchronously.
+///
+/// Drivers return it from
+/// [`DbValue::into_async_lob_reader()`](trait.DbValue.html#method.into_async_lob_reader).
+pub trait AsyncLobReader {
+    /// Attempts to fetch the next chunk of the content, and returns `None` at the end.
+    ///
+    /// If the chunk is not yet available, the reader returns `Poll::Pending` and arranges
+    /// for the waker of the given context to be woken once it is.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if fetching fails.
+    fn poll_next_chunk(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<u8>>, ConversionError>>;
+}
+
+/// Writes the content of a LOB value into the given writer, fetching the chunks of
+/// asynchronously fetched LOBs without blocking, and returns the number of written bytes.
+///
+/// Deserialization with serde is synchronous, so values for which
+/// [`DbValue::is_async_lob()`](trait.DbValue.html#method.is_async_lob) returns true can only be
+/// converted with this function; all other entry points produce a
+/// [`DeserializationError::Usage`](enum.DeserializationError.html#variant.Usage) for them.
+/// Other values are written like with [`write_lob()`](fn.write_lob.html).
+///
+/// # Errors
+///
+/// If the value is NULL or not text or binary, or if fetching or writing fails.
+pub async fn write_lob_async<DBV: DbValue, W: Write>(
+    value: DBV,
+    writer: &mut W,
+) -> DeserializationResult<u64> {
+    if !value.is_async_lob() {
+        return write_lob(value, writer);
+    }
+    let mut reader = value.into_async_lob_reader()?;
+    let mut written = 0;
+    while let Some(chunk) = poll_fn(|cx| reader.poll_next_chunk(cx)).await? {
+        written += WriteVisitor(&mut *writer).write::<DeserializationError>(&chunk)?;
+    }
+    Ok(written)
+}
+
+// The error for asynchronously fetched LOBs that meet a synchronous entry point.
+pub(crate) fn async_lob_error() -> DeserializationError {
+    DeserializationError::Usage(String::from(
+        "an asynchronously fetched LOB cannot be deserialized synchronously; \
+         use serde_db::de::write_lob_async()",
+    ))
+}
+
+// Writes all content it is given into the writer.
+struct WriteVisitor<'w, W>(&'w mut W);
+
+impl<W: Write> WriteVisitor<'_, W> {
+    fn write<E: serde::de::Error>(&mut self, bytes: &[u8]) -> Result<u64, E> {
+        self.0.write_all(bytes).map_err(E::custom)?;
+        Ok(bytes.len() as u64)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for WriteVisitor<'_, W> {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("LOB content")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(mut self, v: &[u8]) -> Result<u64, E> {
+        self.write(v)
+    }
+
+    fn visit_str<E: serde::de::Error>(mut self, v: &str) -> Result<u64, E> {
+        self.write(v.as_bytes())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<u64, A::Error> {
+        let mut written = 0;
+        while let Some(n) = seq.next_element_seed(WriteVisitor(&mut *self.0))? {
+            written += n;
+        }
+        Ok(written)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<u64, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, W: Write> DeserializeSeed<'de> for WriteVisitor<'_, W> {
+    type Value = u64;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<u64, D::Error> {
+        deserializer.deserialize_bytes(self)
+    }
+}
+
+// Hands out the content of a LOB in chunks.
+pub(crate) struct LobChunks {
+    reader: Box<dyn Read>,
+    buffer: Vec<u8>,
+}
+
+impl LobChunks {
+    pub fn new(reader: Box<dyn Read>) -> Self {
+        LobChunks {
+            reader,
+            buffer: vec![0; CHUNK_SIZE],
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for LobChunks {
+    type Error = DeserializationError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> DeserializationResult<Option<T::Value>> {
+        let n = self
+            .reader
+            .read(&mut self.buffer)
+            .map_err(|e| ConversionError::Other(Box::new(e)))?;
+        if n == 0 {
+            return Ok(None);
+        }
+        seed.deserialize(BytesDeserializer::new(&self.buffer[..n]))
+            .map(Some)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::de::{
+    struct_fields, DeserializableResultset, DeserializationError, DeserializationOptions,
+    DeserializationResult, FieldnameFallback, ROW_INDEX_FIELD,
+};
+
+/// How the columns of a resultset would be bound to the fields of a struct,
+/// as produced by [`mapping_report`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MappingReport {
+    /// The columns of the resultset, in their order, with their bindings.
+    pub columns: Vec<ColumnBinding>,
+    /// The fields of the struct to which no column would be bound; the deserialization
+    /// only succeeds if they are `Option`s or have a default value.
+    pub unbound_fields: Vec<&'static str>,
+}
+
+impl MappingReport {
+    /// Returns true if every column is bound to a field, and every field to a column.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.unbound_fields.is_empty() && self.unused_columns().is_empty()
+    }
+
+    /// Returns the names of the columns that would not be bound to a field.
+    #[must_use]
+    pub fn unused_columns(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .filter(|c| matches!(c.binding, Binding::Masked | Binding::Unused))
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+}
+
+/// A column of a resultset and the field it would be bound to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnBinding {
+    /// The index of the column.
+    pub index: usize,
+    /// The name of the column, or `#<index>` if the column has no name.
+    pub name: String,
+    /// The type descriptor of the column, if the driver provides it
+    /// (see [`DeserializableResultset::fieldtype`]).
+    pub column_type: Option<String>,
+    /// How the column would be bound.
+    pub binding: Binding,
+}
+
+/// How a column would be bound to the fields of a struct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Binding {
+    /// The column would be converted into the field with the given name.
+    Field(&'static str),
+    /// The column would be converted into a field of the nested struct in the field
+    /// with the given name, due to
+    /// [`DeserializationOptions::with_prefix_separator()`](struct.DeserializationOptions.html#method.with_prefix_separator).
+    Nested(&'static str),
+    /// The column would be converted into the field with the given name, by its position,
+    /// because the resultset has no field names.
+    Positional(&'static str),
+    /// The column would be skipped, because it is excluded by the field mask.
+    Masked,
+    /// The struct has no field for the column; depending on the
+    /// [`UnknownColumnPolicy`](enum.UnknownColumnPolicy.html), the deserialization
+    /// would fail or skip the column.
+    Unused,
+}
+
+/// Reports how the columns of the resultset would be bound to the fields of `T`
+/// when the rows are deserialized with the given options,
+/// without fetching rows and without converting values.
+///
+/// ```rust,ignore
+/// let report = serde_db::de::mapping_report::<MyStruct, _>(&resultset, &options)?;
+/// for column in &report.columns {
+///     println!("{} ({:?}) -> {:?}", column.name, column.column_type, column.binding);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// `DeserializationError::Usage` if `T` is not deserialized as a plain struct
+/// (see [`struct_fields`]), or if the resultset has no field names and the
+/// [`FieldnameFallback`](enum.FieldnameFallback.html) does not allow positional assignment.
+pub fn mapping_report<'de, T, RS>(
+    rs: &RS,
+    options: &DeserializationOptions,
+) -> DeserializationResult<MappingReport>
+where
+    T: serde::Deserialize<'de>,
+    RS: DeserializableResultset,
+{
+    #[cfg(feature = "trace")]
+    log::trace!("serde_db::de::mapping_report()");
+    let fields = struct_fields::<T>().ok_or_else(|| {
+        DeserializationError::Usage(format!(
+            "{} is not deserialized as a plain struct",
+            std::any::type_name::<T>()
+        ))
+    })?;
+    let number_of_columns = rs.number_of_columns();
+    let has_no_fieldnames =
+        number_of_columns > 0 && (0..number_of_columns).all(|idx| rs.fieldname(idx).is_none());
+    if has_no_fieldnames && options.fieldname_fallback() == FieldnameFallback::Error {
+        return Err(DeserializationError::Usage(format!(
+            "{} cannot be deserialized from a row without field names",
+            std::any::type_name::<T>()
+        )));
+    }
+
+    let columns: Vec<ColumnBinding> = (0..number_of_columns)
+        .map(|index| {
+            let binding = if has_no_fieldnames {
+                fields
+                    .get(index)
+                    .map_or(Binding::Unused, |field| Binding::Positional(field))
+            } else {
+                rs.fieldname(index)
+                    .map_or(Binding::Unused, |column| bind(column, fields, options))
+            };
+            ColumnBinding {
+                index,
+                name: rs
+                    .fieldname(index)
+                    .map_or_else(|| format!("#{index}"), ToString::to_string),
+                column_type: rs.fieldtype(index).map(ToString::to_string),
+                binding,
+            }
+        })
+        .collect();
+    let unbound_fields = fields
+        .iter()
+        .copied()
+        .filter(|field| *field != ROW_INDEX_FIELD)
+        .filter(|field| {
+            !columns.iter().any(|c| match c.binding {
+                Binding::Field(f) | Binding::Nested(f) | Binding::Positional(f) => f == *field,
+                Binding::Masked | Binding::Unused => false,
+            })
+        })
+        .collect();
+    Ok(MappingReport {
+        columns,
+        unbound_fields,
+    })
+}
+
+// Resolves the column name like the row deserializer does.
+fn bind(
+    column: &str,
+    fields: &'static [&'static str],
+    options: &DeserializationOptions,
+) -> Binding {
+    let matching_field = |name: &str| {
+        fields.iter().copied().find(|f| *f == name).or_else(|| {
+            fields
+                .iter()
+                .copied()
+                .find(|field| options.field_name_matching().matches(name, field))
+        })
+    };
+    let binding = if let Some(field) = matching_field(column) {
+        Binding::Field(field)
+    } else if let Some(field) = options.prefix_separator().and_then(|separator| {
+        column
+            .match_indices(separator)
+            .find_map(|(pos, _)| matching_field(&column[..pos]))
+    }) {
+        Binding::Nested(field)
+    } else {
+        Binding::Unused
+    };
+    match binding {
+        Binding::Field(field) | Binding::Nested(field) if !options.is_in_field_mask(field) => {
+            Binding::Masked
+        }
+        Binding::Unused if !options.is_in_field_mask(column) => Binding::Masked,
+        binding => binding,
+    }
+}
@@ -0,0 +1,86 @@
+use crate::de::DeserializableResultset;
+
+/// The differences between the column metadata of two resultsets,
+/// as produced by [`metadata_diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetadataDiff {
+    /// Names of the columns that exist only in the second resultset.
+    pub added: Vec<String>,
+    /// Names of the columns that exist only in the first resultset.
+    pub removed: Vec<String>,
+    /// Columns that exist in both resultsets, but with different types.
+    pub retyped: Vec<RetypedColumn>,
+}
+
+impl MetadataDiff {
+    /// Returns true if both resultsets have compatible column metadata.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty()
+    }
+}
+
+/// A column that exists in both compared resultsets, but with a different type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetypedColumn {
+    /// Name of the column.
+    pub name: String,
+    /// Type of the column in the first resultset.
+    pub old_type: String,
+    /// Type of the column in the second resultset.
+    pub new_type: String,
+}
+
+/// Compares the column metadata of two resultsets.
+///
+/// Columns are matched by name; columns without a name are matched by their position
+/// and reported as `#<index>`.
+/// A column is only reported as retyped if both resultsets provide a type descriptor
+/// for it (see [`DeserializableResultset::fieldtype`]).
+///
+/// The resultsets are not consumed, and no rows are fetched.
+pub fn metadata_diff<A, B>(rs_a: &A, rs_b: &B) -> MetadataDiff
+where
+    A: DeserializableResultset,
+    B: DeserializableResultset,
+{
+    #[cfg(feature = "trace")]
+    log::trace!("serde_db::de::metadata_diff()");
+    let columns_a = columns(rs_a);
+    let columns_b = columns(rs_b);
+
+    let mut diff = MetadataDiff::default();
+    for (name, old_type) in &columns_a {
+        match columns_b.iter().find(|(n, _)| n == name) {
+            None => diff.removed.push(name.clone()),
+            Some((_, new_type)) => {
+                if let (Some(old_type), Some(new_type)) = (old_type, new_type) {
+                    if old_type != new_type {
+                        diff.retyped.push(RetypedColumn {
+                            name: name.clone(),
+                            old_type: (*old_type).to_string(),
+                            new_type: (*new_type).to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    for (name, _) in &columns_b {
+        if !columns_a.iter().any(|(n, _)| n == name) {
+            diff.added.push(name.clone());
+        }
+    }
+    diff
+}
+
+fn columns<RS: DeserializableResultset>(rs: &RS) -> Vec<(String, Option<&str>)> {
+    (0..rs.number_of_columns())
+        .map(|idx| {
+            let name = rs
+                .fieldname(idx)
+                .map_or_else(|| format!("#{idx}"), ToString::to_string);
+            (name, rs.fieldtype(idx))
+        })
+        .collect()
+}
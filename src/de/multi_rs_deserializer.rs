@@ -0,0 +1,119 @@
+use crate::de::rs_deserializer::RsDeserializer;
+use crate::de::{
+    DeserializableMultiResultset, DeserializationError, DeserializationOptions,
+    DeserializationResult,
+};
+#[cfg(feature = "trace")]
+use log::trace;
+use serde::de::Visitor;
+use serde::forward_to_deserialize_any;
+
+// Deserialize a sequence of resultsets into a tuple or a sequence.
+#[derive(Debug)]
+pub struct MultiRsDeserializer<'o, MRS> {
+    mrs: MRS,
+    options: &'o DeserializationOptions,
+}
+
+impl<'o, MRS: DeserializableMultiResultset> MultiRsDeserializer<'o, MRS> {
+    pub fn new(mrs: MRS, options: &'o DeserializationOptions) -> Self {
+        #[cfg(feature = "trace")]
+        trace!("MultiRsDeserializer::new()");
+        MultiRsDeserializer { mrs, options }
+    }
+
+    fn visit_resultsets<'x, V>(&mut self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: Visitor<'x>,
+    {
+        let value = visitor.visit_seq(ResultsetsVisitor { de: self })?;
+        if self.mrs.next_resultset()?.is_some() {
+            return Err(DeserializationError::Usage(String::from(
+                "the target has fewer elements than there are resultsets",
+            )));
+        }
+        Ok(value)
+    }
+}
+
+impl<'x, MRS: DeserializableMultiResultset> serde::Deserializer<'x>
+    for &mut MultiRsDeserializer<'_, MRS>
+{
+    type Error = DeserializationError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: Visitor<'x>,
+    {
+        Err(DeserializationError::Usage(String::from(
+            "multiple resultsets can only be deserialized into tuples or sequences",
+        )))
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("MultiRsDeserializer::deserialize_seq()");
+        self.visit_resultsets(visitor)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("MultiRsDeserializer::deserialize_tuple()");
+        self.visit_resultsets(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> DeserializationResult<V::Value>
+    where
+        V: Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("MultiRsDeserializer::deserialize_tuple_struct()");
+        self.visit_resultsets(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.options.is_human_readable()
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'x>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+struct ResultsetsVisitor<'a, 'o, MRS> {
+    de: &'a mut MultiRsDeserializer<'o, MRS>,
+}
+
+impl<'x, MRS: DeserializableMultiResultset> serde::de::SeqAccess<'x>
+    for ResultsetsVisitor<'_, '_, MRS>
+{
+    type Error = DeserializationError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("ResultsetsVisitor.next_element_seed()");
+        match self.de.mrs.next_resultset()? {
+            None => Ok(None),
+            Some(rs) => seed
+                .deserialize(&mut RsDeserializer::try_new(rs, self.de.options)?)
+                .map(Some),
+        }
+    }
+}
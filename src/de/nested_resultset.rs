@@ -0,0 +1,78 @@
+use crate::de::{
+    DbValue, DeserializableResultset, DeserializationError, DeserializationResult, Row,
+};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// A resultset that is contained in a database value, like a nested table in SAP HANA.
+///
+/// Drivers return it from
+/// [`DbValue::into_nested_resultset()`](trait.DbValue.html#method.into_nested_resultset);
+/// it is deserialized like any other resultset, e.g. into a field of type `Vec<Child>`.
+///
+/// ```rust,ignore
+/// let mut nested = NestedResultset::new(vec!["POS".to_string(), "ARTICLE".to_string()]);
+/// nested.push(vec![pos_value, article_value]);
+/// ```
+#[derive(Debug)]
+pub struct NestedResultset<V> {
+    fieldnames: Arc<Vec<String>>,
+    rows: VecDeque<NestedRow<V>>,
+}
+
+impl<V: DbValue> NestedResultset<V> {
+    /// Creates an empty nested resultset with the given column names.
+    #[must_use]
+    pub fn new(fieldnames: Vec<String>) -> Self {
+        NestedResultset {
+            fieldnames: Arc::new(fieldnames),
+            rows: VecDeque::new(),
+        }
+    }
+
+    /// Appends a row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values does not match the number of columns.
+    pub fn push(&mut self, values: Vec<V>) {
+        self.rows
+            .push_back(Row::new(Arc::clone(&self.fieldnames), values));
+    }
+}
+
+impl<V: DbValue> DeserializableResultset for NestedResultset<V> {
+    type E = DeserializationError;
+    type ROW = NestedRow<V>;
+
+    fn has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+        Ok(self.rows.len() > 1)
+    }
+
+    fn has_no_rows(&mut self) -> DeserializationResult<bool> {
+        Ok(self.rows.is_empty())
+    }
+
+    fn next(&mut self) -> DeserializationResult<Option<NestedRow<V>>> {
+        Ok(self.rows.pop_front())
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.fieldnames.len()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.fieldnames.get(field_idx).map(String::as_str)
+    }
+
+    fn remaining_rows(&self) -> Option<usize> {
+        Some(self.rows.len())
+    }
+
+    fn take_row(&mut self, idx: usize) -> DeserializationResult<Option<NestedRow<V>>> {
+        Ok(self.rows.remove(idx))
+    }
+}
+
+/// A row of a [`NestedResultset`].
+pub type NestedRow<V> = Row<V>;
@@ -0,0 +1,25 @@
+use crate::de::typed_columns::TypedColumn;
+use crate::de::{DeserializationError, DeserializationResult};
+use polars::frame::DataFrame;
+use polars::prelude::{Column, NamedFrom, Series};
+
+// Builds a data frame from the typed columns of a resultset.
+pub fn dataframe(columns: Vec<(String, TypedColumn)>) -> DeserializationResult<DataFrame> {
+    let height = columns.first().map_or(0, |(_, column)| column.len());
+    let columns = columns
+        .into_iter()
+        .map(|(name, column)| {
+            let name = name.into();
+            Column::from(match column {
+                TypedColumn::Null(len) => Series::new_null(name, len),
+                TypedColumn::Bool(values) => Series::new(name, values),
+                TypedColumn::Int(values) => Series::new(name, values),
+                TypedColumn::Float(values) => Series::new(name, values),
+                TypedColumn::Text(values) => Series::new(name, values),
+                TypedColumn::Bytes(values) => Series::new(name, values),
+            })
+        })
+        .collect();
+    DataFrame::new(height, columns)
+        .map_err(|e| DeserializationError::Usage(format!("cannot build the data frame: {e}")))
+}
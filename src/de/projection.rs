@@ -0,0 +1,105 @@
+use crate::de::{
+    DeserializableResultset, DeserializableRow, DeserializationError, DeserializationResult, Row,
+};
+use std::sync::Arc;
+
+/// A view on a resultset whose rows only expose the selected columns, in the selected order.
+///
+/// Is returned by
+/// [`DeserializableResultset::project()`](trait.DeserializableResultset.html#method.project)
+/// and [`DeserializableResultset::project_at()`](trait.DeserializableResultset.html#method.project_at).
+/// The values of the other columns are dropped without being converted.
+#[derive(Debug)]
+pub struct Projection<RS> {
+    rs: RS,
+    indices: Vec<usize>,
+    metadata: Arc<Vec<Option<String>>>,
+}
+
+impl<RS: DeserializableResultset> Projection<RS> {
+    pub(crate) fn try_new(rs: RS, indices: Vec<usize>) -> DeserializationResult<Self> {
+        let number_of_fields = rs.number_of_fields();
+        for (pos, idx) in indices.iter().enumerate() {
+            if *idx >= number_of_fields {
+                return Err(DeserializationError::Usage(format!(
+                    "projection refers to column {idx}, but the resultset has only \
+                     {number_of_fields} columns"
+                )));
+            }
+            if indices[..pos].contains(idx) {
+                return Err(DeserializationError::Usage(format!(
+                    "projection contains column {idx} more than once"
+                )));
+            }
+        }
+        let metadata = Arc::new(
+            indices
+                .iter()
+                .map(|idx| rs.fieldname(*idx).map(ToString::to_string))
+                .collect(),
+        );
+        Ok(Projection {
+            rs,
+            indices,
+            metadata,
+        })
+    }
+
+    /// Returns the underlying resultset.
+    pub fn into_inner(self) -> RS {
+        self.rs
+    }
+
+    fn project_row(
+        &self,
+        mut row: RS::ROW,
+    ) -> Row<<RS::ROW as DeserializableRow>::V, Vec<Option<String>>, RS::E> {
+        let mut values = Vec::with_capacity(row.len());
+        while let Some(value) = row.next() {
+            values.push(Some(value));
+        }
+        let projected = self
+            .indices
+            .iter()
+            .map(|idx| values[*idx].take().unwrap(/* indices are checked in try_new() */))
+            .collect();
+        Row::new(Arc::clone(&self.metadata), projected)
+    }
+}
+
+impl<RS: DeserializableResultset> DeserializableResultset for Projection<RS> {
+    type E = RS::E;
+    type ROW = Row<<RS::ROW as DeserializableRow>::V, Vec<Option<String>>, RS::E>;
+
+    fn has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+        self.rs.has_multiple_rows()
+    }
+
+    fn next(&mut self) -> DeserializationResult<Option<Self::ROW>> {
+        Ok(self.rs.next()?.map(|row| self.project_row(row)))
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.metadata.get(field_idx)?.as_deref()
+    }
+
+    fn fieldtype(&self, field_idx: usize) -> Option<&str> {
+        self.rs.fieldtype(*self.indices.get(field_idx)?)
+    }
+
+    fn remaining_rows(&self) -> Option<usize> {
+        self.rs.remaining_rows()
+    }
+
+    fn has_no_rows(&mut self) -> DeserializationResult<bool> {
+        self.rs.has_no_rows()
+    }
+
+    fn take_row(&mut self, idx: usize) -> DeserializationResult<Option<Self::ROW>> {
+        Ok(self.rs.take_row(idx)?.map(|row| self.project_row(row)))
+    }
+}
@@ -0,0 +1,141 @@
+use crate::de::{DbValue, DeserializableRow, DeserializationError};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Describes the columns of a [`Row`]; is shared by all rows of a resultset.
+pub trait RowMetadata {
+    /// Returns the number of fields in a complete row.
+    fn number_of_fields(&self) -> usize;
+
+    /// Returns the name of the column at the specified index.
+    fn fieldname(&self, field_idx: usize) -> Option<&str>;
+}
+
+impl RowMetadata for Vec<String> {
+    fn number_of_fields(&self) -> usize {
+        self.len()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.get(field_idx).map(String::as_str)
+    }
+}
+
+impl RowMetadata for Vec<Option<String>> {
+    fn number_of_fields(&self) -> usize {
+        self.len()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.get(field_idx)?.as_deref()
+    }
+}
+
+/// A generic row, which drivers can use instead of implementing their own row type.
+///
+/// A row consists of its values and of metadata that are shared with the other rows
+/// of the resultset. `Row` implements
+/// [`DeserializableRow`](trait.DeserializableRow.html), with error type `E`.
+///
+/// ```rust,ignore
+/// let metadata = Arc::new(vec!["ID".to_string(), "NAME".to_string()]);
+/// let row: Row<MyDbValue> = Row::new(Arc::clone(&metadata), vec![id_value, name_value]);
+/// let (id, name): (u32, String) = row.try_into()?;
+/// ```
+#[derive(Debug)]
+pub struct Row<V, MD = Vec<String>, E = DeserializationError> {
+    metadata: Arc<MD>,
+    // in reverse order, so that the next value can be popped from the end
+    values: Vec<V>,
+    _error: PhantomData<fn() -> E>,
+}
+
+impl<V: Clone, MD, E> Clone for Row<V, MD, E> {
+    fn clone(&self) -> Self {
+        Row {
+            metadata: Arc::clone(&self.metadata),
+            values: self.values.clone(),
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<V, MD: RowMetadata, E> Row<V, MD, E> {
+    /// Creates a row from the metadata and the values, in column order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values does not match the number of fields.
+    #[must_use]
+    pub fn new(metadata: Arc<MD>, mut values: Vec<V>) -> Self {
+        assert_eq!(metadata.number_of_fields(), values.len());
+        values.reverse();
+        Row {
+            metadata,
+            values,
+            _error: PhantomData,
+        }
+    }
+
+    /// Returns the metadata of the row.
+    #[must_use]
+    pub fn metadata(&self) -> &Arc<MD> {
+        &self.metadata
+    }
+
+    /// Returns the index of the column with the given name.
+    #[must_use]
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        (0..self.metadata.number_of_fields())
+            .find(|idx| self.metadata.fieldname(*idx) == Some(name))
+    }
+
+    /// Returns the value of the specified column, unless it was already consumed.
+    #[must_use]
+    pub fn get(&self, column_idx: usize) -> Option<&V> {
+        let consumed = self.metadata.number_of_fields() - self.values.len();
+        column_idx
+            .checked_sub(consumed)
+            .and_then(|idx| self.values.len().checked_sub(idx + 1))
+            .map(|idx| &self.values[idx])
+    }
+
+    /// Returns the value of the column with the given name, unless it was already consumed.
+    #[must_use]
+    pub fn get_by_name(&self, name: &str) -> Option<&V> {
+        self.column_index(name).and_then(|idx| self.get(idx))
+    }
+
+    /// Returns the values that were not yet consumed, in column order.
+    #[must_use]
+    pub fn into_values(mut self) -> Vec<V> {
+        self.values.reverse();
+        self.values
+    }
+}
+
+impl<V, MD, E> DeserializableRow for Row<V, MD, E>
+where
+    V: DbValue,
+    MD: RowMetadata,
+    E: From<DeserializationError>,
+{
+    type E = E;
+    type V = V;
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn next(&mut self) -> Option<V> {
+        self.values.pop()
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.metadata.number_of_fields()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.metadata.fieldname(field_idx)
+    }
+}
@@ -1,10 +1,34 @@
+use crate::de::db_interval::DB_INTERVAL;
 use crate::de::field_deserializer::FieldDeserializer;
+use crate::de::lob::LOB_STREAM;
+use crate::de::timezone::TIMEZONE_AWARE;
 use crate::de::{
-    DbValue, DbValueInto, DeserializableRow, DeserializationError, DeserializationResult,
+    DbValue, DeserializableRow, DeserializationError, DeserializationOptions,
+    DeserializationResult, DeserializationWarning, FieldnameFallback, NullEvent, NullOutcome,
+    NullPolicy, UnknownColumnPolicy,
 };
 #[cfg(feature = "trace")]
 use log::trace;
+use serde::de::value::StrDeserializer;
+use serde::forward_to_deserialize_any;
 use serde::Deserialize as SD;
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The name of a struct field that is filled with the zero-based index of the row
+/// in its resultset, rather than with a column value.
+///
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct Line {
+///     #[serde(rename = "__row_index")]
+///     row_index: usize,
+///     name: String,
+/// }
+/// ```
+///
+/// The field is filled when resultsets are converted, not when individual rows are converted.
+pub const ROW_INDEX_FIELD: &str = "__row_index";
 
 #[derive(Debug)]
 enum Need {
@@ -15,33 +39,78 @@ enum Need {
 
 // Deserialize a single Row into a normal rust type.
 #[derive(Debug)]
-pub struct RowDeserializer<ROW> {
+pub struct RowDeserializer<'o, ROW> {
     row: ROW,
     need: Need,
+    options: &'o DeserializationOptions,
+    row_index: Option<usize>,
+    // the indices of the columns whose values were dropped without being converted
+    unused_columns: Vec<usize>,
+    // true while the value of a field of the target struct is deserialized
+    is_known_field: bool,
+    // true if the positional fallback was already reported for the resultset
+    fallback_reported: bool,
 }
 
-impl<ROW> RowDeserializer<ROW>
+impl<'o, ROW> RowDeserializer<'o, ROW>
 where
     ROW: DeserializableRow,
     <ROW as DeserializableRow>::V: DbValue,
 {
-    pub fn new(row: ROW) -> RowDeserializer<ROW> {
+    pub fn new(row: ROW, options: &'o DeserializationOptions) -> RowDeserializer<'o, ROW> {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::new()");
-        let cols_treat = match row.len() {
-            1 => Need::Can,
-            _ => Need::Must,
+        let cols_treat = if row.len() == 1 {
+            Need::Can
+        } else {
+            Need::Must
         };
         RowDeserializer {
             row,
             need: cols_treat,
+            options,
+            row_index: None,
+            unused_columns: Vec::new(),
+            is_known_field: false,
+            fallback_reported: false,
         }
     }
 
+    // Suppresses the warning about the positional fallback if it was already reported
+    // for an earlier row of the resultset.
+    pub fn with_fallback_reported(mut self, fallback_reported: bool) -> Self {
+        self.fallback_reported = fallback_reported;
+        self
+    }
+
+    // Returns true if the warning about the positional fallback was reported,
+    // for this or an earlier row.
+    pub fn fallback_reported(&self) -> bool {
+        self.fallback_reported
+    }
+
+    // Sets the index of the row in its resultset, with which a struct field
+    // named ROW_INDEX_FIELD is filled.
+    pub fn with_row_index(mut self, row_index: usize) -> Self {
+        self.row_index = Some(row_index);
+        self
+    }
+
     fn get_fieldname(&self, idx: usize) -> Option<&str> {
         self.row.fieldname(idx)
     }
 
+    // Returns true if the driver provides no field names.
+    fn has_no_fieldnames(&self) -> bool {
+        let columns = self.row.number_of_columns();
+        columns > 0 && (0..columns).all(|idx| self.get_fieldname(idx).is_none())
+    }
+
+    // Returns the index of the next column.
+    fn column_index(&self) -> usize {
+        self.row.number_of_columns() - self.row.len()
+    }
+
     fn next_value(&mut self) -> DeserializationResult<ROW::V> {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::next_value()");
@@ -54,9 +123,101 @@ where
             None => Err(impl_err("next_value(): no more value found in row")),
         }
     }
+
+    // Deserializes the next value of the row with `f`.
+    fn deserialize_next_field<T, F>(&mut self, f: F) -> DeserializationResult<T>
+    where
+        F: FnOnce(FieldDeserializer<'_, ROW::V>) -> DeserializationResult<T>,
+    {
+        let idx = self.column_index();
+        let value = self.next_value()?;
+        self.deserialize_field(idx, value, f)
+    }
+
+    // Deserializes the value of column `idx` with `f`, and reports NULL values to the observer.
+    fn deserialize_field<T, F>(&self, idx: usize, value: ROW::V, f: F) -> DeserializationResult<T>
+    where
+        F: FnOnce(FieldDeserializer<'_, ROW::V>) -> DeserializationResult<T>,
+    {
+        #[cfg(feature = "trace")]
+        trace!(
+            "RowDeserializer: converting column {idx} ({})",
+            self.get_fieldname(idx).unwrap_or("<unnamed>")
+        );
+        let result = if value.is_null() {
+            let defaulted = AtomicBool::new(false);
+            let result =
+                f(FieldDeserializer::new(value, self.options).with_default_flag(&defaulted));
+            self.report_null(
+                idx,
+                match result {
+                    Ok(_) if defaulted.load(Ordering::Relaxed) => NullOutcome::Defaulted,
+                    Ok(_) => NullOutcome::Accepted,
+                    Err(_) => NullOutcome::Error,
+                },
+            );
+            result
+        } else {
+            f(FieldDeserializer::new(value, self.options))
+        };
+        let result = result.map_err(|e| match self.get_fieldname(idx) {
+            Some(column) if self.options.is_sensitive_column(column) => {
+                e.redacted(column, std::any::type_name::<T>())
+            }
+            _ => e,
+        });
+        #[cfg(feature = "trace")]
+        if let Err(ref e) = result {
+            log::debug!(
+                "conversion of column {idx} ({}) failed: {e:?}",
+                self.get_fieldname(idx).unwrap_or("<unnamed>")
+            );
+        }
+        result
+    }
+
+    // Drops the next value without converting it.
+    fn drop_next_value(&mut self) {
+        self.unused_columns.push(self.column_index());
+        self.row.next();
+    }
+
+    // Fails if there are unused columns and they are denied,
+    // otherwise informs the observer about them, once per resultset.
+    fn check_unused_columns(&self) -> DeserializationResult<()> {
+        if self.unused_columns.is_empty() {
+            return Ok(());
+        }
+        let columns = self
+            .unused_columns
+            .iter()
+            .map(|idx| self.get_fieldname(*idx).unwrap_or_default());
+        if self.options.deny_unused_columns() {
+            return Err(DeserializationError::UnusedColumns(
+                columns.map(ToString::to_string).collect(),
+            ));
+        }
+        if self.row_index.is_none_or(|idx| idx == 0) {
+            if let Some(observer) = self.options.observer() {
+                observer.unused_columns(&columns.collect::<Vec<&str>>());
+            }
+        }
+        Ok(())
+    }
+
+    // Informs the observer, if one is set, about a NULL value in column `idx`.
+    fn report_null(&self, idx: usize, outcome: NullOutcome) {
+        if let Some(observer) = self.options.observer() {
+            observer.null_encountered(&NullEvent {
+                column_index: idx,
+                column_name: self.get_fieldname(idx),
+                outcome,
+            });
+        }
+    }
 }
 
-impl<'x, 'a, ROW: DeserializableRow> serde::Deserializer<'x> for &'a mut RowDeserializer<ROW>
+impl<'x, ROW: DeserializableRow> serde::Deserializer<'x> for &mut RowDeserializer<'_, ROW>
 where
     <ROW as DeserializableRow>::V: DbValue,
 {
@@ -68,7 +229,13 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_any()");
-        visitor.visit_string(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        if let Need::Must = self.need {
+            // e.g. internally tagged enums, where the tag is one of the columns
+            self.need = Need::Done;
+            visitor.visit_map(FieldsMapVisitor::new(self, String::new(), None))
+        } else {
+            self.deserialize_next_field(|fd| fd.deserialize_any(visitor))
+        }
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -77,7 +244,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_bool()");
-        visitor.visit_bool(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_bool(SD::deserialize(fd)?))
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -86,7 +253,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u8()");
-        visitor.visit_u8(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_u8(SD::deserialize(fd)?))
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -95,7 +262,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u16()");
-        visitor.visit_u16(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_u16(SD::deserialize(fd)?))
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -104,7 +271,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u32()");
-        visitor.visit_u32(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_u32(SD::deserialize(fd)?))
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -113,7 +280,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u64()");
-        visitor.visit_u64(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_u64(SD::deserialize(fd)?))
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -122,7 +289,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i8()");
-        visitor.visit_i8(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_i8(SD::deserialize(fd)?))
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -131,7 +298,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i16()");
-        visitor.visit_i16(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_i16(SD::deserialize(fd)?))
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -140,7 +307,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i32()");
-        visitor.visit_i32(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_i32(SD::deserialize(fd)?))
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -149,7 +316,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i64()");
-        visitor.visit_i64(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_i64(SD::deserialize(fd)?))
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -158,7 +325,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_f32()");
-        visitor.visit_f32(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_f32(SD::deserialize(fd)?))
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -167,16 +334,16 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_f64()");
-        visitor.visit_f64(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_f64(SD::deserialize(fd)?))
     }
 
-    fn deserialize_char<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_char<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_char()!",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_char()");
+        self.deserialize_next_field(|fd| fd.deserialize_char(visitor))
     }
 
     fn deserialize_str<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -194,16 +361,16 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_string()");
-        visitor.visit_string(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        self.deserialize_next_field(|fd| visitor.visit_string(SD::deserialize(fd)?))
     }
 
-    fn deserialize_unit<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_unit<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_unit()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_unit()");
+        self.deserialize_next_field(|fd| fd.deserialize_unit(visitor))
     }
 
     fn deserialize_option<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -212,83 +379,93 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_option()");
-        FieldDeserializer::new(self.next_value()?).deserialize_option(visitor)
+        self.deserialize_next_field(|fd| fd.deserialize_option(visitor))
     }
 
     #[inline]
-    fn deserialize_seq<V>(mut self, visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_seq<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_seq()");
         if let Need::Done = self.need {
-            Err(impl_err(
-                "double-nesting (struct/tuple in struct/tuple) not possible",
-            ))
+            self.deserialize_next_field(|fd| fd.deserialize_seq(visitor))
         } else {
             self.need = Need::Done;
             visitor.visit_seq(FieldsSeqVisitor::new(self))
         }
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_map<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_map()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_map()");
+        if let Need::Done = self.need {
+            self.deserialize_next_field(|fd| fd.deserialize_map(visitor))
+        } else {
+            // e.g. structs with #[serde(flatten)], or maps with the column names as keys
+            self.need = Need::Done;
+            visitor.visit_map(FieldsMapVisitor::new(self, String::new(), None))
+        }
     }
 
     fn deserialize_unit_struct<V>(
         self,
-        _name: &'static str,
-        _visitor: V,
+        name: &'static str,
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_unit_struct()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_unit_struct()");
+        self.deserialize_next_field(|fd| fd.deserialize_unit_struct(name, visitor))
     }
 
     #[allow(clippy::used_underscore_binding)]
     fn deserialize_newtype_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!(
-            "RowDeserializer::deserialize_newtype_struct() with _name = {}",
-            _name
-        );
+        trace!("RowDeserializer::deserialize_newtype_struct() with name = {name}");
+        if name == TIMEZONE_AWARE || name == LOB_STREAM || name == DB_INTERVAL {
+            return self.deserialize_next_field(|fd| fd.deserialize_newtype_struct(name, visitor));
+        }
         visitor.visit_newtype_struct(self)
     }
 
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
-        _len: usize,
-        _visitor: V,
+        name: &'static str,
+        len: usize,
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_tuple_struct()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_tuple_struct()");
+        if let Need::Done = self.need {
+            self.deserialize_next_field(|fd| fd.deserialize_tuple_struct(name, len, visitor))
+        } else {
+            Err(DeserializationError::NotImplemented(
+                "RowDeserializer::deserialize_tuple_struct()",
+            ))
+        }
     }
 
     fn deserialize_struct<V>(
-        mut self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
         visitor: V,
     ) -> DeserializationResult<V::Value>
     where
@@ -297,10 +474,36 @@ where
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_struct()");
         if let Need::Done = self.need {
-            Err(impl_err("double-nesting (struct in struct) not possible"))
+            self.deserialize_next_field(|fd| fd.deserialize_struct(name, fields, visitor))
+        } else if self.has_no_fieldnames() {
+            match self.options.fieldname_fallback() {
+                FieldnameFallback::Positional => {
+                    if let (false, Some(observer)) =
+                        (self.fallback_reported, self.options.observer())
+                    {
+                        observer.warning(&DeserializationWarning::PositionalFallback {
+                            struct_name: name,
+                        });
+                    }
+                    self.fallback_reported = true;
+                    self.need = Need::Done;
+                    let result = visitor.visit_seq(FieldsSeqVisitor::new(self))?;
+                    while !self.row.is_empty() {
+                        self.drop_next_value();
+                    }
+                    self.check_unused_columns()?;
+                    Ok(result)
+                }
+                FieldnameFallback::Error => Err(DeserializationError::Usage(format!(
+                    "struct {name} cannot be deserialized from a row without field names"
+                ))),
+            }
         } else {
             self.need = Need::Done;
-            visitor.visit_map(FieldsMapVisitor::new(self))
+            let result =
+                visitor.visit_map(FieldsMapVisitor::new(self, String::new(), Some(fields)))?;
+            self.check_unused_columns()?;
+            Ok(result)
         }
     }
 
@@ -310,7 +513,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_bytes()");
-        visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.next_value()?)?)
+        self.deserialize_next_field(|fd| fd.deserialize_bytes(visitor))
     }
 
     fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -319,99 +522,189 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_byte_buf()");
-        visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.next_value()?)?)
+        self.deserialize_next_field(|fd| fd.deserialize_byte_buf(visitor))
     }
 
-    fn deserialize_tuple<V>(mut self, _len: usize, visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_tuple()");
         if let Need::Done = self.need {
-            Err(impl_err(
-                "double-nesting (struct/tuple in struct/tuple) not possible",
-            ))
+            self.deserialize_next_field(|fd| fd.deserialize_tuple(len, visitor))
+        } else if self.row.len() < len {
+            // e.g. an array [T; N] that is longer than the row
+            Err(DeserializationError::Usage(format!(
+                "the row has {} columns, but the target has {len} elements",
+                self.row.len()
+            )))
         } else {
             self.need = Need::Done;
-            visitor.visit_seq(FieldsSeqVisitor::new(self))
+            let result = visitor.visit_seq(FieldsSeqVisitor::new(self))?;
+            while !self.row.is_empty() {
+                self.drop_next_value();
+            }
+            self.check_unused_columns()?;
+            Ok(result)
         }
     }
 
     fn deserialize_enum<V>(
         self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
-        _visitor: V,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_enum()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_enum()");
+        self.deserialize_next_field(|fd| fd.deserialize_enum(name, variants, visitor))
     }
 
     fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
-        match self.row.len() {
-            0 => Err(impl_err(
-                "empty row in RowDeserializer::deserialize_identifier()",
-            )),
-            curr_len => {
-                let idx = self.row.number_of_fields() - curr_len;
-                match self.get_fieldname(idx) {
-                    Some(fieldname) => {
-                        #[cfg(feature = "trace")]
-                        trace!(
-                            "RowDeserializer::deserialize_identifier(): column {:?} ({})",
-                            idx,
-                            fieldname
-                        );
-                        visitor.visit_str(fieldname)
-                    }
-                    None => Err(impl_err(
-                        "no fieldname in RowDeserializer::deserialize_identifier()",
-                    )),
-                }
-            }
-        }
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_identifier()");
+        self.deserialize_next_field(|fd| fd.deserialize_identifier(visitor))
     }
 
-    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_ignored_any()");
-        let fieldname = self
-            .get_fieldname(self.row.number_of_fields() - self.row.len())
-            .unwrap_or("unknown");
+        if self.is_known_field {
+            // a struct field of type IgnoredAny; its value is dropped without being converted
+            self.row.next();
+            return visitor.visit_unit();
+        }
+        if self.options.unknown_column_policy() == UnknownColumnPolicy::Ignore {
+            self.drop_next_value();
+            return visitor.visit_unit();
+        }
+        let fieldname = self.get_fieldname(self.column_index()).unwrap_or("unknown");
         Err(DeserializationError::UnknownField(fieldname.to_string()))
     }
+
+    fn is_human_readable(&self) -> bool {
+        self.options.is_human_readable()
+    }
 }
 
-struct FieldsMapVisitor<'a, R: 'a + DeserializableRow>
+// Provides the columns of a row as map entries, with the column names as keys.
+//
+// If a prefix separator is configured, consecutive columns like `address_street`,
+// `address_city` are provided as a single entry with key `address`, if `address`
+// is a field of the target struct; its value is a nested map-like structure.
+struct FieldsMapVisitor<'a, 'o, R: 'a + DeserializableRow>
 where
     <R as DeserializableRow>::V: DbValue,
 {
-    de: &'a mut RowDeserializer<R>,
+    de: &'a mut RowDeserializer<'o, R>,
+    // only columns that start with this prefix belong to the map
+    prefix: String,
+    // the fields of the target struct, if known
+    fields: Option<&'static [&'static str]>,
+    // the prefix of a nested structure, whose key was just provided
+    pending_prefix: Option<String>,
+    // the value that was taken from the row to check it for NULL, and its column index
+    pending_value: Option<(usize, R::V)>,
+    // the row index, if the target struct has a field for it, and if its key was not yet provided
+    row_index: Option<usize>,
+    // the row index, whose key was just provided
+    pending_row_index: Option<usize>,
+    // the key that was just provided, if errors are to contain the field path;
+    // borrowed from the target struct, if it is one of its fields
+    pending_key: Option<Cow<'static, str>>,
+    // true if the key that was just provided is a field of the target struct
+    pending_is_known: bool,
 }
 
-impl<'a, R: DeserializableRow> FieldsMapVisitor<'a, R>
+impl<'a, 'o, R: DeserializableRow> FieldsMapVisitor<'a, 'o, R>
 where
     <R as DeserializableRow>::V: DbValue,
 {
-    pub fn new(de: &'a mut RowDeserializer<R>) -> Self {
+    pub fn new(
+        de: &'a mut RowDeserializer<'o, R>,
+        prefix: String,
+        fields: Option<&'static [&'static str]>,
+    ) -> Self {
         #[cfg(feature = "trace")]
         trace!("FieldsMapVisitor::new()");
-        FieldsMapVisitor { de }
+        let row_index = de
+            .row_index
+            .filter(|_| prefix.is_empty())
+            .filter(|_| fields.is_some_and(|fields| fields.contains(&ROW_INDEX_FIELD)));
+        FieldsMapVisitor {
+            de,
+            prefix,
+            fields,
+            pending_prefix: None,
+            pending_value: None,
+            row_index,
+            pending_row_index: None,
+            pending_key: None,
+            pending_is_known: false,
+        }
+    }
+
+    // Drops the current column, or all consecutive columns with the given prefix.
+    fn skip_columns(&mut self, prefix: Option<&str>) {
+        self.de.drop_next_value();
+        if let Some(prefix) = prefix {
+            while !self.de.row.is_empty() {
+                let idx = self.de.column_index();
+                if !self
+                    .de
+                    .get_fieldname(idx)
+                    .is_some_and(|f| f.starts_with(prefix))
+                {
+                    break;
+                }
+                self.de.drop_next_value();
+            }
+        }
+    }
+
+    // Returns the field of the target struct that matches the column name.
+    fn matching_field(&self, column: &str) -> Option<&'static str> {
+        let fields = self.fields?;
+        if let Some(field) = fields.iter().find(|f| **f == column) {
+            return Some(field);
+        }
+        let matching = self.de.options.field_name_matching();
+        fields
+            .iter()
+            .copied()
+            .find(|field| matching.matches(column, field))
+    }
+
+    // Resolves the column name to the key of the map entry.
+    // For the first column of a nested structure, the prefix of the nested structure
+    // is returned as well.
+    fn resolve_key<'c>(&self, column: &'c str) -> (&'c str, Option<String>) {
+        if let Some(field) = self.matching_field(column) {
+            return (field, None);
+        }
+        if let Some(separator) = self.de.options.prefix_separator() {
+            for (pos, _) in column.match_indices(separator) {
+                if let Some(field) = self.matching_field(&column[..pos]) {
+                    let prefix = format!("{}{}{separator}", self.prefix, &column[..pos]);
+                    return (field, Some(prefix));
+                }
+            }
+        }
+        (column, None)
     }
 }
 
-impl<'x, 'a, R: DeserializableRow> serde::de::MapAccess<'x> for FieldsMapVisitor<'a, R>
+impl<'x, R: DeserializableRow> serde::de::MapAccess<'x> for FieldsMapVisitor<'_, '_, R>
 where
     <R as DeserializableRow>::V: DbValue,
 {
@@ -421,47 +714,218 @@ where
     where
         K: serde::de::DeserializeSeed<'x>,
     {
-        match self.de.row.len() {
-            0 => {
+        if let Some(row_index) = self.row_index.take() {
+            self.pending_row_index = Some(row_index);
+            return seed
+                .deserialize(StrDeserializer::<DeserializationError>::new(
+                    ROW_INDEX_FIELD,
+                ))
+                .map(Some);
+        }
+        loop {
+            if self.de.row.is_empty() {
                 #[cfg(feature = "trace")]
                 trace!("FieldsMapVisitor::next_key_seed() on empty row");
-                Ok(None)
+                return Ok(None);
             }
-            len => {
-                let idx = self.de.row.number_of_fields() - len;
+            let idx = self.de.column_index();
+            #[cfg(feature = "trace")]
+            trace!("FieldsMapVisitor::next_key_seed() for col {idx}");
+            let Some(fname) = self.de.get_fieldname(idx) else {
+                return Err(impl_err("FieldsMapVisitor::next_key_seed(): no fieldname"));
+            };
+            let Some(column) = fname.strip_prefix(self.prefix.as_str()) else {
+                // end of a nested structure
+                return Ok(None);
+            };
+            let (key, nested_prefix) = self.resolve_key(column);
+            let is_unknown = nested_prefix.is_none()
+                && self.de.options.unknown_column_policy() == UnknownColumnPolicy::Ignore
+                && self.fields.is_some_and(|fields| !fields.contains(&key));
+            if is_unknown || (self.prefix.is_empty() && !self.de.options.is_in_field_mask(key)) {
+                // skip the column without converting its value
                 #[cfg(feature = "trace")]
-                trace!("FieldsMapVisitor::next_key_seed() for col {}", idx);
-                let value = seed.deserialize(&mut *self.de);
-                if let Ok(res) = value {
-                    Ok(Some(res))
-                } else {
-                    let fname = self.de.get_fieldname(idx).unwrap();
-                    #[cfg(feature = "trace")]
-                    trace!("FieldsMapVisitor::next_key_seed(): Error at {}", fname);
-                    Err(DeserializationError::UnknownField(fname.to_string()))
+                trace!("FieldsMapVisitor::next_key_seed(): skipping {fname}");
+                self.skip_columns(nested_prefix.as_deref());
+                continue;
+            }
+            let omittable =
+                nested_prefix.is_none() && self.de.options.null_policy() == NullPolicy::Omit;
+            if let Some(field) = self
+                .fields
+                .filter(|_| omittable)
+                .and_then(|fields| fields.iter().copied().find(|f| *f == key))
+            {
+                let Some(value) = self.de.row.next() else {
+                    return Err(impl_err("FieldsMapVisitor::next_key_seed(): no value"));
+                };
+                if value.is_null() {
+                    // omit the column, so that serde handles the field as missing
+                    self.de.report_null(idx, NullOutcome::Omitted);
+                    continue;
+                }
+                self.pending_value = Some((idx, value));
+                if self.de.options.field_paths() {
+                    self.pending_key = Some(Cow::Borrowed(field));
                 }
+                return seed
+                    .deserialize(StrDeserializer::<DeserializationError>::new(field))
+                    .map(Some);
+            }
+            self.pending_is_known =
+                nested_prefix.is_none() && self.fields.is_some_and(|fields| fields.contains(&key));
+            self.pending_prefix = nested_prefix;
+            if self.de.options.field_paths() {
+                self.pending_key = Some(
+                    self.fields
+                        .and_then(|fields| fields.iter().copied().find(|f| *f == key))
+                        .map_or_else(|| Cow::Owned(key.to_string()), Cow::Borrowed),
+                );
+            }
+            if let Ok(res) = seed.deserialize(StrDeserializer::<DeserializationError>::new(key)) {
+                return Ok(Some(res));
             }
+            #[cfg(feature = "trace")]
+            trace!("FieldsMapVisitor::next_key_seed(): Error at {fname}");
+            return Err(DeserializationError::UnknownField(fname.to_string()));
         }
     }
 
-    #[allow(clippy::used_underscore_binding)]
     fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::DeserializeSeed<'x>,
     {
-        match self.de.row.len() {
-            0 => Err(impl_err(
+        if let Some(row_index) = self.pending_row_index.take() {
+            return seed.deserialize(RowIndexDeserializer(row_index));
+        }
+        let key = self.pending_key.take();
+        let result = if let Some((idx, value)) = self.pending_value.take() {
+            self.de
+                .deserialize_field(idx, value, |fd| seed.deserialize(fd))
+        } else if self.de.row.is_empty() {
+            Err(impl_err(
                 "FieldsMapVisitor::next_value_seed(): no more value",
-            )),
-            _len => {
-                #[cfg(feature = "trace")]
-                trace!(
-                    "FieldsMapVisitor::next_value_seed() for col {}",
-                    self.de.row.number_of_fields() - _len
-                );
-                seed.deserialize(&mut *self.de)
+            ))
+        } else {
+            #[cfg(feature = "trace")]
+            trace!(
+                "FieldsMapVisitor::next_value_seed() for col {}",
+                self.de.column_index()
+            );
+            if let Some(prefix) = self.pending_prefix.take() {
+                seed.deserialize(NestedDeserializer {
+                    de: &mut *self.de,
+                    prefix,
+                })
+            } else {
+                self.de.is_known_field = std::mem::take(&mut self.pending_is_known);
+                let result = seed.deserialize(&mut *self.de);
+                self.de.is_known_field = false;
+                result
             }
-        }
+        };
+        result.map_err(|e| match key {
+            Some(key) => e.at_field(&key),
+            None => e,
+        })
+    }
+}
+
+// Deserializes the row index into integer fields, or into optional integer fields.
+struct RowIndexDeserializer(usize);
+
+impl<'x> serde::Deserializer<'x> for RowIndexDeserializer {
+    type Error = DeserializationError;
+
+    fn deserialize_any<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        visitor.visit_u64(self.0 as u64)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        visitor.visit_some(self)
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'x>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+// Deserializes the consecutive columns with a common prefix into a nested structure.
+struct NestedDeserializer<'a, 'o, R: 'a + DeserializableRow>
+where
+    <R as DeserializableRow>::V: DbValue,
+{
+    de: &'a mut RowDeserializer<'o, R>,
+    prefix: String,
+}
+
+impl<'x, R: DeserializableRow> serde::Deserializer<'x> for NestedDeserializer<'_, '_, R>
+where
+    <R as DeserializableRow>::V: DbValue,
+{
+    type Error = DeserializationError;
+
+    fn deserialize_any<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("NestedDeserializer::deserialize_any() for {}", self.prefix);
+        visitor.visit_map(FieldsMapVisitor::new(self.de, self.prefix, None))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!(
+            "NestedDeserializer::deserialize_struct() for {}",
+            self.prefix
+        );
+        visitor.visit_map(FieldsMapVisitor::new(self.de, self.prefix, Some(fields)))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.de.options.is_human_readable()
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'x>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map enum identifier ignored_any
     }
 }
 
@@ -469,24 +933,24 @@ fn impl_err(s: &'static str) -> DeserializationError {
     DeserializationError::Usage(s.to_string())
 }
 
-struct FieldsSeqVisitor<'a, R: 'a + DeserializableRow>
+struct FieldsSeqVisitor<'a, 'o, R: 'a + DeserializableRow>
 where
     <R as DeserializableRow>::V: DbValue,
 {
-    de: &'a mut RowDeserializer<R>,
+    de: &'a mut RowDeserializer<'o, R>,
 }
-impl<'a, R: DeserializableRow> FieldsSeqVisitor<'a, R>
+impl<'a, 'o, R: DeserializableRow> FieldsSeqVisitor<'a, 'o, R>
 where
     <R as DeserializableRow>::V: DbValue,
 {
-    pub fn new(de: &'a mut RowDeserializer<R>) -> Self {
+    pub fn new(de: &'a mut RowDeserializer<'o, R>) -> Self {
         #[cfg(feature = "trace")]
         trace!("FieldsSeqVisitor::new()");
         FieldsSeqVisitor { de }
     }
 }
 
-impl<'x, 'a, R> serde::de::SeqAccess<'x> for FieldsSeqVisitor<'a, R>
+impl<'x, R> serde::de::SeqAccess<'x> for FieldsSeqVisitor<'_, '_, R>
 where
     R: DeserializableRow,
     <R as DeserializableRow>::V: DbValue,
@@ -499,9 +963,17 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("FieldsSeqVisitor.next_element_seed()");
+        let idx = self.de.column_index();
         match self.de.row.next() {
             None => Ok(None),
-            Some(val) => seed.deserialize(FieldDeserializer::new(val)).map(Some),
+            Some(val) => self
+                .de
+                .deserialize_field(idx, val, |fd| seed.deserialize(fd))
+                .map(Some),
         }
     }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.de.row.len())
+    }
 }
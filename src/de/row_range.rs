@@ -0,0 +1,124 @@
+use crate::de::{DeserializableResultset, DeserializationResult};
+
+/// A view on a resultset that skips a number of leading rows
+/// and exposes at most a number of the following rows.
+///
+/// Is returned by
+/// [`DeserializableResultset::skip_rows()`](trait.DeserializableResultset.html#method.skip_rows)
+/// and [`DeserializableResultset::take_rows()`](trait.DeserializableResultset.html#method.take_rows).
+/// The skipped rows are fetched and dropped without being converted,
+/// the rows after the range are not fetched.
+#[derive(Debug)]
+pub struct RowRange<RS> {
+    rs: RS,
+    skip: usize,
+    take: Option<usize>,
+}
+
+impl<RS: DeserializableResultset> RowRange<RS> {
+    pub(crate) fn new(rs: RS, skip: usize, take: Option<usize>) -> Self {
+        RowRange { rs, skip, take }
+    }
+
+    /// Skips `n` further leading rows.
+    #[must_use]
+    pub fn skip_rows(mut self, n: usize) -> Self {
+        match self.take {
+            Some(take) => {
+                let n = n.min(take);
+                self.skip += n;
+                self.take = Some(take - n);
+            }
+            None => self.skip += n,
+        }
+        self
+    }
+
+    /// Limits the range to at most `n` rows.
+    #[must_use]
+    pub fn take_rows(mut self, n: usize) -> Self {
+        self.take = Some(self.take.map_or(n, |take| take.min(n)));
+        self
+    }
+
+    /// Returns the underlying resultset.
+    pub fn into_inner(self) -> RS {
+        self.rs
+    }
+
+    fn drop_skipped_rows(&mut self) -> DeserializationResult<()> {
+        while self.skip > 0 {
+            self.skip -= 1;
+            if self.rs.next()?.is_none() {
+                self.skip = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<RS: DeserializableResultset> DeserializableResultset for RowRange<RS> {
+    type E = RS::E;
+    type ROW = RS::ROW;
+
+    fn has_multiple_rows(&mut self) -> DeserializationResult<bool> {
+        self.drop_skipped_rows()?;
+        match self.take {
+            Some(0 | 1) => Ok(false),
+            _ => self.rs.has_multiple_rows(),
+        }
+    }
+
+    fn next(&mut self) -> DeserializationResult<Option<Self::ROW>> {
+        self.drop_skipped_rows()?;
+        match self.take {
+            Some(0) => Ok(None),
+            Some(ref mut take) => {
+                *take -= 1;
+                self.rs.next()
+            }
+            None => self.rs.next(),
+        }
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.rs.number_of_fields()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        self.rs.fieldname(field_idx)
+    }
+
+    fn fieldtype(&self, field_idx: usize) -> Option<&str> {
+        self.rs.fieldtype(field_idx)
+    }
+
+    fn remaining_rows(&self) -> Option<usize> {
+        let remaining = self.rs.remaining_rows()?.saturating_sub(self.skip);
+        Some(self.take.map_or(remaining, |take| take.min(remaining)))
+    }
+
+    fn has_no_rows(&mut self) -> DeserializationResult<bool> {
+        self.drop_skipped_rows()?;
+        Ok(self.take == Some(0) || self.rs.has_no_rows()?)
+    }
+
+    fn number_of_columns(&self) -> usize {
+        self.rs.number_of_columns()
+    }
+
+    fn take_row(&mut self, idx: usize) -> DeserializationResult<Option<Self::ROW>> {
+        self.drop_skipped_rows()?;
+        match self.take {
+            Some(take) if take <= idx => Ok(None),
+            Some(ref mut take) => {
+                let row = self.rs.take_row(idx)?;
+                if row.is_some() {
+                    *take -= 1;
+                }
+                Ok(row)
+            }
+            None => self.rs.take_row(idx),
+        }
+    }
+}
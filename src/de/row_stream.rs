@@ -0,0 +1,125 @@
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::{DeserializableRow, DeserializationError, DeserializationOptions};
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::marker::PhantomData;
+use std::task::{Context, Poll};
+
+/// Interface for a database resultset whose rows are fetched asynchronously in chunks.
+///
+/// Such resultsets are converted into a stream of typed rows with [`RowStream`].
+pub trait ChunkedResultset {
+    /// Error type of the database driver.
+    type E: From<DeserializationError>;
+    /// Concrete type for the DB row, which must implement `DeserializabeRow`.
+    type ROW: DeserializableRow;
+
+    /// Attempts to fetch the next chunk of rows, and returns `None` at the end.
+    ///
+    /// If the chunk is not yet available, the resultset returns `Poll::Pending`
+    /// and arranges for the waker of the given context to be woken once it is.
+    ///
+    /// # Errors
+    ///
+    /// E.g. fetching can fail.
+    #[allow(clippy::type_complexity)]
+    fn poll_next_chunk(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<Self::ROW>>, Self::E>>;
+}
+
+/// A stream that converts the rows of a [`ChunkedResultset`] one by one into `T`.
+///
+/// ```rust,ignore
+/// let mut stream = RowStream::<_, Line>::new(resultset);
+/// while let Some(line) = stream.next().await {
+///     let line = line?;
+///     // ...
+/// }
+/// ```
+///
+/// After a fetch error the stream is exhausted.
+///
+/// With feature `futures`, `RowStream` also implements `futures::Stream`,
+/// so that it can be used with the combinators of the `futures` crates.
+#[derive(Debug)]
+pub struct RowStream<RS: ChunkedResultset, T> {
+    rs: RS,
+    chunk: VecDeque<RS::ROW>,
+    options: DeserializationOptions,
+    exhausted: bool,
+    _target: PhantomData<fn() -> T>,
+}
+
+impl<RS, T> RowStream<RS, T>
+where
+    RS: ChunkedResultset,
+    T: serde::de::DeserializeOwned,
+{
+    /// Wraps the given resultset.
+    pub fn new(rs: RS) -> Self {
+        Self::new_with(rs, &DeserializationOptions::default())
+    }
+
+    /// Wraps the given resultset, and converts its rows with the given options.
+    pub fn new_with(rs: RS, options: &DeserializationOptions) -> Self {
+        RowStream {
+            rs,
+            chunk: VecDeque::new(),
+            options: options.clone(),
+            exhausted: false,
+            _target: PhantomData,
+        }
+    }
+
+    /// Attempts to convert the next row, and returns `None` at the end.
+    ///
+    /// The signature corresponds to that of `futures::Stream::poll_next()`.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<T, RS::E>>> {
+        loop {
+            if let Some(row) = self.chunk.pop_front() {
+                return Poll::Ready(Some(
+                    serde::Deserialize::deserialize(&mut RowDeserializer::new(row, &self.options))
+                        .map_err(Into::into),
+                ));
+            }
+            if self.exhausted {
+                return Poll::Ready(None);
+            }
+            match self.rs.poll_next_chunk(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(Some(rows))) => self.chunk.extend(rows),
+                Poll::Ready(Ok(None)) => self.exhausted = true,
+                Poll::Ready(Err(e)) => {
+                    self.exhausted = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+
+    /// Converts the next row, and returns `None` at the end.
+    pub async fn next(&mut self) -> Option<Result<T, RS::E>> {
+        poll_fn(|cx| self.poll_next(cx)).await
+    }
+
+    /// Returns the underlying resultset.
+    pub fn into_inner(self) -> RS {
+        self.rs
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<RS, T> futures_core::Stream for RowStream<RS, T>
+where
+    RS: ChunkedResultset,
+    T: serde::de::DeserializeOwned,
+    Self: Unpin,
+{
+    type Item = Result<T, RS::E>;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        RowStream::poll_next(self.get_mut(), cx)
+    }
+}
@@ -1,7 +1,10 @@
+use crate::de::db_interval::DB_INTERVAL;
+use crate::de::lob::LOB_STREAM;
 use crate::de::row_deserializer::RowDeserializer;
+use crate::de::timezone::TIMEZONE_AWARE;
 use crate::de::{
     DbValue, DeserializableResultset, DeserializableRow, DeserializationError,
-    DeserializationResult,
+    DeserializationOptions, DeserializationResult,
 };
 #[cfg(feature = "trace")]
 use log::trace;
@@ -16,17 +19,25 @@ enum Need {
 
 // Deserialize a ResultSet into a normal rust type.
 #[derive(Debug)]
-pub struct RsDeserializer<RS> {
+pub struct RsDeserializer<'o, RS> {
     rs: RS,
     need: Need,
+    options: &'o DeserializationOptions,
+    rows: usize,
+    bytes: usize,
+    // true if the positional fallback was already reported for a row
+    fallback_reported: bool,
 }
 
-impl<RS> RsDeserializer<RS>
+impl<'o, RS> RsDeserializer<'o, RS>
 where
     RS: DeserializableResultset,
     <<RS as DeserializableResultset>::ROW as DeserializableRow>::V: DbValue,
 {
-    pub fn try_new(mut rs: RS) -> Result<RsDeserializer<RS>, DeserializationError> {
+    pub fn try_new(
+        mut rs: RS,
+        options: &'o DeserializationOptions,
+    ) -> Result<RsDeserializer<'o, RS>, DeserializationError> {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::new()");
         let need = if rs.has_multiple_rows()? {
@@ -34,23 +45,55 @@ where
         } else {
             Need::Can
         };
-        Ok(RsDeserializer { rs, need })
+        Ok(RsDeserializer {
+            rs,
+            need,
+            options,
+            rows: 0,
+            bytes: 0,
+            fallback_reported: false,
+        })
+    }
+
+    // Returns the number of rows that were handed out for deserialization.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    // Returns the number of bytes of the rows that were handed out for deserialization.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    // Informs the observer, if one is set, when the next progress interval is completed.
+    fn report_progress(&self) {
+        if let (Some(observer), Some(interval)) =
+            (self.options.observer(), self.options.progress_interval())
+        {
+            if self.rows.is_multiple_of(interval) {
+                observer.progress(self.rows);
+            }
+        }
     }
 
     fn pop_single_row(&mut self) -> DeserializationResult<<RS as DeserializableResultset>::ROW> {
         if let Need::Must = self.need {
             return Err(DeserializationError::TrailingRows);
-        };
+        }
         match self.rs.next()? {
             None => Err(DeserializationError::Usage(String::from(
                 "no row found in resultset",
             ))),
-            Some(row) => Ok(row),
+            Some(row) => {
+                self.rows += 1;
+                add_row_bytes(self.options, &mut self.bytes, &row)?;
+                Ok(row)
+            }
         }
     }
 }
 
-impl<'x, 'a, RS: DeserializableResultset> serde::Deserializer<'x> for &'a mut RsDeserializer<RS>
+impl<'x, RS: DeserializableResultset> serde::Deserializer<'x> for &mut RsDeserializer<'_, RS>
 where
     <<RS as DeserializableResultset>::ROW as DeserializableRow>::V: DbValue,
 {
@@ -60,9 +103,10 @@ where
     where
         V: serde::de::Visitor<'x>,
     {
-        visitor.visit_string(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_any()");
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
+        rd.deserialize_any(visitor)
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -73,6 +117,7 @@ where
         trace!("RsDeserializer::deserialize_bool()");
         visitor.visit_bool(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -84,6 +129,7 @@ where
         trace!("RsDeserializer::deserialize_u8()");
         visitor.visit_u8(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -95,6 +141,7 @@ where
         trace!("RsDeserializer::deserialize_u16()");
         visitor.visit_u16(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -106,6 +153,7 @@ where
         trace!("RsDeserializer::deserialize_u32()");
         visitor.visit_u32(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -117,6 +165,7 @@ where
         trace!("RsDeserializer::deserialize_u64()");
         visitor.visit_u64(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -128,6 +177,7 @@ where
         trace!("RsDeserializer::deserialize_i8()");
         visitor.visit_i8(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -139,6 +189,7 @@ where
         trace!("RsDeserializer::deserialize_i16()");
         visitor.visit_i16(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -150,6 +201,7 @@ where
         trace!("RsDeserializer::deserialize_i32()");
         visitor.visit_i32(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -161,6 +213,7 @@ where
         trace!("RsDeserializer::deserialize_i64()");
         visitor.visit_i64(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -172,6 +225,7 @@ where
         trace!("RsDeserializer::deserialize_f32()");
         visitor.visit_f32(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -183,16 +237,20 @@ where
         trace!("RsDeserializer::deserialize_f64()");
         visitor.visit_f64(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
-    fn deserialize_char<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_char<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RsDeserializer::deserialize_char()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_char()");
+        visitor.visit_char(SD::deserialize(&mut RowDeserializer::new(
+            self.pop_single_row()?,
+            self.options,
+        ))?)
     }
 
     fn deserialize_str<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -212,6 +270,7 @@ where
         trace!("RsDeserializer::deserialize_string()");
         visitor.visit_string(SD::deserialize(&mut RowDeserializer::new(
             self.pop_single_row()?,
+            self.options,
         ))?)
     }
 
@@ -230,11 +289,11 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_option()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
         rd.deserialize_option(visitor)
     }
 
-    fn deserialize_seq<V>(mut self, visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_seq<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
@@ -250,13 +309,14 @@ where
         }
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_map<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RsDeserializer::deserialize_map()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_map()");
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
+        rd.deserialize_map(visitor)
     }
 
     fn deserialize_unit_struct<V>(
@@ -272,20 +332,20 @@ where
         ))
     }
 
-    #[allow(clippy::used_underscore_binding)]
     fn deserialize_newtype_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!(
-            "RsDeserializer::deserialize_newtype_struct() with name = {}",
-            _name
-        );
+        trace!("RsDeserializer::deserialize_newtype_struct() with name = {name}");
+        if name == TIMEZONE_AWARE || name == LOB_STREAM || name == DB_INTERVAL {
+            let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
+            return rd.deserialize_newtype_struct(name, visitor);
+        }
         visitor.visit_newtype_struct(self)
     }
 
@@ -299,11 +359,8 @@ where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!(
-            "RsDeserializer::deserialize_tuple_struct() with name = {}",
-            name
-        );
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
+        trace!("RsDeserializer::deserialize_tuple_struct() with name = {name}");
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
         rd.deserialize_tuple_struct(name, len, visitor)
     }
 
@@ -317,8 +374,9 @@ where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!("RsDeserializer::deserialize_struct() with name = {}", name);
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
+        trace!("RsDeserializer::deserialize_struct() with name = {name}");
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options)
+            .with_row_index(self.rows - 1);
         rd.deserialize_struct(name, fields, visitor)
     }
 
@@ -328,7 +386,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_bytes()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
         rd.deserialize_bytes(visitor)
     }
 
@@ -338,7 +396,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_byte_buf()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
         rd.deserialize_byte_buf(visitor)
     }
 
@@ -348,22 +406,39 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_tuple()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
+        if let Need::Must = self.need {
+            // the rows are the elements, e.g. of an array [T; N]
+            if let Some(rows) = self.rs.remaining_rows().filter(|rows| *rows != len) {
+                return Err(DeserializationError::Usage(format!(
+                    "the resultset has {rows} rows, but the target has {len} elements"
+                )));
+            }
+            self.need = Need::Done;
+            let value = visitor.visit_seq(RowsVisitor::new(self))?;
+            if self.rs.next()?.is_some() {
+                return Err(DeserializationError::Usage(format!(
+                    "the resultset has more than {len} rows, but the target has {len} elements"
+                )));
+            }
+            return Ok(value);
+        }
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
         rd.deserialize_tuple(len, visitor)
     }
 
     fn deserialize_enum<V>(
         self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
-        _visitor: V,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RsDeserializer::deserialize_enum()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_enum() with name = {name}");
+        let mut rd = RowDeserializer::new(self.pop_single_row()?, self.options);
+        rd.deserialize_enum(name, variants, visitor)
     }
 
     fn deserialize_identifier<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
@@ -375,30 +450,35 @@ where
         ))
     }
 
-    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RsDeserializer::deserialize_ignored_any()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_ignored_any()");
+        // the rows are dropped with the resultset, without being fetched or converted
+        visitor.visit_unit()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.options.is_human_readable()
     }
 }
 
 // we use generalization <R> here because this allows us to bind the parameter to the lifetime 'a
-struct RowsVisitor<'a, R: 'a> {
-    de: &'a mut RsDeserializer<R>,
+struct RowsVisitor<'a, 'o, R: 'a> {
+    de: &'a mut RsDeserializer<'o, R>,
 }
 
-impl<'a, R> RowsVisitor<'a, R> {
-    pub fn new(de: &'a mut RsDeserializer<R>) -> Self {
+impl<'a, 'o, R> RowsVisitor<'a, 'o, R> {
+    pub fn new(de: &'a mut RsDeserializer<'o, R>) -> Self {
         #[cfg(feature = "trace")]
         trace!("RowsVisitor::new()");
         RowsVisitor { de }
     }
 }
 
-impl<'x, 'a, R: DeserializableResultset> serde::de::SeqAccess<'x> for RowsVisitor<'a, R> {
+impl<'x, R: DeserializableResultset> serde::de::SeqAccess<'x> for RowsVisitor<'_, '_, R> {
     type Error = DeserializationError;
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
@@ -406,10 +486,71 @@ impl<'x, 'a, R: DeserializableResultset> serde::de::SeqAccess<'x> for RowsVisito
         T: serde::de::DeserializeSeed<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!("RowsVisitor.next_element_seed()");
+        trace!("RowsVisitor.next_element_seed() for row {}", self.de.rows);
+        if self.de.options.is_cancelled() {
+            return Err(DeserializationError::Cancelled);
+        }
         match self.de.rs.next()? {
             None => Ok(None),
-            Some(row) => seed.deserialize(&mut RowDeserializer::new(row)).map(Some),
+            Some(row) => {
+                check_row_limit(self.de.options, self.de.rows)?;
+                add_row_bytes(self.de.options, &mut self.de.bytes, &row)?;
+                let mut row_deserializer = RowDeserializer::new(row, self.de.options)
+                    .with_row_index(self.de.rows)
+                    .with_fallback_reported(self.de.fallback_reported);
+                #[cfg(feature = "tracing")]
+                let span = tracing::trace_span!("deserialize_row", row = self.de.rows).entered();
+                self.de.rows += 1;
+                let result = seed.deserialize(&mut row_deserializer);
+                #[cfg(feature = "tracing")]
+                drop(span);
+                self.de.fallback_reported = row_deserializer.fallback_reported();
+                #[cfg(feature = "trace")]
+                if let Err(ref e) = result {
+                    log::debug!("deserialization of row {} failed: {e:?}", self.de.rows - 1);
+                }
+                let value = result?;
+                self.de.report_progress();
+                Ok(Some(value))
+            }
         }
     }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.de.rs.remaining_rows()
+    }
+}
+
+// Fails if the row with index `idx` exceeds the configured limit.
+pub(crate) fn check_row_limit(
+    options: &DeserializationOptions,
+    idx: usize,
+) -> DeserializationResult<()> {
+    match options.max_rows() {
+        Some(max_rows) if idx >= max_rows => Err(DeserializationError::RowLimitExceeded(max_rows)),
+        _ => Ok(()),
+    }
+}
+
+// Adds the bytes of `row` to `bytes`, and fails if they exceed the configured budget,
+// or if a budget is configured but the row does not report its size.
+pub(crate) fn add_row_bytes<ROW: DeserializableRow>(
+    options: &DeserializationOptions,
+    bytes: &mut usize,
+    row: &ROW,
+) -> DeserializationResult<()> {
+    let Some(max_bytes) = options.max_bytes() else {
+        *bytes += row.byte_len().unwrap_or_default();
+        return Ok(());
+    };
+    *bytes += row.byte_len().ok_or_else(|| {
+        DeserializationError::Usage(String::from(
+            "the byte budget requires rows that implement DeserializableRow::byte_len()",
+        ))
+    })?;
+    if *bytes > max_bytes {
+        Err(DeserializationError::ByteLimitExceeded(max_bytes))
+    } else {
+        Ok(())
+    }
 }
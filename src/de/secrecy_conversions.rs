@@ -0,0 +1,65 @@
+use crate::de::{ConversionError, DbValue, DbValueInto};
+use secrecy::{SecretString, SecretVec};
+
+/// Converts any `DbValue` into a `SecretString`, which takes over the text of the value.
+impl<DBV: DbValue> DbValueInto<SecretString> for DBV {
+    fn try_into(self) -> Result<SecretString, ConversionError> {
+        DbValueInto::<String>::try_into(self).map(SecretString::new)
+    }
+}
+
+/// Converts any `DbValue` into a `SecretVec<u8>`, which takes over the bytes of the value.
+impl<DBV: DbValue> DbValueInto<SecretVec<u8>> for DBV {
+    fn try_into(self) -> Result<SecretVec<u8>, ConversionError> {
+        DbValueInto::<Vec<u8>>::try_into(self).map(SecretVec::new)
+    }
+}
+
+/// Deserializes a binary column into a `SecretVec<u8>`.
+///
+/// Like `Vec<u8>`, `SecretVec<u8>` would by default be deserialized as a sequence of numbers;
+/// use this function to have it take over the bytes of the value instead:
+///
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct Key {
+///     #[serde(deserialize_with = "serde_db::de::deserialize_secret_bytes")]
+///     key: SecretVec<u8>,
+/// }
+/// ```
+///
+/// # Errors
+///
+/// An error is produced if the value cannot be converted into bytes.
+pub fn deserialize_secret_bytes<'de, D>(deserializer: D) -> Result<SecretVec<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_byte_buf(SecretBytesVisitor)
+}
+
+struct SecretBytesVisitor;
+
+impl serde::de::Visitor<'_> for SecretBytesVisitor {
+    type Value = SecretVec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("bytes")
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(SecretVec::new(v))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(SecretVec::new(v.to_vec()))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(SecretVec::new(v.into_bytes()))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(SecretVec::new(v.as_bytes().to_vec()))
+    }
+}
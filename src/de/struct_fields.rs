@@ -0,0 +1,88 @@
+use crate::de::DeserializationError;
+use serde::forward_to_deserialize_any;
+use std::cell::Cell;
+
+/// Returns the field names that the `serde::Deserialize` implementation of `T`
+/// expects, or `None` if `T` is not deserialized as a plain struct
+/// (e.g. because it is a tuple, or uses `#[serde(flatten)]`).
+///
+/// The names are those after applying attributes like `#[serde(rename)]`,
+/// i.e. the column names that `T` maps to;
+/// recent versions of `serde_derive` also list the names given with `#[serde(alias)]`.
+///
+/// ```rust
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "UPPERCASE")]
+/// struct Data {
+///     id: u32,
+///     name: String,
+/// }
+/// assert_eq!(serde_db::de::struct_fields::<Data>(), Some(&["ID", "NAME"][..]));
+/// ```
+#[must_use]
+pub fn struct_fields<'de, T>() -> Option<&'static [&'static str]>
+where
+    T: serde::Deserialize<'de>,
+{
+    let fields = Cell::new(None);
+    let _ = T::deserialize(FieldsProbe(&fields));
+    fields.get()
+}
+
+/// Panics if the field names of `T` (see [`struct_fields`]) differ from `columns`.
+///
+/// Is used by [`assert_maps!`](../macro.assert_maps.html).
+///
+/// # Panics
+///
+/// If the field names of `T` differ from `columns`.
+pub fn assert_struct_fields<'de, T>(type_name: &str, columns: &[&str])
+where
+    T: serde::Deserialize<'de>,
+{
+    let Some(fields) = struct_fields::<T>() else {
+        panic!("{type_name} is not deserialized as a plain struct");
+    };
+    if fields != columns {
+        let missing: Vec<_> = columns.iter().filter(|c| !fields.contains(c)).collect();
+        let unexpected: Vec<_> = fields.iter().filter(|f| !columns.contains(f)).collect();
+        panic!(
+            "field names of {type_name} do not match the columns:\n  \
+             fields:     {fields:?}\n  columns:    {columns:?}\n  \
+             missing:    {missing:?}\n  unexpected: {unexpected:?}"
+        );
+    }
+}
+
+// A deserializer that records the field names of a struct and then gives up.
+struct FieldsProbe<'a>(&'a Cell<Option<&'static [&'static str]>>);
+
+impl<'de> serde::Deserializer<'de> for FieldsProbe<'_> {
+    type Error = DeserializationError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        Err(DeserializationError::Usage("not a struct".to_string()))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.set(Some(fields));
+        Err(DeserializationError::Usage("fields captured".to_string()))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
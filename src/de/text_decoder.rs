@@ -0,0 +1,38 @@
+use crate::de::ConversionError;
+
+/// Decodes the bytes of raw text values into strings.
+///
+/// Register a decoder with
+/// [`DeserializationOptions::with_text_decoder()`](struct.DeserializationOptions.html#method.with_text_decoder)
+/// for databases or columns whose text is not encoded in UTF-8, like latin1 or UTF-16.
+/// The decoder is used for values for which
+/// [`DbValue::is_raw_text()`](trait.DbValue.html#method.is_raw_text) returns true,
+/// instead of the [`Utf8Policy`](enum.Utf8Policy.html).
+///
+/// The trait is implemented for all suitable closures:
+///
+/// ```rust
+/// use serde_db::de::{DeserializationOptions, TextDecoder};
+/// use std::sync::Arc;
+///
+/// let latin1 = |bytes: &[u8]| Ok(bytes.iter().copied().map(char::from).collect::<String>());
+/// assert_eq!(latin1.decode(b"Gr\xfc\xdfe").unwrap(), "Grüße");
+/// let options = DeserializationOptions::default().with_text_decoder(Arc::new(latin1));
+/// ```
+pub trait TextDecoder: Send + Sync {
+    /// Decodes the given bytes.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the bytes are not valid in the decoder's encoding.
+    fn decode(&self, bytes: &[u8]) -> Result<String, ConversionError>;
+}
+
+impl<F> TextDecoder for F
+where
+    F: Fn(&[u8]) -> Result<String, ConversionError> + Send + Sync,
+{
+    fn decode(&self, bytes: &[u8]) -> Result<String, ConversionError> {
+        self(bytes)
+    }
+}
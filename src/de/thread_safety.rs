@@ -0,0 +1,23 @@
+// Compile-time checks that deserialization can be moved across threads, given Send values,
+// and that the errors can be sent through channels.
+use crate::de::field_deserializer::FieldDeserializer;
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::rs_deserializer::RsDeserializer;
+use crate::de::{ConversionError, DeserializationError, DeserializationOptions};
+
+fn assert_send<T: Send>() {}
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+#[allow(dead_code)]
+fn assert_errors_and_options() {
+    assert_send_sync::<ConversionError>();
+    assert_send_sync::<DeserializationError>();
+    assert_send_sync::<DeserializationOptions>();
+}
+
+#[allow(dead_code)]
+fn assert_deserializers<DBV: Send, ROW: Send, RS: Send>() {
+    assert_send::<FieldDeserializer<'static, DBV>>();
+    assert_send::<RowDeserializer<'static, ROW>>();
+    assert_send::<RsDeserializer<'static, RS>>();
+}
@@ -0,0 +1,114 @@
+use crate::de::field_deserializer::FieldDeserializer;
+use crate::de::{
+    DbValue, DbValueInto, DeserializationError, DeserializationOptions, DeserializationResult,
+    TimezonePolicy,
+};
+use serde::de::{Deserialize, Deserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use std::marker::PhantomData;
+
+// The name of the newtype struct with which `timezone_aware()` marks its target.
+pub(crate) const TIMEZONE_AWARE: &str = "$serde_db::TimezoneAware";
+
+/// Deserializes a timezone-aware temporal type, like `chrono::DateTime<Utc>`,
+/// applying the [`TimezonePolicy`](enum.TimezonePolicy.html) of the
+/// [`DeserializationOptions`](struct.DeserializationOptions.html)
+/// to naive timestamps (see
+/// [`DbValue::is_naive_timestamp()`](trait.DbValue.html#method.is_naive_timestamp)).
+///
+/// Use it with serde's `deserialize_with` attribute; it also works for `Option`s:
+///
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct Event {
+///     #[serde(deserialize_with = "serde_db::de::timezone_aware")]
+///     created_at: chrono::DateTime<chrono::Utc>,
+///     #[serde(deserialize_with = "serde_db::de::timezone_aware")]
+///     deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+/// }
+/// let options = DeserializationOptions::default().with_timezone_policy(TimezonePolicy::AssumeUtc);
+/// let events: Vec<Event> = resultset.try_into_with(&options)?;
+/// ```
+///
+/// # Errors
+///
+/// If the value cannot be deserialized into `T`, or if the policy rejects naive timestamps.
+pub fn timezone_aware<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_newtype_struct(TIMEZONE_AWARE, TimezoneAwareVisitor(PhantomData))
+}
+
+struct TimezoneAwareVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for TimezoneAwareVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a timezone-aware temporal value")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+
+// Deserializes a value for a timezone-aware target.
+pub(crate) struct TimezoneAwareDeserializer<'o, DBV>(DBV, &'o DeserializationOptions);
+
+impl<'o, DBV: DbValue> TimezoneAwareDeserializer<'o, DBV> {
+    pub fn new(value: DBV, options: &'o DeserializationOptions) -> Self {
+        TimezoneAwareDeserializer(value, options)
+    }
+}
+
+impl<'x, DBV: DbValue> Deserializer<'x> for TimezoneAwareDeserializer<'_, DBV> {
+    type Error = DeserializationError;
+
+    fn deserialize_any<V: Visitor<'x>>(self, visitor: V) -> DeserializationResult<V::Value> {
+        if !self.0.is_naive_timestamp() {
+            return FieldDeserializer::new(self.0, self.1).deserialize_any(visitor);
+        }
+        let naive: String = DbValueInto::try_into(self.0)?;
+        match self.1.timezone_policy() {
+            TimezonePolicy::Reject => Err(DeserializationError::Usage(format!(
+                "the naive timestamp {naive} cannot be converted into a timezone-aware type"
+            ))),
+            TimezonePolicy::AssumeUtc => visitor.visit_string(format!("{naive}Z")),
+            TimezonePolicy::AssumeOffset(seconds) => {
+                let sign = if seconds < 0 { '-' } else { '+' };
+                let minutes = seconds.unsigned_abs() / 60;
+                visitor.visit_string(format!(
+                    "{naive}{sign}{:02}:{:02}",
+                    minutes / 60,
+                    minutes % 60
+                ))
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'x>>(self, visitor: V) -> DeserializationResult<V::Value> {
+        if self.0.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'x>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> DeserializationResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'x>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
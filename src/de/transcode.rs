@@ -0,0 +1,303 @@
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::rs_deserializer::RsDeserializer;
+use crate::de::{DeserializableResultset, DeserializableRow, DeserializationOptions};
+use serde::de::{DeserializeSeed, Deserializer, Error as _, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Error as _, Serialize, SerializeMap, SerializeSeq, Serializer};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+/// Writes the rows of a resultset with the given `serde::Serializer`, like the serializers of
+/// `serde_json` or `rmp-serde`, without intermediate rust types.
+///
+/// The resultset is serialized as a sequence of rows, each row as a map from the column names
+/// to the values. The rows are converted one by one, while they are serialized.
+///
+/// ```rust,ignore
+/// let mut out = std::io::stdout();
+/// serde_db::de::transcode(resultset, &mut serde_json::Serializer::new(&mut out))?;
+/// ```
+///
+/// # Errors
+///
+/// An error is produced if fetching or converting the rows fails,
+/// or if the serializer fails; both are reported as errors of the serializer.
+pub fn transcode<RS, S>(rs: RS, serializer: S) -> Result<S::Ok, S::Error>
+where
+    RS: DeserializableResultset,
+    S: Serializer,
+{
+    transcode_with(rs, serializer, &DeserializationOptions::default())
+}
+
+/// Writes the rows of a resultset with the given `serde::Serializer`, using the given options.
+///
+/// # Errors
+///
+/// An error is produced if fetching or converting the rows fails,
+/// or if the serializer fails; both are reported as errors of the serializer.
+pub fn transcode_with<RS, S>(
+    rs: RS,
+    serializer: S,
+    options: &DeserializationOptions,
+) -> Result<S::Ok, S::Error>
+where
+    RS: DeserializableResultset,
+    S: Serializer,
+{
+    let mut rs_deserializer = RsDeserializer::try_new(rs, options).map_err(S::Error::custom)?;
+    (&mut rs_deserializer)
+        .deserialize_seq(TranscodeVisitor(serializer))
+        .map_err(S::Error::custom)
+}
+
+/// Makes a resultset serializable with any `serde::Serializer`,
+/// e.g. for handing it to `serde_json::to_string()`.
+///
+/// The resultset is serialized like with [`transcode()`](fn.transcode.html);
+/// since the rows are consumed, it can be serialized only once.
+///
+/// ```rust,ignore
+/// let json = serde_json::to_string(&SerializableResultset::new(resultset))?;
+/// ```
+pub struct SerializableResultset<'a, RS> {
+    rs: Cell<Option<RS>>,
+    options: Cow<'a, DeserializationOptions>,
+}
+
+impl<RS: DeserializableResultset> SerializableResultset<'static, RS> {
+    /// Wraps the resultset, for serialization with the default options.
+    #[must_use]
+    pub fn new(rs: RS) -> Self {
+        SerializableResultset {
+            rs: Cell::new(Some(rs)),
+            options: Cow::Owned(DeserializationOptions::default()),
+        }
+    }
+}
+
+impl<'a, RS: DeserializableResultset> SerializableResultset<'a, RS> {
+    /// Wraps the resultset, for serialization with the given options.
+    #[must_use]
+    pub fn with_options(rs: RS, options: &'a DeserializationOptions) -> Self {
+        SerializableResultset {
+            rs: Cell::new(Some(rs)),
+            options: Cow::Borrowed(options),
+        }
+    }
+}
+
+impl<RS> std::fmt::Debug for SerializableResultset<'_, RS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SerializableResultset")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<RS: DeserializableResultset> Serialize for SerializableResultset<'_, RS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rs = self.rs.take().ok_or_else(|| {
+            S::Error::custom("a SerializableResultset can be serialized only once")
+        })?;
+        transcode_with(rs, serializer, &self.options)
+    }
+}
+
+/// Makes a row serializable with any `serde::Serializer`,
+/// e.g. for handing it to `serde_json::to_string()`.
+///
+/// The row is serialized as a map from the column names to the values;
+/// since the values are consumed, it can be serialized only once.
+pub struct SerializableRow<'a, R> {
+    row: Cell<Option<R>>,
+    options: Cow<'a, DeserializationOptions>,
+}
+
+impl<R: DeserializableRow> SerializableRow<'static, R> {
+    /// Wraps the row, for serialization with the default options.
+    #[must_use]
+    pub fn new(row: R) -> Self {
+        SerializableRow {
+            row: Cell::new(Some(row)),
+            options: Cow::Owned(DeserializationOptions::default()),
+        }
+    }
+}
+
+impl<'a, R: DeserializableRow> SerializableRow<'a, R> {
+    /// Wraps the row, for serialization with the given options.
+    #[must_use]
+    pub fn with_options(row: R, options: &'a DeserializationOptions) -> Self {
+        SerializableRow {
+            row: Cell::new(Some(row)),
+            options: Cow::Borrowed(options),
+        }
+    }
+}
+
+impl<R> std::fmt::Debug for SerializableRow<'_, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SerializableRow")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: DeserializableRow> Serialize for SerializableRow<'_, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let row = self
+            .row
+            .take()
+            .ok_or_else(|| S::Error::custom("a SerializableRow can be serialized only once"))?;
+        Transcoder::new(&mut RowDeserializer::new(row, &self.options)).serialize(serializer)
+    }
+}
+
+// Serializes what the wrapped deserializer produces; can be serialized only once.
+struct Transcoder<D>(Cell<Option<D>>);
+
+impl<D> Transcoder<D> {
+    fn new(deserializer: D) -> Self {
+        Transcoder(Cell::new(Some(deserializer)))
+    }
+}
+
+impl<'de, D: Deserializer<'de>> Serialize for Transcoder<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0
+            .take()
+            .ok_or_else(|| S::Error::custom("a value can be transcoded only once"))?
+            .deserialize_any(TranscodeVisitor(serializer))
+            .map_err(S::Error::custom)
+    }
+}
+
+// Hands everything it visits to the serializer.
+struct TranscodeVisitor<S>(S);
+
+impl<'de, S: Serializer> Visitor<'de> for TranscodeVisitor<S> {
+    type Value = S::Ok;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<S::Ok, E> {
+        self.0.serialize_bool(v).map_err(E::custom)
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<S::Ok, E> {
+        self.0.serialize_i64(v).map_err(E::custom)
+    }
+
+    fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<S::Ok, E> {
+        self.0.serialize_i128(v).map_err(E::custom)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<S::Ok, E> {
+        self.0.serialize_u64(v).map_err(E::custom)
+    }
+
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<S::Ok, E> {
+        self.0.serialize_u128(v).map_err(E::custom)
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<S::Ok, E> {
+        self.0.serialize_f64(v).map_err(E::custom)
+    }
+
+    fn visit_char<E: serde::de::Error>(self, v: char) -> Result<S::Ok, E> {
+        self.0.serialize_char(v).map_err(E::custom)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<S::Ok, E> {
+        self.0.serialize_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<S::Ok, E> {
+        self.0.serialize_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<S::Ok, E> {
+        self.0.serialize_none().map_err(E::custom)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Ok, D::Error> {
+        self.0
+            .serialize_some(&Transcoder::new(deserializer))
+            .map_err(D::Error::custom)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<S::Ok, E> {
+        self.0.serialize_unit().map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<S::Ok, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<S::Ok, A::Error> {
+        let mut serialize_seq = self
+            .0
+            .serialize_seq(seq.size_hint())
+            .map_err(A::Error::custom)?;
+        while seq
+            .next_element_seed(ElementSeed(&mut serialize_seq))?
+            .is_some()
+        {}
+        serialize_seq.end().map_err(A::Error::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<S::Ok, A::Error> {
+        let mut serialize_map = self
+            .0
+            .serialize_map(map.size_hint())
+            .map_err(A::Error::custom)?;
+        while map.next_key_seed(KeySeed(&mut serialize_map))?.is_some() {
+            map.next_value_seed(ValueSeed(&mut serialize_map))?;
+        }
+        serialize_map.end().map_err(A::Error::custom)
+    }
+}
+
+// Serializes an element of a sequence.
+struct ElementSeed<'a, S>(&'a mut S);
+
+impl<'de, S: SerializeSeq> DeserializeSeed<'de> for ElementSeed<'_, S> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.0
+            .serialize_element(&Transcoder::new(deserializer))
+            .map_err(D::Error::custom)
+    }
+}
+
+// Serializes the key of a map entry.
+struct KeySeed<'a, S>(&'a mut S);
+
+impl<'de, S: SerializeMap> DeserializeSeed<'de> for KeySeed<'_, S> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.0
+            .serialize_key(&Transcoder::new(deserializer))
+            .map_err(D::Error::custom)
+    }
+}
+
+// Serializes the value of a map entry.
+struct ValueSeed<'a, S>(&'a mut S);
+
+impl<'de, S: SerializeMap> DeserializeSeed<'de> for ValueSeed<'_, S> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.0
+            .serialize_value(&Transcoder::new(deserializer))
+            .map_err(D::Error::custom)
+    }
+}
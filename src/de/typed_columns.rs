@@ -0,0 +1,106 @@
+use crate::de::columns_deserializer::ColumnsDeserializer;
+use crate::de::{
+    ConversionError, DbValue, DbValueInto, DeserializableResultset, DeserializableRow,
+    DeserializationError, DeserializationOptions, DeserializationResult, NumericPolicy,
+    NumericTarget, ValueKind,
+};
+
+// The values of a column, converted into the rust type that fits to the kind of its values.
+#[derive(Debug)]
+pub enum TypedColumn {
+    Null(usize),
+    Bool(Vec<Option<bool>>),
+    Int(Vec<Option<i64>>),
+    Float(Vec<Option<f64>>),
+    Text(Vec<Option<String>>),
+    Bytes(Vec<Option<Vec<u8>>>),
+}
+
+impl TypedColumn {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Null(len) => *len,
+            Self::Bool(values) => values.len(),
+            Self::Int(values) => values.len(),
+            Self::Float(values) => values.len(),
+            Self::Text(values) => values.len(),
+            Self::Bytes(values) => values.len(),
+        }
+    }
+}
+
+// Converts a resultset column by column into typed columns, with their names.
+//
+// The type of a column is derived from the `ValueKind` of its first non-NULL value;
+// decimals, dates, times, timestamps, and values of unknown kind are converted into text.
+// Numbers are converted from `DbValue::number()`, if available, with the `NumericPolicy`
+// of the options, or with `NumericPolicy::Checked`.
+pub fn typed_columns<RS>(
+    rs: RS,
+    options: &DeserializationOptions,
+) -> DeserializationResult<Vec<(String, TypedColumn)>>
+where
+    RS: DeserializableResultset,
+{
+    ColumnsDeserializer::<<RS::ROW as DeserializableRow>::V>::try_new(rs, options)?
+        .into_columns()
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (name, values))| {
+            let name = name.unwrap_or_else(|| idx.to_string());
+            let column =
+                typed_column(values, options).map_err(|e| DeserializationError::AtField {
+                    path: name.clone(),
+                    source: Box::new(e.into()),
+                })?;
+            Ok((name, column))
+        })
+        .collect()
+}
+
+fn typed_column<V: DbValue>(
+    values: Vec<V>,
+    options: &DeserializationOptions,
+) -> Result<TypedColumn, ConversionError> {
+    let policy = options.numeric_policy().unwrap_or(NumericPolicy::Checked);
+    let kind = values
+        .iter()
+        .find(|value| !value.is_null())
+        .map(DbValue::value_kind);
+    Ok(match kind {
+        None => TypedColumn::Null(values.len()),
+        Some(ValueKind::Bool) => TypedColumn::Bool(convert(values, DbValueInto::try_into)?),
+        Some(ValueKind::Int) => TypedColumn::Int(convert(values, |v| number(v, policy))?),
+        Some(ValueKind::Float) => TypedColumn::Float(convert(values, |v| number(v, policy))?),
+        Some(ValueKind::Bytes) => TypedColumn::Bytes(convert(values, DbValueInto::try_into)?),
+        Some(_) => TypedColumn::Text(convert(values, DbValueInto::try_into)?),
+    })
+}
+
+fn convert<V, T, F>(values: Vec<V>, f: F) -> Result<Vec<Option<T>>, ConversionError>
+where
+    V: DbValue,
+    F: Fn(V) -> Result<T, ConversionError>,
+{
+    values
+        .into_iter()
+        .map(|value| {
+            if value.is_null() {
+                Ok(None)
+            } else {
+                f(value).map(Some)
+            }
+        })
+        .collect()
+}
+
+fn number<V, T>(value: V, policy: NumericPolicy) -> Result<T, ConversionError>
+where
+    V: DbValue + DbValueInto<T>,
+    T: NumericTarget,
+{
+    match value.number() {
+        Some(number) => number.convert(policy),
+        None => DbValueInto::try_into(value),
+    }
+}
@@ -0,0 +1,92 @@
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::{DeserializableResultset, DeserializationOptions};
+use std::marker::PhantomData;
+
+/// An iterator that converts the rows of a resultset one by one into `T`.
+///
+/// Is returned by
+/// [`DeserializableResultset::typed_rows()`](trait.DeserializableResultset.html#method.typed_rows).
+/// Fetching continues lazily while iterating; after a fetch error the iterator is exhausted.
+///
+/// With feature `fallible-iterator`, `TypedRows` also implements
+/// `fallible_iterator::FallibleIterator`, with the conversion and fetch errors as `Error`.
+#[derive(Debug)]
+pub struct TypedRows<RS, T> {
+    rs: RS,
+    options: DeserializationOptions,
+    exhausted: bool,
+    _target: PhantomData<fn() -> T>,
+}
+
+impl<RS, T> TypedRows<RS, T> {
+    pub(crate) fn new(rs: RS, options: DeserializationOptions) -> Self {
+        TypedRows {
+            rs,
+            options,
+            exhausted: false,
+            _target: PhantomData,
+        }
+    }
+
+    /// Returns the underlying resultset.
+    pub fn into_inner(self) -> RS {
+        self.rs
+    }
+}
+
+impl<RS, T> Iterator for TypedRows<RS, T>
+where
+    RS: DeserializableResultset,
+    T: serde::de::DeserializeOwned,
+{
+    type Item = Result<T, RS::E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        match self.rs.next() {
+            Ok(Some(row)) => Some(
+                serde::Deserialize::deserialize(&mut RowDeserializer::new(row, &self.options))
+                    .map_err(Into::into),
+            ),
+            Ok(None) => {
+                self.exhausted = true;
+                None
+            }
+            Err(e) => {
+                self.exhausted = true;
+                Some(Err(e.into()))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted {
+            (0, Some(0))
+        } else {
+            match self.rs.remaining_rows() {
+                Some(n) => (n, Some(n)),
+                None => (0, None),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "fallible-iterator")]
+impl<RS, T> fallible_iterator::FallibleIterator for TypedRows<RS, T>
+where
+    RS: DeserializableResultset,
+    T: serde::de::DeserializeOwned,
+{
+    type Item = T;
+    type Error = RS::E;
+
+    fn next(&mut self) -> Result<Option<T>, RS::E> {
+        Iterator::next(self).transpose()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
@@ -0,0 +1,60 @@
+use serde::de::{DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use std::marker::PhantomData;
+
+// A seed that deserializes a sequence of rows into a Vec<T>, and validates each value
+// with the given closure; the failures are returned together with the row indices.
+pub(crate) struct ValidatingSeed<T, F> {
+    validate: F,
+    _target: PhantomData<fn() -> T>,
+}
+
+impl<T, F> ValidatingSeed<T, F> {
+    pub(crate) fn new(validate: F) -> Self {
+        ValidatingSeed {
+            validate,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, F> DeserializeSeed<'de> for ValidatingSeed<T, F>
+where
+    T: serde::Deserialize<'de>,
+    F: FnMut(&T) -> Result<(), String>,
+{
+    type Value = (Vec<T>, Vec<(usize, String)>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T, F> Visitor<'de> for ValidatingSeed<T, F>
+where
+    T: serde::Deserialize<'de>,
+    F: FnMut(&T) -> Result<(), String>,
+{
+    type Value = (Vec<T>, Vec<(usize, String)>);
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence of rows")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        let mut failures = Vec::new();
+        while let Some(value) = seq.next_element::<T>()? {
+            if let Err(reason) = (self.validate)(&value) {
+                failures.push((values.len(), reason));
+            }
+            values.push(value);
+        }
+        Ok((values, failures))
+    }
+}
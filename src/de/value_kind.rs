@@ -0,0 +1,39 @@
+/// A coarse classification of a database value, independent of its database type.
+///
+/// Drivers expose it with [`DbValue::value_kind()`](trait.DbValue.html#method.value_kind),
+/// so that self-describing targets, like `serde_json::Value` or untagged enums, which are
+/// deserialized with `deserialize_any()`, get the value in a fitting rust type,
+/// rather than as text.
+///
+/// ```rust
+/// use serde_db::de::ValueKind;
+/// assert_eq!(ValueKind::default(), ValueKind::Unknown);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueKind {
+    /// NULL.
+    Null,
+    /// A boolean value.
+    Bool,
+    /// An integer value.
+    Int,
+    /// A floating point value.
+    Float,
+    /// A decimal value, see
+    /// [`DbValue::decimal_parts()`](trait.DbValue.html#method.decimal_parts).
+    Decimal,
+    /// A character string.
+    Text,
+    /// A binary string.
+    Bytes,
+    /// A date, provided with its textual representation.
+    Date,
+    /// A time of day, provided with its textual representation.
+    Time,
+    /// A timestamp, provided with its textual representation.
+    Timestamp,
+    /// The kind of the value is not known; it is provided as text.
+    #[default]
+    Unknown,
+}
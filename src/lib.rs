@@ -14,4 +14,36 @@
 #![deny(clippy::pedantic)]
 
 pub mod de;
+pub mod message;
 pub mod ser;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+/// Asserts that the serde field names of a struct match the given list of column names.
+///
+/// This catches drift between a struct and a stable database schema in a unit test.
+/// The field names are those after applying attributes like `#[serde(rename)]`;
+/// their order must also match.
+///
+/// ```rust
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "UPPERCASE")]
+/// struct Data {
+///     id: u32,
+///     name: String,
+///     #[serde(rename = "CREATED_AT")]
+///     created: String,
+/// }
+/// serde_db::assert_maps!(Data, ["ID", "NAME", "CREATED_AT"]);
+/// ```
+///
+/// # Panics
+///
+/// If the field names differ from the column names, with a message that lists
+/// the missing and the unexpected names.
+#[macro_export]
+macro_rules! assert_maps {
+    ($t:ty, [$($column:expr),* $(,)?]) => {
+        $crate::de::assert_struct_fields::<$t>(stringify!($t), &[$($column),*])
+    };
+}
@@ -0,0 +1,290 @@
+//! Pluggable rendering of error messages.
+//!
+//! The error types of this crate expose their data as an [`ErrorMessage`],
+//! which a [`MessageFormatter`] turns into text.
+//! Applications that need localized or brand-specific messages implement
+//! `MessageFormatter` and call `render()` on the errors, e.g.
+//! [`DeserializationError::render()`](../de/enum.DeserializationError.html#method.render).
+//!
+//! ```rust
+//! use serde_db::de::DeserializationError;
+//! use serde_db::message::{DefaultMessageFormatter, ErrorMessage, MessageFormatter};
+//!
+//! struct German;
+//! impl MessageFormatter for German {
+//!     fn format(&self, message: &ErrorMessage) -> String {
+//!         match message {
+//!             ErrorMessage::UnknownField(field) => format!("Unbekanntes Feld: {field}"),
+//!             _ => DefaultMessageFormatter.format(message),
+//!         }
+//!     }
+//! }
+//!
+//! let error = DeserializationError::UnknownField("ID".to_string());
+//! assert_eq!(error.render(&German), "Unbekanntes Feld: ID");
+//! assert_eq!(error.render(&DefaultMessageFormatter), "unknown field: ID");
+//! ```
+
+/// The structured data of an error, independent of its textual representation.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorMessage<'a> {
+    /// A database value cannot be converted into the desired rust type.
+    ValueType(&'a str),
+    /// A database value is too big or too small for the desired rust type.
+    NumberRange(&'a str),
+    /// A database value was not completely loaded.
+    Incomplete(&'a str),
+    /// A JSON value is not a valid JSON document.
+    InvalidJson(&'a str),
+    /// A text value is not valid UTF-8.
+    InvalidUtf8 {
+        /// The length of the valid prefix, in bytes.
+        valid_up_to: usize,
+    },
+    /// A number is NaN or infinite, and the target does not accept it.
+    NonFinite(f64),
+    /// A number is zero, and the target is a non-zero integer of the given primitive type.
+    Zero(&'a str),
+    /// A conversion failed due to the given root cause.
+    ConversionCause(&'a (dyn std::error::Error + 'static)),
+    /// An error reported from the serde framework.
+    Serde(&'a str),
+    /// The target structure does not fit to the structure of the data.
+    Usage(&'a str),
+    /// A not implemented function of the serde interface was called.
+    NotImplemented(&'a str),
+    /// The deserialization target has no field for a column.
+    UnknownField(&'a str),
+    /// Not all rows could be consumed.
+    TrailingRows,
+    /// Not all columns could be consumed.
+    TrailingCols,
+    /// A single value was requested, but there are no rows.
+    NoRows,
+    /// A single value or row was requested, but there is more than one row.
+    MultipleRows,
+    /// A single value was requested, but there is more than one column.
+    MultipleColumns,
+    /// The deserialization was cancelled.
+    Cancelled,
+    /// The resultset contains more rows than the given limit.
+    RowLimitExceeded(usize),
+    /// The values of the resultset occupy more bytes than the given budget.
+    ByteLimitExceeded(usize),
+    /// The values of these columns were not used by the deserialization target.
+    UnusedColumns(&'a [String]),
+    /// Some rows were rejected by the validation, with their indices and the reasons.
+    ValidationFailed(&'a [(usize, String)]),
+    /// The deserialization of the field with the given path failed.
+    AtField {
+        /// The path of the field, like `address.lines[2]`.
+        path: &'a str,
+        /// The message of the underlying error.
+        cause: Box<ErrorMessage<'a>>,
+    },
+    /// A String cannot be parsed into the value for a database parameter.
+    Parse {
+        /// The String that was to be parsed.
+        value: &'a str,
+        /// The database type of the parameter.
+        db_type: &'a str,
+        /// The root cause, if available.
+        cause: Option<&'a (dyn std::error::Error + 'static)>,
+    },
+    /// The structure of the input does not fit to the list of parameters.
+    StructuralMismatch(&'a str),
+    /// The input has a field for which the statement has no parameter.
+    UnknownParameter(&'a str),
+    /// The input has no values for these parameters.
+    MissingParameters(&'a [String]),
+    /// The input map has keys for which the statement has no parameters.
+    UnmatchedParameters {
+        /// The keys for which no parameters exist.
+        unknown_keys: &'a [String],
+        /// The parameters for which the input has no keys.
+        missing: &'a [String],
+    },
+    /// The input provides fewer values than there are parameters.
+    ParameterCount {
+        /// The number of parameters.
+        expected: usize,
+        /// The number of provided values.
+        provided: usize,
+    },
+    /// The serialization of the input with the given position in a batch failed.
+    AtRow {
+        /// The position of the input, starting with 0.
+        index: usize,
+        /// The message of the underlying error.
+        cause: Box<ErrorMessage<'a>>,
+    },
+    /// The conversion of the value for the parameter with the given position failed.
+    AtParameter {
+        /// The position of the parameter, starting with 0.
+        index: usize,
+        /// The name of the parameter, if known.
+        name: Option<&'a str>,
+        /// The database type of the parameter.
+        db_type: &'a str,
+        /// The field of the input struct that provided the value, if known.
+        field: Option<&'a str>,
+        /// The message of the underlying error.
+        cause: Box<ErrorMessage<'a>>,
+    },
+    /// The type of an input value does not fit to the database type.
+    Type {
+        /// The type of the input value.
+        value_type: &'a str,
+        /// The database type of the parameter.
+        db_type: &'a str,
+    },
+    /// An input value is too big or too small for the database type.
+    Range {
+        /// The type of the input value.
+        value_type: &'a str,
+        /// The database type of the parameter.
+        db_type: &'a str,
+    },
+}
+
+/// Renders an [`ErrorMessage`] as text.
+pub trait MessageFormatter {
+    /// Returns the text for the given message.
+    fn format(&self, message: &ErrorMessage) -> String;
+}
+
+/// Renders English messages.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultMessageFormatter;
+
+impl MessageFormatter for DefaultMessageFormatter {
+    fn format(&self, message: &ErrorMessage) -> String {
+        match *message {
+            ErrorMessage::ValueType(s) => format!("value types do not match: {s}"),
+            ErrorMessage::NumberRange(s) => format!("number range exceeded: {s}"),
+            ErrorMessage::Incomplete(s) => format!("value is not completely loaded: {s}"),
+            ErrorMessage::InvalidJson(s) => format!("invalid JSON document: {s}"),
+            ErrorMessage::InvalidUtf8 { valid_up_to } => {
+                format!("invalid UTF-8 sequence at byte offset {valid_up_to}")
+            }
+            ErrorMessage::NonFinite(f) => format!("non-finite number: {f}"),
+            ErrorMessage::Zero(s) => format!("zero is not a valid non-zero {s}"),
+            ErrorMessage::ConversionCause(e) => format!("conversion failed: {e}"),
+            ErrorMessage::Serde(s) => format!("serde error: {s}"),
+            ErrorMessage::Usage(s) => format!("incompatible target structure: {s}"),
+            ErrorMessage::NotImplemented(s) => format!("not implemented: {s}"),
+            ErrorMessage::UnknownField(s) => format!("unknown field: {s}"),
+            ErrorMessage::TrailingRows => "cannot consume all existing rows".to_string(),
+            ErrorMessage::TrailingCols => "cannot consume all existing columns".to_string(),
+            ErrorMessage::NoRows => "the resultset contains no rows".to_string(),
+            ErrorMessage::MultipleRows => "the resultset contains more than one row".to_string(),
+            ErrorMessage::MultipleColumns => {
+                "the resultset contains more than one column".to_string()
+            }
+            ErrorMessage::Cancelled => "the deserialization was cancelled".to_string(),
+            ErrorMessage::RowLimitExceeded(max_rows) => {
+                format!("the resultset exceeds the limit of {max_rows} rows")
+            }
+            ErrorMessage::ByteLimitExceeded(max_bytes) => {
+                format!("the resultset exceeds the budget of {max_bytes} bytes")
+            }
+            ErrorMessage::UnusedColumns(columns) => {
+                format!("columns not used by the target: {}", columns.join(", "))
+            }
+            ErrorMessage::ValidationFailed(failures) => format_validation_failures(failures),
+            ErrorMessage::AtField { path, ref cause } => {
+                format!("at {path}: {}", self.format(cause))
+            }
+            ErrorMessage::Parse {
+                value,
+                db_type,
+                cause,
+            } => match cause {
+                Some(e) => {
+                    format!("\"{value}\" cannot be parsed into SQL type {db_type} due to {e}")
+                }
+                None => format!("\"{value}\" cannot be parsed into SQL type {db_type}"),
+            },
+            ErrorMessage::StructuralMismatch(s) => {
+                format!("structure does not fit to the parameters: {s}")
+            }
+            ErrorMessage::UnknownParameter(s) => format!("no parameter for field {s}"),
+            ErrorMessage::MissingParameters(names) => {
+                format!("no values for the parameters {}", names.join(", "))
+            }
+            ErrorMessage::UnmatchedParameters {
+                unknown_keys,
+                missing,
+            } => format_unmatched_parameters(unknown_keys, missing),
+            ErrorMessage::ParameterCount { expected, provided } => {
+                format!("{provided} values provided for {expected} parameters")
+            }
+            ErrorMessage::AtRow { index, ref cause } => {
+                format!("in row {index}: {}", self.format(cause))
+            }
+            ErrorMessage::AtParameter {
+                index,
+                name,
+                db_type,
+                field,
+                ref cause,
+            } => {
+                let name = name.map(|name| format!(" ({name})")).unwrap_or_default();
+                let field = field
+                    .map(|field| format!(" from field {field}"))
+                    .unwrap_or_default();
+                // conversion errors already name the database type
+                let db_type = if names_db_type(cause) {
+                    String::new()
+                } else {
+                    format!(" of SQL type {db_type}")
+                };
+                format!(
+                    "at parameter {index}{name}{field}{db_type}: {}",
+                    self.format(cause)
+                )
+            }
+            ErrorMessage::Type {
+                value_type,
+                db_type,
+            } => format!("value of type {value_type} cannot be converted into SQL type {db_type}"),
+            ErrorMessage::Range {
+                value_type,
+                db_type,
+            } => format!(
+                "value of type {value_type} does not fit into the range of SQL type {db_type}"
+            ),
+        }
+    }
+}
+
+fn format_validation_failures(failures: &[(usize, String)]) -> String {
+    format!(
+        "validation failed for {}",
+        failures
+            .iter()
+            .map(|(idx, reason)| format!("row {idx} ({reason})"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn format_unmatched_parameters(unknown_keys: &[String], missing: &[String]) -> String {
+    let unknown = format!("no parameters for the keys {}", unknown_keys.join(", "));
+    if missing.is_empty() {
+        unknown
+    } else {
+        format!(
+            "{unknown}; no values for the parameters {}",
+            missing.join(", ")
+        )
+    }
+}
+
+fn names_db_type(message: &ErrorMessage) -> bool {
+    matches!(
+        *message,
+        ErrorMessage::Type { .. } | ErrorMessage::Range { .. } | ErrorMessage::Parse { .. }
+    )
+}
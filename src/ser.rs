@@ -32,14 +32,47 @@
 //!
 //! It is assumed that the prepared statement has metadata about the required input parameters,
 //! which implement [`DbvFactory`](trait.DbvFactory.html).
+//!
+//! Drivers without parameter metadata can infer descriptors from the input, with
+//! [`to_params_inferred()`](fn.to_params_inferred.html).
+//!
+//! Statements with named parameters can bind the fields of a struct by name instead of by
+//! position, with [`to_named_params()`](fn.to_named_params.html), if
+//! [`DbvFactory::name()`](trait.DbvFactory.html#method.name) provides the parameter names.
+//!
+//! Unit variants of enums, like `Status::Active`, are converted by name or by position,
+//! as decided by the parameter descriptor or by the
+//! [`SerializationOptions`](struct.SerializationOptions.html) of the call.
+//!
+//! Values of type `Vec<u8>` or `&[u8]`, and fields with `#[serde(with = "serde_bytes")]`,
+//! are converted into a single binary parameter, with
+//! [`DbvFactory::serialize_bytes()`](trait.DbvFactory.html#tymethod.serialize_bytes).
+//! Large binary values can be provided as a [`LobStream`](struct.LobStream.html),
+//! which the driver reads in pieces.
 
+mod byte_serializer;
 mod dbv_factory;
+mod inference;
+mod lob_stream;
+mod named_serializer;
 mod serialization_error;
+mod serialization_options;
 mod serializer;
+#[cfg(feature = "chrono")]
+mod temporal;
 
 pub use self::dbv_factory::DbvFactory;
+pub use self::inference::{
+    infer_parameters, infer_parameters_with, to_params_inferred, to_params_inferred_with,
+    InferredParameter, ParameterKind,
+};
+pub use self::lob_stream::LobStream;
 pub use self::serialization_error::{parse_error, type_error, SerializationError};
+pub use self::serialization_options::{EnumRepresentation, NonePolicy, SerializationOptions};
+#[cfg(feature = "chrono")]
+pub use self::temporal::{Temporal, TemporalEncoding};
 
+use self::named_serializer::{parameter_names, NamedSerializer};
 use self::serializer::Serializer;
 
 /// Provided method that translates the input into a Vec of database values.
@@ -47,21 +80,226 @@ use self::serializer::Serializer;
 /// Database drivers use this method in their implementation (e.g. behind a
 /// `PreparedStatement::add_batch()`).
 ///
+/// The input can be a struct, whose fields are taken in their order,
+/// or a tuple, an array, or a `Vec`, with one value for each parameter.
+///
 /// # Errors
 ///
-/// `SerializationError` if the value cannot be translated into a database value of
-/// the required type.
+/// `SerializationError::ParameterCount` if fewer values than parameters are provided,
+/// `SerializationError::StructuralMismatch` if more values than parameters are provided,
+/// and `SerializationError::AtParameter` with the position of the parameter
+/// if a value cannot be translated into a database value of the required type.
 pub fn to_params<T, DF>(
     value: &T,
     metadata: &mut dyn std::iter::Iterator<Item = DF>,
 ) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    DF: DbvFactory,
+{
+    to_params_with(value, metadata, &SerializationOptions::default())
+}
+
+/// Provided method that translates the input into a Vec of database values,
+/// using the given options.
+///
+/// # Errors
+///
+/// See [`to_params()`](fn.to_params.html).
+pub fn to_params_with<T, DF>(
+    value: &T,
+    metadata: &mut dyn std::iter::Iterator<Item = DF>,
+    options: &SerializationOptions,
+) -> Result<Vec<DF::DBV>, SerializationError>
 where
     T: Sized + serde::Serialize,
     DF: DbvFactory,
 {
     #[cfg(feature = "trace")]
     log::trace!("serde_db::to_params()");
-    let mut serializer = Serializer::new(metadata);
+    let mut serializer = Serializer::new(metadata, options);
     value.serialize(&mut serializer)?;
+    serializer.check_completeness()?;
     Ok(serializer.into_inner())
 }
+
+/// Provided method that translates a batch of inputs, e.g. for an execute-many call,
+/// into one Vec of database values per input.
+///
+/// The same parameter descriptors are used for all inputs; they are borrowed from the slice
+/// for each input, so nothing is resolved per descriptor beyond what
+/// [`to_params()`](fn.to_params.html) does for a single input.
+///
+/// ```rust,ignore
+/// let rows: Vec<Vec<DBValue>> = serde_db::ser::to_params_batch(&inputs, &input_metadata)?;
+/// ```
+///
+/// # Errors
+///
+/// `SerializationError::AtRow` with the index of the failing input,
+/// containing the error of [`to_params()`](fn.to_params.html).
+pub fn to_params_batch<'p, T, P>(
+    values: &[T],
+    metadata: &'p [P],
+) -> Result<Vec<Vec<<&'p P as DbvFactory>::DBV>>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    &'p P: DbvFactory,
+{
+    to_params_batch_with(values, metadata, &SerializationOptions::default())
+}
+
+/// Provided method that translates a batch of inputs into one Vec of database values
+/// per input, using the given options.
+///
+/// # Errors
+///
+/// See [`to_params_batch()`](fn.to_params_batch.html).
+pub fn to_params_batch_with<'p, T, P>(
+    values: &[T],
+    metadata: &'p [P],
+    options: &SerializationOptions,
+) -> Result<Vec<Vec<<&'p P as DbvFactory>::DBV>>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    &'p P: DbvFactory,
+{
+    #[cfg(feature = "trace")]
+    log::trace!("serde_db::to_params_batch() with {} rows", values.len());
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            to_params_with(value, &mut metadata.iter(), options).map_err(|e| {
+                SerializationError::AtRow {
+                    index,
+                    source: Box::new(e),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Provided method that translates the fields of a struct into a Vec of database values,
+/// using the names of the parameters.
+///
+/// Each field is converted into the parameter with the same name, which is provided
+/// by [`DbvFactory::name()`](trait.DbvFactory.html#method.name); the values are returned
+/// in the order of the parameters, independent of the order of the fields:
+///
+/// ```rust,ignore
+/// // for a statement like "INSERT INTO T (ID, NAME) VALUES (:ID, :NAME)"
+/// #[derive(Serialize)]
+/// #[allow(non_snake_case)]
+/// struct Input { NAME: String, ID: i32 }
+/// let db_values: Vec<DBValue> = serde_db::ser::to_named_params(&input, input_metadata)?;
+/// ```
+///
+/// For dynamically constructed statements, the input can also be a map with string keys,
+/// like a `HashMap<String, T>` or a `BTreeMap<String, T>`, whose entries are bound by key.
+///
+/// # Errors
+///
+/// `SerializationError::UnknownParameter` if a field has no parameter with its name,
+/// `SerializationError::UnmatchedParameters` with all unknown keys and all missing parameters
+/// if the keys of a map have no parameter with their name,
+/// `SerializationError::MissingParameters` if some parameters are not provided by a field
+/// or a key, and `SerializationError` if a value cannot be translated into a database
+/// value of the required type.
+pub fn to_named_params<T, DF>(
+    value: &T,
+    metadata: &mut dyn std::iter::Iterator<Item = DF>,
+) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    DF: DbvFactory,
+{
+    to_named_params_with(value, metadata, &SerializationOptions::default())
+}
+
+/// Provided method that translates the fields of a struct into a Vec of database values,
+/// using the names of the parameters and the given options.
+///
+/// # Errors
+///
+/// See [`to_named_params()`](fn.to_named_params.html).
+pub fn to_named_params_with<T, DF>(
+    value: &T,
+    metadata: &mut dyn std::iter::Iterator<Item = DF>,
+    options: &SerializationOptions,
+) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    DF: DbvFactory,
+{
+    #[cfg(feature = "trace")]
+    log::trace!("serde_db::to_named_params()");
+    let parameters: Vec<DF> = metadata.collect();
+    let names = parameter_names(&parameters)?;
+    let mut serializer = NamedSerializer::new(parameters, &names, options);
+    value.serialize(&mut serializer)?;
+    serializer.into_inner()
+}
+
+/// Provided method that translates a batch of structs or maps into one Vec of database values
+/// per input, using the names of the parameters.
+///
+/// The names of the parameters are resolved only once for the whole batch.
+///
+/// ```rust,ignore
+/// let rows: Vec<Vec<DBValue>> = serde_db::ser::to_named_params_batch(&inputs, &input_metadata)?;
+/// ```
+///
+/// # Errors
+///
+/// `SerializationError::StructuralMismatch` if a parameter has no name,
+/// and `SerializationError::AtRow` with the index of the failing input,
+/// containing the error of [`to_named_params()`](fn.to_named_params.html).
+pub fn to_named_params_batch<'p, T, P>(
+    values: &[T],
+    metadata: &'p [P],
+) -> Result<Vec<Vec<<&'p P as DbvFactory>::DBV>>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    &'p P: DbvFactory,
+{
+    to_named_params_batch_with(values, metadata, &SerializationOptions::default())
+}
+
+/// Provided method that translates a batch of structs or maps into one Vec of database values
+/// per input, using the names of the parameters and the given options.
+///
+/// # Errors
+///
+/// See [`to_named_params_batch()`](fn.to_named_params_batch.html).
+pub fn to_named_params_batch_with<'p, T, P>(
+    values: &[T],
+    metadata: &'p [P],
+    options: &SerializationOptions,
+) -> Result<Vec<Vec<<&'p P as DbvFactory>::DBV>>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    &'p P: DbvFactory,
+{
+    #[cfg(feature = "trace")]
+    log::trace!(
+        "serde_db::to_named_params_batch() with {} rows",
+        values.len()
+    );
+    let parameters: Vec<&'p P> = metadata.iter().collect();
+    let names = parameter_names(&parameters)?;
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let mut serializer = NamedSerializer::new(parameters.clone(), &names, options);
+            value
+                .serialize(&mut serializer)
+                .and_then(|()| serializer.into_inner())
+                .map_err(|e| SerializationError::AtRow {
+                    index,
+                    source: Box::new(e),
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,148 @@
+use super::SerializationError;
+use serde::ser::Impossible;
+use serde::Serialize;
+
+type SerializationResult<T> = Result<T, SerializationError>;
+
+const NOT_A_BYTE: &str = "a sequence of bytes contains other values";
+
+// Accepts only u8 values, for collecting the elements of a `Vec<u8>` or a `&[u8]`
+// into a single binary parameter.
+pub(crate) struct ByteSerializer;
+
+fn not_a_byte<T>() -> SerializationResult<T> {
+    Err(SerializationError::StructuralMismatch(NOT_A_BYTE))
+}
+
+impl serde::Serializer for ByteSerializer {
+    type Ok = u8;
+    type Error = SerializationError;
+    type SerializeSeq = Impossible<u8, SerializationError>;
+    type SerializeTuple = Impossible<u8, SerializationError>;
+    type SerializeTupleStruct = Impossible<u8, SerializationError>;
+    type SerializeTupleVariant = Impossible<u8, SerializationError>;
+    type SerializeMap = Impossible<u8, SerializationError>;
+    type SerializeStruct = Impossible<u8, SerializationError>;
+    type SerializeStructVariant = Impossible<u8, SerializationError>;
+
+    fn serialize_u8(self, value: u8) -> SerializationResult<u8> {
+        Ok(value)
+    }
+
+    fn serialize_bool(self, _: bool) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_i8(self, _: i8) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_i16(self, _: i16) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_i32(self, _: i32) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_i64(self, _: i64) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_u16(self, _: u16) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_u32(self, _: u32) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_u64(self, _: u64) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_f32(self, _: f32) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_f64(self, _: f64) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_char(self, _: char) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_str(self, _: &str) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_bytes(self, _: &[u8]) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_none(self) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_unit(self) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> SerializationResult<u8> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> SerializationResult<u8> {
+        not_a_byte()
+    }
+    fn serialize_seq(self, _: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
+        not_a_byte()
+    }
+    fn serialize_tuple(self, _: usize) -> SerializationResult<Self::SerializeTuple> {
+        not_a_byte()
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeTupleStruct> {
+        not_a_byte()
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeTupleVariant> {
+        not_a_byte()
+    }
+    fn serialize_map(self, _: Option<usize>) -> SerializationResult<Self::SerializeMap> {
+        not_a_byte()
+    }
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeStruct> {
+        not_a_byte()
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeStructVariant> {
+        not_a_byte()
+    }
+}
@@ -1,8 +1,17 @@
-use super::SerializationError;
+use super::{type_error, EnumRepresentation, SerializationError};
+#[cfg(feature = "chrono")]
+use super::{Temporal, TemporalEncoding};
 
 /// A factory for database objects.
 ///
 /// This trait is to be implemented by descriptors of parameters for database commands.
+/// Since each conversion is done by the descriptor of the target parameter, the same rust
+/// value is converted according to the database type, e.g. an `i64` into a `BIGINT` or
+/// into a `DECIMAL` value.
+///
+/// Descriptors can also declare the nullability, the maximum length, and the precision of the
+/// parameter; the serializer then rejects `None` values for non-nullable parameters and too
+/// long texts and byte sequences before calling the factory methods.
 ///
 /// # Example
 ///
@@ -117,6 +126,97 @@ pub trait DbvFactory: Sized {
     /// `SerializationError` if the required database parameter cannot be constructed
     /// from the given value.
     fn serialize_none(&self) -> Result<Self::DBV, SerializationError>;
+    /// Serialize a LOB value that is provided as a [`LobStream`](struct.LobStream.html).
+    ///
+    /// The database value can keep the reader, so that the driver reads the content in pieces
+    /// when it sends the parameter to the database.
+    ///
+    /// # Errors
+    ///
+    /// The default implementation returns a `SerializationError::Type`.
+    fn serialize_lob(
+        &self,
+        _reader: Box<dyn std::io::Read + Send>,
+    ) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("lob", self.descriptor()))
+    }
+
+    /// Serialize a marker that lets the database apply the default value of the column,
+    /// like `DEFAULT` in an `INSERT` statement, for `None` values with
+    /// [`NonePolicy::Skip`](enum.NonePolicy.html#variant.Skip).
+    ///
+    /// # Errors
+    ///
+    /// The default implementation returns a `SerializationError::Type`,
+    /// for drivers that cannot skip parameters.
+    fn serialize_default(&self) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("default", self.descriptor()))
+    }
     /// Provide a descriptive String of the type that is required (for error messages).
     fn descriptor(&self) -> String;
+
+    /// Returns the name of the parameter, if the database command has named parameters.
+    ///
+    /// Named parameters are required by [`to_named_params()`](fn.to_named_params.html).
+    /// The default implementation returns `None`.
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns whether the parameter accepts NULL values, if known.
+    ///
+    /// The default implementation returns `None`.
+    fn is_nullable(&self) -> Option<bool> {
+        None
+    }
+
+    /// Returns the maximum length of text values (in characters) and binary values (in bytes),
+    /// if the parameter has one.
+    ///
+    /// The default implementation returns `None`.
+    fn max_length(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the precision and the scale of numeric parameters, like `DECIMAL(10, 2)`,
+    /// if known, for use in the conversions of the factory methods.
+    ///
+    /// The default implementation returns `None`.
+    fn precision(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    /// Returns how unit variants of enums are converted for this parameter, e.g.
+    /// by position for integer parameters, if the descriptor has a preference.
+    ///
+    /// The [`SerializationOptions`](struct.SerializationOptions.html) of the call take
+    /// precedence. The default implementation returns `None`, so that variants are
+    /// converted by name.
+    fn enum_representation(&self) -> Option<EnumRepresentation> {
+        None
+    }
+
+    /// Returns how the parameter expects temporal values, if it is of a temporal type.
+    ///
+    /// Types like `chrono::NaiveDate` or `chrono::DateTime<Utc>` are serialized as ISO 8601
+    /// strings; for parameters with a temporal encoding, the serializer parses such strings
+    /// into a [`Temporal`](enum.Temporal.html) and converts them according to the encoding.
+    /// Other strings are handed over to `serialize_str()` unchanged.
+    ///
+    /// The default implementation returns `None`.
+    #[cfg(feature = "chrono")]
+    fn temporal_encoding(&self) -> Option<TemporalEncoding> {
+        None
+    }
+
+    /// Serialize a temporal value, for parameters with
+    /// [`TemporalEncoding::Native`](enum.TemporalEncoding.html#variant.Native).
+    ///
+    /// # Errors
+    ///
+    /// The default implementation returns a `SerializationError::Type`.
+    #[cfg(feature = "chrono")]
+    fn serialize_temporal(&self, _value: Temporal) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("temporal", self.descriptor()))
+    }
 }
@@ -0,0 +1,230 @@
+use super::lob_stream::restore_pending_reader;
+use super::serializer::Serializer;
+use super::{DbvFactory, SerializationError, SerializationOptions};
+
+/// The description of a parameter that was inferred from a value,
+/// as produced by [`infer_parameters()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InferredParameter {
+    /// The position of the parameter, starting with 0.
+    pub index: usize,
+    /// The field of the input struct that provides the value, if any.
+    pub field: Option<&'static str>,
+    /// The kind of the value.
+    pub kind: ParameterKind,
+    /// True if the value is an `Option`.
+    ///
+    /// Since only the value is analyzed, `Option` values are only recognized
+    /// if they are `Some` or `None`, but not in their type.
+    pub nullable: bool,
+}
+
+/// The kind of value that a parameter gets, in serde's data model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParameterKind {
+    /// A `bool`.
+    Bool,
+    /// An `i8`.
+    I8,
+    /// An `i16`.
+    I16,
+    /// An `i32`.
+    I32,
+    /// An `i64`.
+    I64,
+    /// A `u8`.
+    U8,
+    /// A `u16`.
+    U16,
+    /// A `u32`.
+    U32,
+    /// A `u64`.
+    U64,
+    /// An `f32`.
+    F32,
+    /// An `f64`.
+    F64,
+    /// A `char`.
+    Char,
+    /// A string, including the names of enum variants and serialized temporal values.
+    Str,
+    /// A sequence of bytes.
+    Bytes,
+    /// A [`LobStream`](struct.LobStream.html).
+    Lob,
+    /// A `None` value, whose type is unknown.
+    Null,
+}
+
+/// Infers the descriptions of the parameters from a value, for database drivers that
+/// have no parameter metadata.
+///
+/// The value is analyzed with the same rules as in [`to_params()`](fn.to_params.html),
+/// so that each description corresponds to one of the database values that `to_params()`
+/// produces. See also [`to_params_inferred()`].
+///
+/// # Errors
+///
+/// `SerializationError` if the value cannot be serialized into parameters.
+pub fn infer_parameters<T>(value: &T) -> Result<Vec<InferredParameter>, SerializationError>
+where
+    T: serde::Serialize,
+{
+    infer_parameters_with(value, &SerializationOptions::default())
+}
+
+/// Infers the descriptions of the parameters from a value, using the given options.
+///
+/// # Errors
+///
+/// `SerializationError` if the value cannot be serialized into parameters.
+pub fn infer_parameters_with<T>(
+    value: &T,
+    options: &SerializationOptions,
+) -> Result<Vec<InferredParameter>, SerializationError>
+where
+    T: serde::Serialize,
+{
+    #[cfg(feature = "trace")]
+    log::trace!("serde_db::infer_parameters()");
+    let mut probes = std::iter::repeat(Probe);
+    let mut serializer = Serializer::for_inference(&mut probes, options);
+    value.serialize(&mut serializer)?;
+    let (kinds, contexts) = serializer.into_inferred();
+    Ok(kinds
+        .into_iter()
+        .zip(contexts)
+        .enumerate()
+        .map(|(index, (kind, (field, optional)))| InferredParameter {
+            index,
+            field,
+            kind,
+            nullable: optional || kind == ParameterKind::Null,
+        })
+        .collect())
+}
+
+/// Translates the input into a Vec of database values in two phases, for database drivers
+/// that have no parameter metadata: first the descriptions of the parameters are inferred
+/// from the input, and the driver turns each of them into a parameter descriptor with
+/// the given function, then the input is converted with these descriptors.
+///
+/// ```rust,ignore
+/// let db_values: Vec<DBValue> = serde_db::ser::to_params_inferred(&input, |p| {
+///     match p.kind {
+///         ParameterKind::I8 | ParameterKind::I16 | ParameterKind::I32 => DbType::Int,
+///         ParameterKind::Bytes | ParameterKind::Lob => DbType::Blob,
+///         _ => DbType::Text,
+///     }
+/// })?;
+/// ```
+///
+/// # Errors
+///
+/// `SerializationError` if the input cannot be serialized into parameters, or if a value
+/// cannot be translated into a database value of the chosen type.
+pub fn to_params_inferred<T, DF, F>(
+    value: &T,
+    describe: F,
+) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    T: serde::Serialize,
+    DF: DbvFactory,
+    F: FnMut(&InferredParameter) -> DF,
+{
+    to_params_inferred_with(value, describe, &SerializationOptions::default())
+}
+
+/// Translates the input into a Vec of database values in two phases, using the given options.
+///
+/// See [`to_params_inferred()`].
+///
+/// # Errors
+///
+/// `SerializationError` if the input cannot be serialized into parameters, or if a value
+/// cannot be translated into a database value of the chosen type.
+pub fn to_params_inferred_with<T, DF, F>(
+    value: &T,
+    describe: F,
+    options: &SerializationOptions,
+) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    T: serde::Serialize,
+    DF: DbvFactory,
+    F: FnMut(&InferredParameter) -> DF,
+{
+    let descriptors: Vec<DF> = infer_parameters_with(value, options)?
+        .iter()
+        .map(describe)
+        .collect();
+    super::to_params_with(value, &mut descriptors.into_iter(), options)
+}
+
+// A parameter descriptor that reports the kind of each value.
+#[derive(Clone, Copy)]
+struct Probe;
+
+impl DbvFactory for Probe {
+    type DBV = ParameterKind;
+
+    fn serialize_bool(&self, _value: bool) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::Bool)
+    }
+    fn serialize_i8(&self, _value: i8) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::I8)
+    }
+    fn serialize_i16(&self, _value: i16) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::I16)
+    }
+    fn serialize_i32(&self, _value: i32) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::I32)
+    }
+    fn serialize_i64(&self, _value: i64) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::I64)
+    }
+    fn serialize_u8(&self, _value: u8) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::U8)
+    }
+    fn serialize_u16(&self, _value: u16) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::U16)
+    }
+    fn serialize_u32(&self, _value: u32) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::U32)
+    }
+    fn serialize_u64(&self, _value: u64) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::U64)
+    }
+    fn serialize_f32(&self, _value: f32) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::F32)
+    }
+    fn serialize_f64(&self, _value: f64) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::F64)
+    }
+    fn serialize_char(&self, _value: char) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::Char)
+    }
+    fn serialize_str(&self, _value: &str) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::Str)
+    }
+    fn serialize_bytes(&self, _value: &[u8]) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::Bytes)
+    }
+    fn serialize_none(&self) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::Null)
+    }
+    fn serialize_default(&self) -> Result<ParameterKind, SerializationError> {
+        Ok(ParameterKind::Null)
+    }
+    fn serialize_lob(
+        &self,
+        reader: Box<dyn std::io::Read + Send>,
+    ) -> Result<ParameterKind, SerializationError> {
+        // the reader is needed when the values are produced
+        restore_pending_reader(reader);
+        Ok(ParameterKind::Lob)
+    }
+    fn descriptor(&self) -> String {
+        "inferred".to_string()
+    }
+}
@@ -0,0 +1,110 @@
+use serde::ser::Error;
+use std::cell::{Cell, RefCell};
+use std::io::Read;
+
+// The name of the newtype struct with which a `LobStream` hands its reader to the serializer.
+pub(crate) const LOB_PARAMETER: &str = "$serde_db::LobParameter";
+
+thread_local! {
+    // The reader of the LobStream that is currently being serialized.
+    static PENDING_READER: RefCell<Option<Box<dyn Read + Send>>> = const { RefCell::new(None) };
+}
+
+/// A value for a LOB parameter that is read from an `io::Read` or from an iterator
+/// of chunks, so that large values don't need to be held completely in memory.
+///
+/// The reader is passed through to the driver with
+/// [`DbvFactory::serialize_lob()`](trait.DbvFactory.html#method.serialize_lob),
+/// which can then send the content to the database in pieces:
+///
+/// ```rust,ignore
+/// #[derive(Serialize)]
+/// struct Document {
+///     id: i32,
+///     content: LobStream,
+/// }
+/// let document = Document { id: 1, content: LobStream::new(File::open("big.pdf")?) };
+/// statement.add_batch(&document)?;
+/// ```
+///
+/// A `LobStream` can be consumed only once, and only by the serializer of this crate.
+///
+/// The reader is handed over to the serializer through a thread-local variable,
+/// which is only valid during the call of `serialize()`. A `Serializer` thus must call
+/// `serialize_newtype_struct()` synchronously and on the same thread; other serializers
+/// just see a newtype struct around `()`. If the serializer does not consume the reader,
+/// e.g. because it fails or because it is not the serializer of this crate,
+/// the reader stays with the `LobStream`, which can then be serialized again.
+pub struct LobStream(Cell<Option<Box<dyn Read + Send>>>);
+
+impl LobStream {
+    /// Creates a value that is read from the given reader.
+    pub fn new<R: Read + Send + 'static>(reader: R) -> Self {
+        LobStream(Cell::new(Some(Box::new(reader))))
+    }
+
+    /// Creates a value that is read from the given chunks.
+    pub fn from_chunks<I>(chunks: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+        I::IntoIter: Send + 'static,
+    {
+        Self::new(ChunkReader {
+            chunks: chunks.into_iter(),
+            current: std::io::Cursor::new(Vec::new()),
+        })
+    }
+}
+
+impl std::fmt::Debug for LobStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("LobStream").finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for LobStream {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let reader = self
+            .0
+            .take()
+            .ok_or_else(|| S::Error::custom("a LobStream can only be serialized once"))?;
+        PENDING_READER.with(|pending| *pending.borrow_mut() = Some(reader));
+        let result = serializer.serialize_newtype_struct(LOB_PARAMETER, &());
+        // other serializers, and the inference of parameters, leave the reader in place
+        if let Some(reader) = PENDING_READER.with(|pending| pending.borrow_mut().take()) {
+            self.0.set(Some(reader));
+        }
+        result
+    }
+}
+
+// Returns the reader of the LobStream that is currently being serialized.
+pub(crate) fn take_pending_reader() -> Option<Box<dyn Read + Send>> {
+    PENDING_READER.with(|pending| pending.borrow_mut().take())
+}
+
+// Hands the reader back to the LobStream that is currently being serialized.
+pub(crate) fn restore_pending_reader(reader: Box<dyn Read + Send>) {
+    PENDING_READER.with(|pending| *pending.borrow_mut() = Some(reader));
+}
+
+// Reads the chunks of an iterator one after the other.
+struct ChunkReader<I> {
+    chunks: I,
+    current: std::io::Cursor<Vec<u8>>,
+}
+
+impl<I: Iterator<Item = Vec<u8>>> Read for ChunkReader<I> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let count = self.current.read(buf)?;
+            if count > 0 || buf.is_empty() {
+                return Ok(count);
+            }
+            match self.chunks.next() {
+                Some(chunk) => self.current = std::io::Cursor::new(chunk),
+                None => return Ok(0),
+            }
+        }
+    }
+}
@@ -0,0 +1,467 @@
+use super::dbv_factory::DbvFactory;
+use super::serializer::Serializer;
+use super::{SerializationError, SerializationOptions};
+#[cfg(feature = "trace")]
+use log::trace;
+use serde::ser::Impossible;
+use serde::Serialize;
+
+type SerializationResult<T> = Result<T, SerializationError>;
+
+const NOT_A_STRUCT: &str = "named parameters can only be serialized from structs and maps";
+const NOT_A_STRING: &str = "the keys of maps must be strings";
+
+// Returns the names of the parameters, which are resolved once per statement.
+pub fn parameter_names<DF: DbvFactory>(parameters: &[DF]) -> SerializationResult<Vec<String>> {
+    parameters
+        .iter()
+        .map(|df| df.name().map(ToString::to_string))
+        .collect::<Option<Vec<String>>>()
+        .ok_or(SerializationError::StructuralMismatch(
+            "the statement has parameters without names",
+        ))
+}
+
+// Serializes the fields of a struct, or the entries of a map, into the parameters with the
+// same names.
+#[allow(missing_debug_implementations)]
+pub struct NamedSerializer<'o, DF: DbvFactory> {
+    names: &'o [String],
+    options: &'o SerializationOptions,
+    parameters: Vec<Option<DF>>,
+    output: Vec<Option<DF::DBV>>,
+    // The keys of a map for which no parameter exists.
+    unknown_keys: Vec<String>,
+}
+
+impl<'o, DF: DbvFactory> NamedSerializer<'o, DF> {
+    // `names` must be the result of `parameter_names(&parameters)`.
+    pub fn new(
+        parameters: Vec<DF>,
+        names: &'o [String],
+        options: &'o SerializationOptions,
+    ) -> Self {
+        NamedSerializer {
+            output: parameters.iter().map(|_| None).collect(),
+            parameters: parameters.into_iter().map(Some).collect(),
+            names,
+            options,
+            unknown_keys: Vec::new(),
+        }
+    }
+
+    // Serializes the value into the parameter with the given name.
+    fn bind<T: ?Sized + Serialize>(&mut self, name: &str, value: &T) -> SerializationResult<()> {
+        #[cfg(feature = "trace")]
+        trace!("NamedSerializer::bind() for {name}");
+        let idx = self
+            .names
+            .iter()
+            .position(|n| n == name)
+            .ok_or_else(|| SerializationError::UnknownParameter(name.to_string()))?;
+        let df = self.parameters[idx]
+            .take()
+            .ok_or(SerializationError::StructuralMismatch(
+                "a parameter is provided twice",
+            ))?;
+        let mut metadata = std::iter::once(df);
+        let mut serializer = Serializer::new_at(&mut metadata, self.options, idx);
+        serializer.serialize_value(value)?;
+        let mut values = serializer.into_inner();
+        if values.len() != 1 {
+            return Err(SerializationError::StructuralMismatch(
+                "a field must provide exactly one value",
+            ));
+        }
+        self.output[idx] = values.pop();
+        Ok(())
+    }
+
+    // Serializes the value of a map entry; unknown keys are collected for the error message.
+    fn bind_entry<T: ?Sized + Serialize>(
+        &mut self,
+        key: String,
+        value: &T,
+    ) -> SerializationResult<()> {
+        if self.names.contains(&key) {
+            self.bind(&key, value)
+        } else {
+            self.unknown_keys.push(key);
+            Ok(())
+        }
+    }
+
+    pub fn into_inner(self) -> SerializationResult<Vec<DF::DBV>> {
+        let missing: Vec<String> = self
+            .names
+            .iter()
+            .zip(&self.output)
+            .filter(|(_, value)| value.is_none())
+            .map(|(name, _)| name.clone())
+            .collect();
+        if !self.unknown_keys.is_empty() {
+            return Err(SerializationError::UnmatchedParameters {
+                unknown_keys: self.unknown_keys,
+                missing,
+            });
+        }
+        if !missing.is_empty() {
+            return Err(SerializationError::MissingParameters(missing));
+        }
+        Ok(self.output.into_iter().flatten().collect())
+    }
+}
+
+impl<'a, 'o, DF: DbvFactory> serde::Serializer for &'a mut NamedSerializer<'o, DF> {
+    type Ok = ();
+    type Error = SerializationError;
+    type SerializeSeq = Impossible<(), SerializationError>;
+    type SerializeTuple = Impossible<(), SerializationError>;
+    type SerializeTupleStruct = Impossible<(), SerializationError>;
+    type SerializeTupleVariant = Impossible<(), SerializationError>;
+    type SerializeMap = NamedCompound<'a, 'o, DF>;
+    type SerializeStruct = NamedCompound<'a, 'o, DF>;
+    type SerializeStructVariant = Impossible<(), SerializationError>;
+
+    fn serialize_bool(self, _value: bool) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_i8(self, _value: i8) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_i16(self, _value: i16) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_i32(self, _value: i32) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_i64(self, _value: i64) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_u8(self, _value: u8) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_u16(self, _value: u16) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_u32(self, _value: u32) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_u64(self, _value: u64) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_f32(self, _value: f32) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_f64(self, _value: f64) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_char(self, _value: char) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_str(self, _value: &str) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_none(self) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_unit(self) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> SerializationResult<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> SerializationResult<Self::Ok> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> SerializationResult<Self::SerializeTuple> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeTupleStruct> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeTupleVariant> {
+        Err(NOT_A_STRUCT.into())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> SerializationResult<Self::SerializeMap> {
+        #[cfg(feature = "trace")]
+        trace!("NamedSerializer::serialize_map()");
+        Ok(NamedCompound {
+            ser: self,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeStruct> {
+        #[cfg(feature = "trace")]
+        trace!("NamedSerializer::serialize_struct()");
+        Ok(NamedCompound {
+            ser: self,
+            key: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeStructVariant> {
+        Err(NOT_A_STRUCT.into())
+    }
+}
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct NamedCompound<'a, 'o, DF: DbvFactory> {
+    ser: &'a mut NamedSerializer<'o, DF>,
+    // The key of the current map entry.
+    key: Option<String>,
+}
+
+impl<DF: DbvFactory> serde::ser::SerializeStruct for NamedCompound<'_, '_, DF> {
+    type Ok = ();
+    type Error = SerializationError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> SerializationResult<()> {
+        self.ser.bind(key, value)
+    }
+
+    fn end(self) -> SerializationResult<Self::Ok> {
+        Ok(())
+    }
+}
+
+impl<DF: DbvFactory> serde::ser::SerializeMap for NamedCompound<'_, '_, DF> {
+    type Ok = ();
+    type Error = SerializationError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> SerializationResult<()> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> SerializationResult<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or(SerializationError::StructuralMismatch(
+                "a map value is provided without a key",
+            ))?;
+        self.ser.bind_entry(key, value)
+    }
+
+    fn end(self) -> SerializationResult<Self::Ok> {
+        Ok(())
+    }
+}
+
+// Accepts only strings, for the keys of maps.
+struct KeySerializer;
+
+impl serde::Serializer for KeySerializer {
+    type Ok = String;
+    type Error = SerializationError;
+    type SerializeSeq = Impossible<String, SerializationError>;
+    type SerializeTuple = Impossible<String, SerializationError>;
+    type SerializeTupleStruct = Impossible<String, SerializationError>;
+    type SerializeTupleVariant = Impossible<String, SerializationError>;
+    type SerializeMap = Impossible<String, SerializationError>;
+    type SerializeStruct = Impossible<String, SerializationError>;
+    type SerializeStructVariant = Impossible<String, SerializationError>;
+
+    fn serialize_str(self, value: &str) -> SerializationResult<String> {
+        Ok(value.to_string())
+    }
+    fn serialize_char(self, value: char) -> SerializationResult<String> {
+        Ok(value.to_string())
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> SerializationResult<String> {
+        Ok(variant.to_string())
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> SerializationResult<String> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _: bool) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_i8(self, _: i8) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_i16(self, _: i16) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_i32(self, _: i32) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_i64(self, _: i64) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_u8(self, _: u8) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_u16(self, _: u16) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_u32(self, _: u32) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_u64(self, _: u64) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_f32(self, _: f32) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_f64(self, _: f64) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_bytes(self, _: &[u8]) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_none(self) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_unit(self) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> SerializationResult<String> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_seq(self, _: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_tuple(self, _: usize) -> SerializationResult<Self::SerializeTuple> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeTupleStruct> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeTupleVariant> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_map(self, _: Option<usize>) -> SerializationResult<Self::SerializeMap> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeStruct> {
+        Err(NOT_A_STRING.into())
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> SerializationResult<Self::SerializeStructVariant> {
+        Err(NOT_A_STRING.into())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::message::{ErrorMessage, MessageFormatter};
 use thiserror::Error;
 
 /// Error that can occur while serializing a standard rust type or struct into a SQL parameter.
@@ -23,6 +24,57 @@ pub enum SerializationError {
     #[error("The structure of the provided type does not fit to the required list of parameters")]
     StructuralMismatch(&'static str),
 
+    /// The input has a field for which no parameter with that name exists
+    #[error("The input has a field for which no parameter with that name exists")]
+    UnknownParameter(String),
+
+    /// The input provides no values for the named parameters
+    #[error("The input provides no values for some parameters")]
+    MissingParameters(Vec<String>),
+
+    /// The input map has keys for which no parameters exist, and possibly lacks
+    /// values for some parameters
+    #[error("The keys of the input map do not fit to the parameters")]
+    UnmatchedParameters {
+        /// The keys for which no parameters exist.
+        unknown_keys: Vec<String>,
+        /// The parameters for which no keys exist.
+        missing: Vec<String>,
+    },
+
+    /// The input provides fewer values than the statement has parameters
+    #[error("The input provides {provided} values for {expected} parameters")]
+    ParameterCount {
+        /// The number of parameters.
+        expected: usize,
+        /// The number of provided values.
+        provided: usize,
+    },
+
+    /// The conversion of the value for a parameter failed
+    #[error("The conversion of the value for parameter {index} failed")]
+    AtParameter {
+        /// The position of the parameter, starting with 0.
+        index: usize,
+        /// The name of the parameter, if known.
+        name: Option<String>,
+        /// The database type of the parameter, as provided by its descriptor.
+        db_type: String,
+        /// The field of the input struct that provided the value, if known.
+        field: Option<&'static str>,
+        /// The underlying error.
+        source: Box<SerializationError>,
+    },
+
+    /// The serialization of an input of a batch failed
+    #[error("The serialization of row {index} of the batch failed")]
+    AtRow {
+        /// The position of the input in the batch, starting with 0.
+        index: usize,
+        /// The underlying error.
+        source: Box<SerializationError>,
+    },
+
     /// The input type does not fit to the required database type
     #[error("The input type does not fit to the required database type")]
     Type {
@@ -37,6 +89,75 @@ pub enum SerializationError {
     Range(&'static str, String),
 }
 
+impl SerializationError {
+    /// Returns the structured data of the error.
+    #[must_use]
+    pub fn message(&self) -> ErrorMessage<'_> {
+        match *self {
+            Self::Serde(ref s) => ErrorMessage::Serde(s),
+            Self::Parse {
+                ref value,
+                ref db_type,
+                ref cause,
+            } => ErrorMessage::Parse {
+                value,
+                db_type,
+                cause: cause
+                    .as_ref()
+                    .map(|e| e.as_ref() as &(dyn std::error::Error + 'static)),
+            },
+            Self::StructuralMismatch(s) => ErrorMessage::StructuralMismatch(s),
+            Self::UnknownParameter(ref s) => ErrorMessage::UnknownParameter(s),
+            Self::MissingParameters(ref names) => ErrorMessage::MissingParameters(names),
+            Self::UnmatchedParameters {
+                ref unknown_keys,
+                ref missing,
+            } => ErrorMessage::UnmatchedParameters {
+                unknown_keys,
+                missing,
+            },
+            Self::ParameterCount { expected, provided } => {
+                ErrorMessage::ParameterCount { expected, provided }
+            }
+            Self::AtParameter {
+                index,
+                ref name,
+                ref db_type,
+                field,
+                ref source,
+            } => ErrorMessage::AtParameter {
+                index,
+                name: name.as_deref(),
+                db_type,
+                field,
+                cause: Box::new(source.message()),
+            },
+            Self::AtRow { index, ref source } => ErrorMessage::AtRow {
+                index,
+                cause: Box::new(source.message()),
+            },
+            Self::Type {
+                value_type,
+                ref db_type,
+            } => ErrorMessage::Type {
+                value_type,
+                db_type,
+            },
+            Self::Range(value_type, ref db_type) => ErrorMessage::Range {
+                value_type,
+                db_type,
+            },
+        }
+    }
+
+    /// Renders the error with the given formatter,
+    /// e.g. for producing localized error messages.
+    #[must_use]
+    pub fn render(&self, formatter: &dyn MessageFormatter) -> String {
+        formatter.format(&self.message())
+    }
+}
+
 /// Factory for Parse Error.
 pub fn parse_error<S: AsRef<str>>(
     value: S,
@@ -74,8 +195,37 @@ impl serde::ser::Error for SerializationError {
 impl std::fmt::Debug for SerializationError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
-            SerializationError::Serde(ref s) => write!(fmt, "{self}: {s}"),
+            SerializationError::Serde(ref s) | SerializationError::UnknownParameter(ref s) => {
+                write!(fmt, "{self}: {s}")
+            }
             SerializationError::StructuralMismatch(s) => write!(fmt, "{self}: {s}"),
+            SerializationError::MissingParameters(ref names) => write!(fmt, "{self}: {names:?}"),
+            SerializationError::UnmatchedParameters {
+                ref unknown_keys,
+                ref missing,
+            } => write!(
+                fmt,
+                "{self}: unknown keys {unknown_keys:?}, missing parameters {missing:?}"
+            ),
+            SerializationError::ParameterCount { .. } => write!(fmt, "{self}"),
+            SerializationError::AtParameter {
+                ref name,
+                ref db_type,
+                field,
+                ref source,
+                ..
+            } => {
+                write!(fmt, "{self} (")?;
+                if let Some(name) = name {
+                    write!(fmt, "{name}, ")?;
+                }
+                write!(fmt, "SQL type {db_type}")?;
+                if let Some(field) = field {
+                    write!(fmt, ", field {field}")?;
+                }
+                write!(fmt, "): {source:?}")
+            }
+            SerializationError::AtRow { ref source, .. } => write!(fmt, "{self}: {source:?}"),
             SerializationError::Parse {
                 ref value,
                 ref db_type,
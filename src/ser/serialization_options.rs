@@ -0,0 +1,88 @@
+/// Options that control how rust values are converted into database values.
+///
+/// The plain [`to_params()`](fn.to_params.html) uses the default options;
+/// use [`to_params_with()`](fn.to_params_with.html) or
+/// [`to_named_params_with()`](fn.to_named_params_with.html) to apply other options.
+///
+/// ```rust
+/// use serde_db::ser::{EnumRepresentation, SerializationOptions};
+/// let options = SerializationOptions::default().with_enum_representation(EnumRepresentation::Index);
+/// assert_eq!(options.enum_representation(), Some(EnumRepresentation::Index));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SerializationOptions {
+    enum_representation: Option<EnumRepresentation>,
+    none_policy: NonePolicy,
+}
+
+impl SerializationOptions {
+    /// Sets how unit variants of enums are converted, for all parameters.
+    ///
+    /// Without this option, the parameter descriptor decides, with
+    /// [`DbvFactory::enum_representation()`](trait.DbvFactory.html#method.enum_representation),
+    /// and variants are converted by name if the descriptor has no preference.
+    #[must_use]
+    pub fn with_enum_representation(mut self, enum_representation: EnumRepresentation) -> Self {
+        self.enum_representation = Some(enum_representation);
+        self
+    }
+
+    /// Returns how unit variants of enums are converted, if set.
+    #[must_use]
+    pub fn enum_representation(&self) -> Option<EnumRepresentation> {
+        self.enum_representation
+    }
+
+    /// Sets how `None` values are converted.
+    #[must_use]
+    pub fn with_none_policy(mut self, none_policy: NonePolicy) -> Self {
+        self.none_policy = none_policy;
+        self
+    }
+
+    /// Returns how `None` values are converted.
+    #[must_use]
+    pub fn none_policy(&self) -> NonePolicy {
+        self.none_policy
+    }
+}
+
+/// Describes how a unit variant of an enum, like `Status::Active`, is converted
+/// into a database value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumRepresentation {
+    /// The name of the variant is converted as a string, e.g. `"Active"`.
+    ///
+    /// Renamings with `#[serde(rename = "...")]` are applied.
+    Name,
+    /// The position of the variant in the enum declaration, starting with 0,
+    /// is converted as a `u32`.
+    ///
+    /// Note that serde does not provide explicit discriminants like `Active = 10`;
+    /// enums with explicit discriminants should be serialized by name or with
+    /// `serde_repr`.
+    Index,
+}
+
+/// Describes how `Option::None` values are converted into database values.
+///
+/// ```rust
+/// use serde_db::ser::{NonePolicy, SerializationOptions};
+/// let options = SerializationOptions::default().with_none_policy(NonePolicy::Skip);
+/// assert_eq!(options.none_policy(), NonePolicy::Skip);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NonePolicy {
+    /// `None` values are bound as NULL, with
+    /// [`DbvFactory::serialize_none()`](trait.DbvFactory.html#tymethod.serialize_none);
+    /// non-nullable parameters produce an error.
+    #[default]
+    Null,
+    /// `None` values skip the parameter, so that the database applies the default value of
+    /// the column; the value for the parameter is produced with
+    /// [`DbvFactory::serialize_default()`](trait.DbvFactory.html#method.serialize_default),
+    /// which is only supported by drivers that can bind default values.
+    ///
+    /// The positions of the other values do not change.
+    Skip,
+}
@@ -1,25 +1,81 @@
+use super::byte_serializer::ByteSerializer;
 use super::dbv_factory::DbvFactory;
+use super::lob_stream::{take_pending_reader, LOB_PARAMETER};
 use super::type_error;
-use super::SerializationError;
+use super::{EnumRepresentation, NonePolicy, SerializationError, SerializationOptions};
+#[cfg(feature = "chrono")]
+use super::{Temporal, TemporalEncoding};
 #[cfg(feature = "trace")]
 use log::{log_enabled, trace};
 
 type SerializationResult<T> = Result<T, SerializationError>;
 
+// The struct field that provided a value, and whether the value was an `Option`.
+pub type ValueContext = (Option<&'static str>, bool);
+
 /// A structure for serializing Rust values into a parameter row for a prepared statement.
 #[allow(missing_debug_implementations)]
 pub struct Serializer<'m, DF: 'm + DbvFactory> {
     output: Vec<DF::DBV>,
     metadata: &'m mut dyn std::iter::Iterator<Item = DF>,
+    options: &'m SerializationOptions,
+    first_index: usize,
+    // The nesting level of the current value; sequences below the top level are bytes.
+    depth: usize,
+    // The struct field whose value is being serialized.
+    field: Option<&'static str>,
+    // True while the content of a `Some` is being serialized.
+    optional: bool,
+    // The field and the optionality of each converted value, if parameters are inferred.
+    contexts: Option<Vec<ValueContext>>,
 }
 
 impl<'m, DF: DbvFactory> Serializer<'m, DF> {
-    pub fn new(metadata: &'m mut dyn std::iter::Iterator<Item = DF>) -> Self {
+    pub fn new(
+        metadata: &'m mut dyn std::iter::Iterator<Item = DF>,
+        options: &'m SerializationOptions,
+    ) -> Self {
         Serializer {
             output: Vec::<DF::DBV>::new(),
             metadata,
+            options,
+            first_index: 0,
+            depth: 0,
+            field: None,
+            optional: false,
+            contexts: None,
         }
     }
+
+    // Creates a serializer for the value of a single parameter, which has the given index
+    // in the statement.
+    pub fn new_at(
+        metadata: &'m mut dyn std::iter::Iterator<Item = DF>,
+        options: &'m SerializationOptions,
+        first_index: usize,
+    ) -> Self {
+        Serializer {
+            output: Vec::<DF::DBV>::new(),
+            metadata,
+            options,
+            first_index,
+            depth: 1,
+            field: None,
+            optional: false,
+            contexts: None,
+        }
+    }
+
+    // Creates a serializer that records the context of each value.
+    pub fn for_inference(
+        metadata: &'m mut dyn std::iter::Iterator<Item = DF>,
+        options: &'m SerializationOptions,
+    ) -> Self {
+        let mut serializer = Self::new(metadata, options);
+        serializer.contexts = Some(Vec::new());
+        serializer
+    }
+
     fn get_current_field(&mut self) -> SerializationResult<DF> {
         match self.metadata.next() {
             Some(df) => Ok(df),
@@ -29,13 +85,80 @@ impl<'m, DF: DbvFactory> Serializer<'m, DF> {
         }
     }
 
-    fn push(&mut self, value: DF::DBV) {
+    // Converts a value with the next parameter, and adds the parameter's index and name
+    // to conversion errors.
+    fn convert<F>(&mut self, f: F) -> SerializationResult<()>
+    where
+        F: FnOnce(&DF) -> SerializationResult<DF::DBV>,
+    {
+        let df = self.get_current_field()?;
+        let value = f(&df).map_err(|e| SerializationError::AtParameter {
+            index: self.first_index + self.output.len(),
+            name: df.name().map(ToString::to_string),
+            db_type: df.descriptor(),
+            field: self.field,
+            source: Box::new(e),
+        })?;
         self.output.push(value);
+        if let Some(ref mut contexts) = self.contexts {
+            contexts.push((self.field, self.optional));
+        }
+        Ok(())
+    }
+
+    // Converts a missing value into NULL; non-nullable parameters produce an error.
+    fn convert_null(&mut self) -> SerializationResult<()> {
+        self.convert(|df| match df.is_nullable() {
+            Some(false) => Err(type_error("none", df.descriptor())),
+            _ => df.serialize_none(),
+        })
+    }
+
+    // Serializes a single value, with the parameter context for its errors.
+    pub fn serialize_value<T: ?Sized + serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> SerializationResult<()> {
+        value.serialize(&mut *self).map_err(|e| self.add_context(e))
+    }
+
+    // Adds the parameter context to errors that the Serialize implementation of a value
+    // produced before the value reached a parameter.
+    fn add_context(&mut self, e: SerializationError) -> SerializationError {
+        if !matches!(e, SerializationError::Serde(_)) {
+            return e;
+        }
+        match self.metadata.next() {
+            Some(df) => SerializationError::AtParameter {
+                index: self.first_index + self.output.len(),
+                name: df.name().map(ToString::to_string),
+                db_type: df.descriptor(),
+                field: self.field,
+                source: Box::new(e),
+            },
+            None => e,
+        }
+    }
+
+    // Fails if the metadata contain parameters for which no value was provided.
+    pub fn check_completeness(&mut self) -> SerializationResult<()> {
+        let missing = self.metadata.count();
+        if missing > 0 {
+            return Err(SerializationError::ParameterCount {
+                expected: self.output.len() + missing,
+                provided: self.output.len(),
+            });
+        }
+        Ok(())
     }
 
     pub fn into_inner(self) -> Vec<DF::DBV> {
         self.output
     }
+
+    pub fn into_inferred(self) -> (Vec<DF::DBV>, Vec<ValueContext>) {
+        (self.output, self.contexts.unwrap_or_default())
+    }
 }
 
 impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF> {
@@ -52,97 +175,73 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     fn serialize_bool(self, value: bool) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_bool()");
-        let val = self.get_current_field()?.serialize_bool(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_bool(value))
     }
 
     fn serialize_i8(self, value: i8) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_i8()");
-        let val = self.get_current_field()?.serialize_i8(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_i8(value))
     }
 
     fn serialize_i16(self, value: i16) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_i16()");
-        let val = self.get_current_field()?.serialize_i16(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_i16(value))
     }
 
     fn serialize_i32(self, value: i32) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
-        trace!("Serializer::serialize_i32() for {}", value);
-        let val = self.get_current_field()?.serialize_i32(value)?;
-        self.push(val);
-        Ok(())
+        trace!("Serializer::serialize_i32() for {value}");
+        self.convert(|df| df.serialize_i32(value))
     }
 
     fn serialize_i64(self, value: i64) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_i64()");
-        let val = self.get_current_field()?.serialize_i64(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_i64(value))
     }
 
     fn serialize_u8(self, value: u8) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_u8()");
-        let val = self.get_current_field()?.serialize_u8(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_u8(value))
     }
 
     fn serialize_u16(self, value: u16) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_u16()");
-        let val = self.get_current_field()?.serialize_u16(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_u16(value))
     }
 
     fn serialize_u32(self, value: u32) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_u32()");
-        let val = self.get_current_field()?.serialize_u32(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_u32(value))
     }
 
     fn serialize_u64(self, value: u64) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_u64()");
-        let val = self.get_current_field()?.serialize_u64(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_u64(value))
     }
 
     fn serialize_f32(self, value: f32) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_f32()");
-        let val = self.get_current_field()?.serialize_f32(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_f32(value))
     }
 
     fn serialize_f64(self, value: f64) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_f64()");
-        let val = self.get_current_field()?.serialize_f64(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_f64(value))
     }
 
     fn serialize_char(self, value: char) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_char()");
-        let val = self.get_current_field()?.serialize_char(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| df.serialize_char(value))
     }
 
     fn serialize_str(self, value: &str) -> SerializationResult<Self::Ok> {
@@ -150,7 +249,7 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
         if log_enabled!(log::Level::Debug) {
             let l = value.len();
             if l < 100 {
-                trace!("Serializer::serialize_str() with {}", value);
+                trace!("Serializer::serialize_str() with {value}");
             } else {
                 trace!(
                     "Serializer::serialize_str() with {}..{}",
@@ -159,57 +258,87 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
                 );
             }
         }
-        let val = self.get_current_field()?.serialize_str(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| {
+            #[cfg(feature = "chrono")]
+            if let Some(encoding) = df.temporal_encoding() {
+                if let Some(temporal) = Temporal::parse(value) {
+                    return match encoding {
+                        TemporalEncoding::Format(format) => {
+                            let formatted = temporal.format(&format, df.descriptor())?;
+                            check_length(df, "str", formatted.chars().count())?;
+                            df.serialize_str(&formatted)
+                        }
+                        TemporalEncoding::Native => df.serialize_temporal(temporal),
+                    };
+                }
+            }
+            check_length(df, "str", value.chars().count())?;
+            df.serialize_str(value)
+        })
     }
 
     fn serialize_bytes(self, value: &[u8]) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_bytes()");
-        let val = self.get_current_field()?.serialize_bytes(value)?;
-        self.push(val);
-        Ok(())
+        self.convert(|df| {
+            check_length(df, "bytes", value.len())?;
+            df.serialize_bytes(value)
+        })
     }
 
     fn serialize_unit(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_unit()");
-        let val = self.get_current_field()?.serialize_none()?;
-        self.push(val);
-        Ok(())
+        self.convert_null()
     }
 
     fn serialize_unit_struct(self, _name: &'static str) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_unit_struct()");
-        Err(type_error(
-            "unit_struct",
-            self.get_current_field()?.descriptor(),
-        ))
+        self.convert(|df| Err(type_error("unit_struct", df.descriptor())))
     }
 
     fn serialize_unit_variant(
         self,
         _name: &'static str,
-        _variant_index: u32,
-        _variant: &'static str,
+        variant_index: u32,
+        variant: &'static str,
     ) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
-        trace!("Serializer::serialize_unit_variant()");
-        Err(type_error(
-            "unit_variant",
-            self.get_current_field()?.descriptor(),
-        ))
+        trace!("Serializer::serialize_unit_variant() for {variant}");
+        let enum_representation = self.options.enum_representation();
+        self.convert(|df| {
+            match enum_representation
+                .or_else(|| df.enum_representation())
+                .unwrap_or(EnumRepresentation::Name)
+            {
+                EnumRepresentation::Name => {
+                    check_length(df, "str", variant.chars().count())?;
+                    df.serialize_str(variant)
+                }
+                EnumRepresentation::Index => df.serialize_u32(variant_index),
+            }
+        })
     }
 
     fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_newtype_struct()");
+        if name == LOB_PARAMETER {
+            // the reader is taken only when a parameter exists for it,
+            // otherwise it stays with the LobStream
+            return self.convert(|df| {
+                let reader =
+                    take_pending_reader().ok_or(SerializationError::StructuralMismatch(
+                        "the reader of the LobStream is missing",
+                    ))?;
+                df.serialize_lob(reader)
+            });
+        }
         value.serialize(self)
     }
 
@@ -228,9 +357,11 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     fn serialize_none(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_none()");
-        let val = self.get_current_field()?.serialize_none()?;
-        self.push(val);
-        Ok(())
+        // Only an explicit `None` is affected by the policy, a unit value is always NULL.
+        match self.options.none_policy() {
+            NonePolicy::Null => self.convert_null(),
+            NonePolicy::Skip => self.convert(DbvFactory::serialize_default),
+        }
     }
 
     fn serialize_some<T: ?Sized + serde::Serialize>(
@@ -239,19 +370,28 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_some()");
-        value.serialize(self)
+        let outer = std::mem::replace(&mut self.optional, true);
+        let result = value.serialize(&mut *self);
+        self.optional = outer;
+        result
     }
 
-    fn serialize_seq(self, _len: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
+    fn serialize_seq(self, len: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_seq()");
-        Ok(Compound { ser: self })
+        // the top level sequence provides the parameters,
+        // nested sequences like Vec<u8> are binary values
+        let bytes = (self.depth > 0).then(|| Vec::with_capacity(len.unwrap_or(0)));
+        Ok(Compound { ser: self, bytes })
     }
 
     fn serialize_tuple(self, _len: usize) -> SerializationResult<Self::SerializeTuple> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_tuple()");
-        Ok(Compound { ser: self })
+        Ok(Compound {
+            ser: self,
+            bytes: None,
+        })
     }
 
     fn serialize_tuple_struct(
@@ -261,7 +401,10 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::SerializeTupleStruct> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_tuple_struct()");
-        Ok(Compound { ser: self })
+        Ok(Compound {
+            ser: self,
+            bytes: None,
+        })
     }
 
     fn serialize_tuple_variant(
@@ -273,13 +416,19 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::SerializeTupleVariant> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_tuple_variant()");
-        Ok(Compound { ser: self })
+        Ok(Compound {
+            ser: self,
+            bytes: None,
+        })
     }
 
     fn serialize_map(self, _len: Option<usize>) -> SerializationResult<Self::SerializeMap> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_map()");
-        Ok(Compound { ser: self })
+        Ok(Compound {
+            ser: self,
+            bytes: None,
+        })
     }
 
     fn serialize_struct(
@@ -289,7 +438,10 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::SerializeStruct> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_struct()");
-        Ok(Compound { ser: self })
+        Ok(Compound {
+            ser: self,
+            bytes: None,
+        })
     }
 
     fn serialize_struct_variant(
@@ -305,6 +457,20 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     }
 }
 
+// Fails if the value is longer than the parameter allows.
+fn check_length<DF: DbvFactory>(
+    df: &DF,
+    value_type: &'static str,
+    length: usize,
+) -> SerializationResult<()> {
+    match df.max_length() {
+        Some(max_length) if length > max_length => {
+            Err(SerializationError::Range(value_type, df.descriptor()))
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(feature = "trace")]
 fn head(count: usize, s: &str) -> String {
     let head: String = s.chars().take(count).collect();
@@ -321,9 +487,23 @@ fn tail(count: usize, s: &str) -> String {
 #[allow(missing_debug_implementations)]
 pub struct Compound<'a, 'm: 'a, DF: 'm + DbvFactory> {
     ser: &'a mut Serializer<'m, DF>,
+    // The collected elements of a nested sequence.
+    bytes: Option<Vec<u8>>,
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeSeq for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> Compound<'_, 'm, DF> {
+    fn serialize_nested<T: ?Sized + serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> SerializationResult<()> {
+        self.ser.depth += 1;
+        let result = self.ser.serialize_value(value);
+        self.ser.depth -= 1;
+        result
+    }
+}
+
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeSeq for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -333,18 +513,34 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeSeq for Compound<'a, 'm,
     ) -> SerializationResult<()> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeSeq::serialize_element()");
-        let t: &mut Serializer<DF> = self.ser;
-        value.serialize(t)
+        if let Some(ref mut bytes) = self.bytes {
+            match value.serialize(ByteSerializer) {
+                Ok(byte) => {
+                    bytes.push(byte);
+                    return Ok(());
+                }
+                // sequences of other values are flattened into consecutive parameters
+                Err(_) if bytes.is_empty() => self.bytes = None,
+                Err(e) => return Err(e),
+            }
+        }
+        self.serialize_nested(value)
     }
 
     fn end(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeSeq::end()");
-        Ok(())
+        match self.bytes {
+            Some(bytes) => self.ser.convert(|df| {
+                check_length(df, "bytes", bytes.len())?;
+                df.serialize_bytes(&bytes)
+            }),
+            None => Ok(()),
+        }
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTuple for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeTuple for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -364,7 +560,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTuple for Compound<'a, 'm
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleStruct for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeTupleStruct for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -384,7 +580,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleStruct for Compound<
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleVariant for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeTupleVariant for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -404,7 +600,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleVariant for Compound
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -420,8 +616,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'a, 'm,
     ) -> SerializationResult<()> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeMap::serialize_value()");
-        let t: &mut Serializer<DF> = self.ser;
-        value.serialize(t)
+        self.serialize_nested(value)
     }
 
     fn end(self) -> SerializationResult<Self::Ok> {
@@ -431,7 +626,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'a, 'm,
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -443,7 +638,10 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'a, '
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeStruct::serialize_field()");
         serde::ser::SerializeMap::serialize_key(self, key)?;
-        serde::ser::SerializeMap::serialize_value(self, value)
+        let outer = self.ser.field.replace(key);
+        let result = serde::ser::SerializeMap::serialize_value(self, value);
+        self.ser.field = outer;
+        result
     }
 
     fn end(self) -> SerializationResult<Self::Ok> {
@@ -453,7 +651,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'a, '
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStructVariant for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeStructVariant for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -0,0 +1,82 @@
+use super::{type_error, SerializationError};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use std::fmt::Write;
+
+/// A temporal value, as recognized in the textual representation that
+/// `chrono` produces when such values are serialized.
+///
+/// ```rust
+/// use serde_db::ser::Temporal;
+/// assert!(matches!(Temporal::parse("2012-12-12"), Some(Temporal::Date(_))));
+/// assert!(matches!(Temporal::parse("12:12:12.5"), Some(Temporal::Time(_))));
+/// assert!(matches!(Temporal::parse("2012-12-12T12:12:12"), Some(Temporal::DateTime(_))));
+/// assert!(matches!(
+///     Temporal::parse("2012-12-12T12:12:12+02:00"),
+///     Some(Temporal::DateTimeWithOffset(_))
+/// ));
+/// assert!(Temporal::parse("December 12").is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Temporal {
+    /// A date, like `2012-12-12`.
+    Date(NaiveDate),
+    /// A time of day, like `12:12:12`.
+    Time(NaiveTime),
+    /// A date and time without a timezone, like `2012-12-12T12:12:12`.
+    DateTime(NaiveDateTime),
+    /// A date and time with an offset from UTC, like `2012-12-12T12:12:12Z`
+    /// or `2012-12-12T12:12:12+02:00`.
+    DateTimeWithOffset(DateTime<FixedOffset>),
+}
+
+impl Temporal {
+    /// Parses ISO 8601 dates, times, and timestamps, with or without an offset.
+    ///
+    /// Returns `None` for other strings.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Temporal> {
+        if let Ok(ts) = DateTime::parse_from_rfc3339(s) {
+            Some(Temporal::DateTimeWithOffset(ts))
+        } else if let Ok(ts) = s.parse::<NaiveDateTime>() {
+            Some(Temporal::DateTime(ts))
+        } else if let Ok(date) = s.parse::<NaiveDate>() {
+            Some(Temporal::Date(date))
+        } else if let Ok(time) = s.parse::<NaiveTime>() {
+            Some(Temporal::Time(time))
+        } else {
+            None
+        }
+    }
+
+    // Formats the value with the given format string.
+    pub(crate) fn format(
+        &self,
+        format: &str,
+        db_type: String,
+    ) -> Result<String, SerializationError> {
+        let mut s = String::new();
+        match *self {
+            Temporal::Date(date) => write!(s, "{}", date.format(format)),
+            Temporal::Time(time) => write!(s, "{}", time.format(format)),
+            Temporal::DateTime(ts) => write!(s, "{}", ts.format(format)),
+            Temporal::DateTimeWithOffset(ts) => write!(s, "{}", ts.format(format)),
+        }
+        .map_err(|_| type_error("temporal", db_type))?;
+        Ok(s)
+    }
+}
+
+/// Describes how a parameter expects temporal values.
+///
+/// See [`DbvFactory::temporal_encoding()`](trait.DbvFactory.html#method.temporal_encoding).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemporalEncoding {
+    /// The value is reformatted with the given `chrono` format string, like `"%Y%m%d"`,
+    /// and handed over to
+    /// [`DbvFactory::serialize_str()`](trait.DbvFactory.html#tymethod.serialize_str).
+    Format(String),
+    /// The parsed value is handed over to
+    /// [`DbvFactory::serialize_temporal()`](trait.DbvFactory.html#method.serialize_temporal),
+    /// for drivers with native temporal types.
+    Native,
+}
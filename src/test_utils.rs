@@ -0,0 +1,43 @@
+//! A simple in-memory database driver, which implements the traits of `serde_db`.
+//!
+//! The driver is used by the tests of `serde_db` itself, and serves driver authors
+//! as a known-good reference implementation: they can compare their implementations of
+//! [`DbValue`](../de/trait.DbValue.html),
+//! [`DeserializableRow`](../de/trait.DeserializableRow.html),
+//! [`DeserializableResultset`](../de/trait.DeserializableResultset.html), and
+//! [`DbvFactory`](../ser/trait.DbvFactory.html) with the ones of this module,
+//! and run their tests against both.
+//!
+//! Requires feature `test-utils`.
+//!
+//! ```rust
+//! use serde_db::test_utils::{MValue, Resultset};
+//!
+//! let mut rs = Resultset::new(&["ID", "NAME"]);
+//! rs.push(vec![MValue::new_bigint(1), MValue::new_string("Ann".to_string())]);
+//! rs.push(vec![MValue::new_bigint(2), MValue::new_string("Bob".to_string())]);
+//!
+//! let data: Vec<(i64, String)> = rs.try_into().unwrap();
+//! assert_eq!(data[1].1, "Bob");
+//! ```
+
+mod chunked_resultset;
+mod error;
+mod fieldnames;
+mod multi_resultset;
+mod mvalue;
+mod output_parameters;
+mod parameter_type;
+mod resultset;
+mod row;
+mod serde_db_impl;
+
+pub use self::chunked_resultset::ChunkedResultset;
+pub use self::error::{Error, Result};
+pub use self::fieldnames::Fieldnames;
+pub use self::multi_resultset::MultiResultset;
+pub use self::mvalue::{MValue, Timestamp};
+pub use self::output_parameters::OutputParameters;
+pub use self::parameter_type::{Parameter, ParameterType};
+pub use self::resultset::Resultset;
+pub use self::row::Row;
@@ -0,0 +1,36 @@
+use crate::test_utils;
+
+/// A resultset whose rows are fetched asynchronously, in chunks of a given size.
+///
+/// Implements [`ChunkedResultset`](../de/trait.ChunkedResultset.html).
+#[derive(Debug)]
+pub struct ChunkedResultset {
+    rs: test_utils::Resultset,
+    chunk_size: usize,
+    fetched: bool,
+}
+impl ChunkedResultset {
+    /// Delivers the rows of the given resultset in chunks of `chunk_size` rows.
+    #[must_use]
+    pub fn new(rs: test_utils::Resultset, chunk_size: usize) -> ChunkedResultset {
+        ChunkedResultset {
+            rs,
+            chunk_size,
+            fetched: false,
+        }
+    }
+
+    /// Simulates the asynchronous fetch: every chunk is only available at the second poll,
+    /// before that `None` is returned.
+    pub fn poll_fetch(&mut self) -> Option<Option<Vec<test_utils::Row>>> {
+        if !self.fetched {
+            self.fetched = true;
+            return None;
+        }
+        self.fetched = false;
+        let chunk: Vec<test_utils::Row> = std::iter::from_fn(|| self.rs.next())
+            .take(self.chunk_size)
+            .collect();
+        Some(if chunk.is_empty() { None } else { Some(chunk) })
+    }
+}
@@ -1,15 +1,20 @@
-use serde_db::{de::DeserializationError, ser::SerializationError};
+use crate::{de::DeserializationError, ser::SerializationError};
 
+/// The result type of the mock driver.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The error type of the mock driver.
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub enum Error {
+    /// Deserialization failed.
     DESERIALIZATION(DeserializationError),
+    /// Serialization failed.
     SERIALIZATION(SerializationError),
 }
 
 impl std::error::Error for Error {
-    fn cause(&self) -> Option<&dyn std::error::Error> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
             Error::DESERIALIZATION(ref e) => Some(e),
             Error::SERIALIZATION(ref e) => Some(e),
@@ -20,8 +25,8 @@ impl std::error::Error for Error {
 impl std::fmt::Display for Error {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
-            Error::DESERIALIZATION(ref e) => write!(fmt, "{}", e),
-            Error::SERIALIZATION(ref e) => write!(fmt, "{}", e),
+            Error::DESERIALIZATION(ref e) => write!(fmt, "{e}"),
+            Error::SERIALIZATION(ref e) => write!(fmt, "{e}"),
         }
     }
 }
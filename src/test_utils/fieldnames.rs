@@ -0,0 +1,54 @@
+/// Metadata for the fields in a result row.
+#[derive(Debug)]
+pub struct Fieldnames {
+    fields: Vec<String>,
+    types: Vec<String>,
+    anonymous: bool,
+}
+impl Fieldnames {
+    /// Describes fields with the given names.
+    #[must_use]
+    pub fn new(fields: &[&'static str]) -> Fieldnames {
+        Fieldnames {
+            fields: fields.iter().map(|s| String::from(*s)).collect(),
+            types: Vec::new(),
+            anonymous: false,
+        }
+    }
+
+    /// Simulates a protocol without field names.
+    pub fn set_anonymous(&mut self) {
+        self.anonymous = true;
+    }
+
+    /// Adds type descriptors for the fields.
+    ///
+    /// # Panics
+    ///
+    /// If the number of types differs from the number of fields.
+    pub fn set_fieldtypes(&mut self, types: &[&'static str]) {
+        assert_eq!(self.fields.len(), types.len());
+        self.types = types.iter().map(|s| String::from(*s)).collect();
+    }
+
+    /// Returns the number of fields.
+    #[must_use]
+    pub fn number_of_fields(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns the name of the ith field, unless the fields are anonymous.
+    #[must_use]
+    pub fn fieldname(&self, i: usize) -> Option<&str> {
+        if self.anonymous {
+            return None;
+        }
+        self.fields.get(i).map(String::as_str)
+    }
+
+    /// Returns the type descriptor of the ith field, if types were set.
+    #[must_use]
+    pub fn fieldtype(&self, i: usize) -> Option<&str> {
+        self.types.get(i).map(String::as_str)
+    }
+}
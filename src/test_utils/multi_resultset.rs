@@ -0,0 +1,52 @@
+use crate::de::{DeserializableMultiResultset, DeserializationOptions};
+use std::collections::VecDeque;
+
+use crate::test_utils;
+
+/// The resultsets of a statement that returns several of them.
+#[derive(Debug, Default)]
+pub struct MultiResultset(VecDeque<test_utils::Resultset>);
+impl MultiResultset {
+    /// Creates an empty instance.
+    #[must_use]
+    pub fn new() -> MultiResultset {
+        MultiResultset::default()
+    }
+
+    /// Appends a resultset.
+    pub fn push(&mut self, rs: test_utils::Resultset) {
+        self.0.push_back(rs);
+    }
+
+    /// Removes and returns the next resultset.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<test_utils::Resultset> {
+        self.0.pop_front()
+    }
+
+    /// Converts the resultsets into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into<'de, T>(self) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("MultiResultset::try_into()");
+        DeserializableMultiResultset::try_into(self)
+    }
+
+    /// Converts the resultsets into a rust value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("MultiResultset::try_into_with()");
+        DeserializableMultiResultset::try_into_with(self, options)
+    }
+}
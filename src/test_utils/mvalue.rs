@@ -0,0 +1,180 @@
+use crate::de::{DbInterval, DbValue, DecimalParts, DeserializationOptions};
+use crate::test_utils;
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+/// A timestamp value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timestamp(pub NaiveDateTime);
+
+impl std::fmt::Display for Timestamp {
+    // The format chosen supports the derserialization to chrono types.
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            fmt,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:07}",
+            self.0.year(),
+            self.0.month(),
+            self.0.day(),
+            self.0.hour(),
+            self.0.minute(),
+            self.0.second(),
+            self.0.nanosecond() * 100
+        )
+    }
+}
+
+/// A database value of the mock driver.
+///
+/// Implements [`DbValue`](../de/trait.DbValue.html), including most of its
+/// optional extension points.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MValue {
+    /// NULL.
+    Null,
+    /// A 16-bit integer.
+    Short(i16),
+    /// A 64-bit integer.
+    BigInt(i64),
+    /// A floating point number.
+    Double(f64),
+    /// A decimal number.
+    Decimal(DecimalParts),
+    /// A variable-width string.
+    String(String),
+    /// A fixed-width string, padded with blanks.
+    Char(String),
+    /// A JSON document.
+    Json(String),
+    /// Text in an unknown encoding.
+    RawText(Vec<u8>),
+    /// A large object.
+    Lob(Vec<u8>),
+    /// A large object whose chunks are fetched asynchronously.
+    AsyncLob(Vec<Vec<u8>>),
+    /// An array.
+    Array(Vec<MValue>),
+    /// A nested resultset, with field names and rows.
+    Nested(Vec<String>, Vec<Vec<MValue>>),
+    /// A timestamp without timezone.
+    Timestamp(Timestamp),
+    /// An interval.
+    Interval(DbInterval),
+}
+
+impl MValue {
+    /// Creates a NULL value.
+    #[must_use]
+    pub fn new_null() -> MValue {
+        MValue::Null
+    }
+    /// Creates a 16-bit integer.
+    #[must_use]
+    pub fn new_short(i: i16) -> MValue {
+        MValue::Short(i)
+    }
+    /// Creates a 64-bit integer.
+    #[must_use]
+    pub fn new_bigint(i: i64) -> MValue {
+        MValue::BigInt(i)
+    }
+    /// Creates a floating point number.
+    #[must_use]
+    pub fn new_double(f: f64) -> MValue {
+        MValue::Double(f)
+    }
+    /// Creates a decimal number with value `mantissa * 10^exponent`.
+    #[must_use]
+    pub fn new_decimal(mantissa: i128, exponent: i32) -> MValue {
+        MValue::Decimal(DecimalParts::new(mantissa, exponent))
+    }
+    /// Creates a variable-width string.
+    #[must_use]
+    pub fn new_string(s: String) -> MValue {
+        MValue::String(s)
+    }
+    /// Creates a fixed-width string, padded with blanks to `width`.
+    #[must_use]
+    pub fn new_char(s: &str, width: usize) -> MValue {
+        MValue::Char(format!("{s:width$}"))
+    }
+    /// Creates a JSON document.
+    #[must_use]
+    pub fn new_json(s: &str) -> MValue {
+        MValue::Json(s.to_string())
+    }
+    /// Creates text in an unknown encoding.
+    #[must_use]
+    pub fn new_raw_text(bytes: &[u8]) -> MValue {
+        MValue::RawText(bytes.to_vec())
+    }
+    /// Creates a large object.
+    #[must_use]
+    pub fn new_lob(bytes: Vec<u8>) -> MValue {
+        MValue::Lob(bytes)
+    }
+    /// Creates a large object whose chunks are fetched asynchronously.
+    #[must_use]
+    pub fn new_async_lob(chunks: Vec<Vec<u8>>) -> MValue {
+        MValue::AsyncLob(chunks)
+    }
+    /// Creates an array.
+    #[must_use]
+    pub fn new_array(elements: Vec<MValue>) -> MValue {
+        MValue::Array(elements)
+    }
+    /// Creates a nested resultset.
+    #[must_use]
+    pub fn new_nested(fields: &[&str], rows: Vec<Vec<MValue>>) -> MValue {
+        MValue::Nested(fields.iter().map(ToString::to_string).collect(), rows)
+    }
+    /// Creates a timestamp.
+    #[must_use]
+    pub fn new_ts(ts: NaiveDateTime) -> MValue {
+        MValue::Timestamp(Timestamp(ts))
+    }
+    /// Creates an interval.
+    #[must_use]
+    pub fn new_interval(months: i32, days: i32, microseconds: i64) -> MValue {
+        MValue::Interval(DbInterval::new(months, days, microseconds))
+    }
+
+    /// Returns the number of bytes of the content of texts, binary values, and
+    /// the elements of arrays and nested resultsets.
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        match *self {
+            MValue::String(ref s) | MValue::Char(ref s) | MValue::Json(ref s) => s.len(),
+            MValue::RawText(ref bytes) | MValue::Lob(ref bytes) => bytes.len(),
+            MValue::AsyncLob(ref chunks) => chunks.iter().map(Vec::len).sum(),
+            MValue::Array(ref elements) => elements.iter().map(MValue::byte_len).sum(),
+            MValue::Nested(_, ref rows) => rows.iter().flatten().map(MValue::byte_len).sum(),
+            _ => 0,
+        }
+    }
+
+    /// Converts the value into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into<'de, T>(self) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("MValue::try_into()");
+        Ok(DbValue::try_into(self)?)
+    }
+
+    /// Converts the value into a rust value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("MValue::try_into_with()");
+        Ok(DbValue::try_into_with(self, options)?)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::de::{DeserializableOutputParameters, DeserializationOptions};
+use std::collections::VecDeque;
+
+use crate::test_utils;
+
+/// The output parameters of a procedure call.
+#[derive(Debug, Default)]
+pub struct OutputParameters {
+    names: Vec<String>,
+    values: VecDeque<test_utils::MValue>,
+}
+impl OutputParameters {
+    /// Creates an empty instance.
+    #[must_use]
+    pub fn new() -> OutputParameters {
+        OutputParameters::default()
+    }
+
+    /// Appends a parameter.
+    pub fn push(&mut self, name: &str, value: test_utils::MValue) {
+        self.names.push(name.to_string());
+        self.values.push_back(value);
+    }
+
+    /// Returns the number of parameters.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns true if there are no parameters.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns the name of the parameter with the given index.
+    #[must_use]
+    pub fn name(&self, idx: usize) -> Option<&str> {
+        self.names.get(idx).map(String::as_str)
+    }
+
+    /// Removes and returns the next value.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<test_utils::MValue> {
+        self.values.pop_front()
+    }
+
+    /// Converts the parameters into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into<'de, T>(self) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("OutputParameters::try_into()");
+        DeserializableOutputParameters::try_into(self)
+    }
+
+    /// Converts the parameters into a rust value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("OutputParameters::try_into_with()");
+        DeserializableOutputParameters::try_into_with(self, options)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::test_utils::MValue;
+
+/// Types that are accepted as parameters in SQL commands.
+///
+/// `&ParameterType` implements [`DbvFactory`](../ser/trait.DbvFactory.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterType {
+    /// A 16-bit integer.
+    Short,
+    /// A 16-bit integer or NULL.
+    NullableShort,
+    /// A string.
+    String,
+    /// A string or NULL.
+    NullableString,
+    /// A timestamp, serialized from strings like `"2012-12-12T12:12:12"`.
+    Timestamp,
+    /// A timestamp or NULL.
+    NullableTimestamp,
+    /// A binary value, serialized from bytes into an `MValue::Lob`.
+    Binary,
+    /// A binary value or NULL.
+    NullableBinary,
+}
+
+/// A named parameter of an SQL command.
+///
+/// `&Parameter` implements [`DbvFactory`](../ser/trait.DbvFactory.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: String,
+    /// The type of the parameter.
+    pub ptype: ParameterType,
+    /// The maximum length of text values, if any.
+    pub max_length: Option<usize>,
+    /// The default value of the column, which is used for skipped parameters.
+    pub default: Option<MValue>,
+    /// The format in which a text parameter expects temporal values, if any.
+    pub temporal_format: Option<String>,
+}
+
+impl Parameter {
+    /// Creates a parameter with the given name and type.
+    #[must_use]
+    pub fn new(name: &str, ptype: ParameterType) -> Parameter {
+        Parameter {
+            name: name.to_string(),
+            ptype,
+            max_length: None,
+            default: None,
+            temporal_format: None,
+        }
+    }
+
+    /// Limits the length of text values.
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: usize) -> Parameter {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the default value of the column.
+    #[must_use]
+    pub fn with_default(mut self, default: MValue) -> Parameter {
+        self.default = Some(default);
+        self
+    }
+
+    /// Lets a text parameter expect temporal values in the given `chrono` format.
+    #[must_use]
+    pub fn with_temporal_format(mut self, format: &str) -> Parameter {
+        self.temporal_format = Some(format.to_string());
+        self
+    }
+}
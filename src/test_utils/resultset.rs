@@ -0,0 +1,155 @@
+use crate::de::{DeserializableResultset, DeserializationOptions};
+use std::sync::Arc;
+
+use crate::test_utils;
+
+/// A simple resultset for test purposes.
+///
+/// Rows are added using `push()`.
+#[derive(Clone, Debug)]
+pub struct Resultset {
+    next_rows: Vec<test_utils::Row>,
+    row_iter: <Vec<test_utils::Row> as IntoIterator>::IntoIter,
+    md: Arc<test_utils::Fieldnames>,
+}
+impl Resultset {
+    /// Creates an empty resultset with the given field names.
+    #[must_use]
+    pub fn new(fields: &[&'static str]) -> Resultset {
+        Resultset {
+            next_rows: Vec::<test_utils::Row>::new(),
+            row_iter: Vec::<test_utils::Row>::new().into_iter(),
+            md: Arc::new(test_utils::Fieldnames::new(fields)),
+        }
+    }
+
+    /// Adds type descriptors for the fields; must be called before rows are pushed.
+    ///
+    /// # Panics
+    ///
+    /// If rows were already pushed.
+    #[must_use]
+    pub fn with_fieldtypes(mut self, types: &[&'static str]) -> Resultset {
+        Arc::get_mut(&mut self.md)
+            .expect("with_fieldtypes() must be called before push()")
+            .set_fieldtypes(types);
+        self
+    }
+
+    /// Hides the field names; must be called before rows are pushed.
+    ///
+    /// # Panics
+    ///
+    /// If rows were already pushed.
+    #[must_use]
+    pub fn without_fieldnames(mut self) -> Resultset {
+        Arc::get_mut(&mut self.md)
+            .expect("without_fieldnames() must be called before push()")
+            .set_anonymous();
+        self
+    }
+
+    /// Appends a row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values differs from the number of fields.
+    pub fn push(&mut self, values: Vec<test_utils::MValue>) {
+        assert_eq!(self.md.number_of_fields(), values.len());
+        self.next_rows
+            .push(test_utils::Row::new(Arc::clone(&self.md), values));
+    }
+
+    /// Removes and returns the next row.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<test_utils::Row> {
+        if let Some(r) = self.row_iter.next() {
+            Some(r)
+        } else {
+            let mut tmp_vec = Vec::<test_utils::Row>::new();
+            std::mem::swap(&mut tmp_vec, &mut self.next_rows);
+            self.row_iter = tmp_vec.into_iter();
+            self.row_iter.next()
+        }
+    }
+
+    /// Removes the row with the given index from the not yet consumed rows.
+    pub fn take_row(&mut self, idx: usize) -> Option<test_utils::Row> {
+        if idx >= self.len() {
+            return None;
+        }
+        let mut rows: Vec<test_utils::Row> = std::mem::take(&mut self.row_iter).collect();
+        rows.append(&mut self.next_rows);
+        let row = rows.remove(idx);
+        self.row_iter = rows.into_iter();
+        Some(row)
+    }
+
+    /// Returns the number of not yet consumed rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.next_rows.len() + self.row_iter.as_slice().len()
+    }
+
+    /// Returns true if all rows are consumed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if more than one row is not yet consumed.
+    pub fn has_multiple_rows(&mut self) -> bool {
+        self.len() > 1
+    }
+
+    /// Returns the number of fields.
+    #[must_use]
+    pub fn number_of_fields(&self) -> usize {
+        self.md.number_of_fields()
+    }
+
+    /// Returns the name of the ith field.
+    #[must_use]
+    pub fn fieldname(&self, i: usize) -> Option<&str> {
+        self.md.fieldname(i)
+    }
+
+    /// Returns the type descriptor of the ith field.
+    #[must_use]
+    pub fn fieldtype(&self, i: usize) -> Option<&str> {
+        self.md.fieldtype(i)
+    }
+
+    /// Converts the resultset into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into<'de, T>(self) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("Resultset::try_into()");
+        DeserializableResultset::try_into(self)
+    }
+
+    /// Converts the resultset into a rust value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("Resultset::try_into_with()");
+        DeserializableResultset::try_into_with(self, options)
+    }
+}
+
+impl Iterator for Resultset {
+    type Item = test_utils::Row;
+    fn next(&mut self) -> Option<test_utils::Row> {
+        self.next()
+    }
+}
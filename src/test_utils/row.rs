@@ -0,0 +1,147 @@
+use crate::de::{DbValue, DeserializableRow, DeserializationError, DeserializationOptions};
+use std::sync::Arc;
+
+use crate::test_utils;
+
+/// A single line of a [`Resultset`](struct.Resultset.html).
+#[derive(Clone, Debug)]
+pub struct Row {
+    metadata: Arc<test_utils::Fieldnames>,
+    value_iter: <Vec<test_utils::MValue> as IntoIterator>::IntoIter,
+}
+
+impl Row {
+    /// Creates a row with the given metadata and values.
+    #[must_use]
+    pub fn new(
+        metadata: Arc<test_utils::Fieldnames>,
+        values: Vec<test_utils::MValue>,
+    ) -> test_utils::Row {
+        test_utils::Row {
+            metadata,
+            value_iter: values.into_iter(),
+        }
+    }
+
+    /// Returns a clone of the ith of the remaining values.
+    ///
+    /// # Errors
+    ///
+    /// Never.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such value.
+    pub fn cloned_value(&self, i: usize) -> Result<test_utils::MValue, DeserializationError> {
+        log::trace!("<test_utils::Row as DeserializableRow>::get()");
+        Ok(self.value_iter.as_slice()[i].clone())
+    }
+
+    /// Removes and converts the next field into a plain rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    ///
+    /// # Panics
+    ///
+    /// If there is no further value.
+    pub fn next_try_into<'de, T>(&mut self) -> Result<T, <test_utils::Row as DeserializableRow>::E>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("Row::next_try_into()");
+        Ok(DbValue::try_into(DeserializableRow::next(self).unwrap())?)
+    }
+
+    /// Clones and converts the specified field into a plain rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such value.
+    pub fn field_into<'de, T>(&self, i: usize) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("Row::field_into() for {:?}", self.value_iter.as_slice()[i]);
+        Ok(DbValue::try_into(self.cloned_value(i)?)?)
+    }
+
+    /// Removes and returns the next value.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<test_utils::MValue> {
+        log::trace!("test_utils::Row::next()");
+        self.value_iter.next()
+    }
+
+    /// Converts the complete row into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into<'de, T>(self) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("Row::try_into()");
+        DeserializableRow::try_into(self)
+    }
+
+    /// Converts the complete row into a rust value, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails.
+    pub fn try_into_with<'de, T>(self, options: &DeserializationOptions) -> test_utils::Result<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        log::trace!("Row::try_into_with()");
+        DeserializableRow::try_into_with(self, options)
+    }
+}
+
+impl DeserializableRow for test_utils::Row {
+    type V = test_utils::MValue;
+    type E = test_utils::Error;
+
+    fn len(&self) -> usize {
+        log::trace!("<test_utils::Row as DeserializableRow>::len()");
+        self.value_iter.as_slice().len()
+    }
+
+    fn next(&mut self) -> Option<test_utils::MValue> {
+        log::trace!("<test_utils::Row as DeserializableRow>::next()");
+        self.next()
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.metadata.number_of_fields()
+    }
+
+    fn fieldname(&self, field_idx: usize) -> Option<&str> {
+        log::trace!("<test_utils::Row as DeserializableRow>::fieldname()");
+        self.metadata.fieldname(field_idx)
+    }
+
+    fn byte_len(&self) -> Option<usize> {
+        Some(
+            self.value_iter
+                .as_slice()
+                .iter()
+                .map(test_utils::MValue::byte_len)
+                .sum(),
+        )
+    }
+}
+
+impl Iterator for test_utils::Row {
+    type Item = test_utils::MValue;
+    fn next(&mut self) -> Option<test_utils::MValue> {
+        self.next()
+    }
+}
@@ -0,0 +1,345 @@
+use crate::de::{
+    AsyncLobReader, ChunkedResultset as SerdeDbChunkedResultset, ConversionError, DbInterval,
+    DbNumber, DbValue, DbValueInto, DecimalParts, DeserializableMultiResultset,
+    DeserializableOutputParameters, DeserializableResultset, DeserializationError, NestedResultset,
+    ValueKind,
+};
+use crate::test_utils;
+use crate::test_utils::{ChunkedResultset, MValue, MultiResultset, OutputParameters, Resultset};
+use std::collections::VecDeque;
+use std::task::{Context, Poll};
+
+fn not_implemented(s: &'static str) -> ConversionError {
+    ConversionError::ValueType(format!("{s} not implemented"))
+}
+
+impl DbValue for MValue {
+    fn is_null(&self) -> bool {
+        matches!(*self, MValue::Null)
+    }
+
+    fn decimal_parts(&self) -> Option<DecimalParts> {
+        match *self {
+            MValue::Decimal(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    fn value_kind(&self) -> ValueKind {
+        match *self {
+            MValue::Null => ValueKind::Null,
+            MValue::Short(_) | MValue::BigInt(_) => ValueKind::Int,
+            MValue::Double(_) => ValueKind::Float,
+            MValue::Decimal(_) => ValueKind::Decimal,
+            MValue::String(_) | MValue::Char(_) | MValue::RawText(_) => ValueKind::Text,
+            MValue::Timestamp(_) => ValueKind::Timestamp,
+            _ => ValueKind::Unknown,
+        }
+    }
+
+    fn interval(&self) -> Option<DbInterval> {
+        match *self {
+            MValue::Interval(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    fn number(&self) -> Option<DbNumber> {
+        match *self {
+            MValue::Short(i) => Some(DbNumber::Integer(i128::from(i))),
+            MValue::BigInt(i) => Some(DbNumber::Integer(i128::from(i))),
+            MValue::Double(f) => Some(DbNumber::Float(f)),
+            _ => None,
+        }
+    }
+
+    fn is_json(&self) -> bool {
+        matches!(*self, MValue::Json(_))
+    }
+
+    fn is_raw_text(&self) -> bool {
+        matches!(*self, MValue::RawText(_))
+    }
+
+    fn is_fixed_width_text(&self) -> bool {
+        matches!(*self, MValue::Char(_))
+    }
+
+    fn is_array(&self) -> bool {
+        matches!(*self, MValue::Array(_))
+    }
+
+    fn into_array(self) -> Result<Vec<MValue>, ConversionError> {
+        match self {
+            MValue::Array(elements) => Ok(elements),
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} is not an array"
+            ))),
+        }
+    }
+
+    fn is_nested_resultset(&self) -> bool {
+        matches!(*self, MValue::Nested(..))
+    }
+
+    fn into_nested_resultset(self) -> Result<NestedResultset<MValue>, ConversionError> {
+        match self {
+            MValue::Nested(fields, rows) => {
+                let mut nested = NestedResultset::new(fields);
+                for row in rows {
+                    nested.push(row);
+                }
+                Ok(nested)
+            }
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} is not a nested resultset"
+            ))),
+        }
+    }
+
+    fn is_lob(&self) -> bool {
+        matches!(*self, MValue::Lob(_))
+    }
+
+    fn into_lob_reader(self) -> Result<Box<dyn std::io::Read>, ConversionError> {
+        match self {
+            MValue::Lob(bytes) => Ok(Box::new(std::io::Cursor::new(bytes))),
+            mv => Err(ConversionError::ValueType(format!("{mv:?} is not a LOB"))),
+        }
+    }
+
+    fn is_async_lob(&self) -> bool {
+        matches!(*self, MValue::AsyncLob(_))
+    }
+
+    fn into_async_lob_reader(self) -> Result<Box<dyn AsyncLobReader>, ConversionError> {
+        match self {
+            MValue::AsyncLob(chunks) => Ok(Box::new(AsyncChunks {
+                chunks: chunks.into(),
+                fetched: false,
+            })),
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} is not an async LOB"
+            ))),
+        }
+    }
+
+    fn is_naive_timestamp(&self) -> bool {
+        matches!(*self, MValue::Timestamp(_))
+    }
+}
+
+// Simulates a LOB whose chunks arrive with a delay.
+struct AsyncChunks {
+    chunks: VecDeque<Vec<u8>>,
+    fetched: bool,
+}
+
+impl AsyncLobReader for AsyncChunks {
+    fn poll_next_chunk(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<u8>>, ConversionError>> {
+        if self.fetched {
+            self.fetched = false;
+            Poll::Ready(Ok(self.chunks.pop_front()))
+        } else {
+            self.fetched = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+impl DbValueInto<bool> for MValue {
+    fn try_into(self) -> Result<bool, ConversionError> {
+        Err(not_implemented("DbValueInto<bool>"))
+    }
+}
+impl DbValueInto<u8> for MValue {
+    fn try_into(self) -> Result<u8, ConversionError> {
+        Err(not_implemented("DbValueInto<u8>"))
+    }
+}
+impl DbValueInto<u16> for MValue {
+    fn try_into(self) -> Result<u16, ConversionError> {
+        Err(not_implemented("DbValueInto<u16>"))
+    }
+}
+impl DbValueInto<u32> for MValue {
+    fn try_into(self) -> Result<u32, ConversionError> {
+        Err(not_implemented("DbValueInto<u32>"))
+    }
+}
+impl DbValueInto<u64> for MValue {
+    fn try_into(self) -> Result<u64, ConversionError> {
+        Err(not_implemented("DbValueInto<u64>"))
+    }
+}
+impl DbValueInto<i8> for MValue {
+    fn try_into(self) -> Result<i8, ConversionError> {
+        Err(not_implemented("DbValueInto<i8>"))
+    }
+}
+impl DbValueInto<i16> for MValue {
+    fn try_into(self) -> Result<i16, ConversionError> {
+        match self {
+            MValue::Short(i) => Ok(i),
+            mv => Err(ConversionError::ValueType(format!(
+                "DbValueInto<i16> not implemented for {mv:?}"
+            ))),
+        }
+    }
+}
+impl DbValueInto<i32> for MValue {
+    fn try_into(self) -> Result<i32, ConversionError> {
+        match self {
+            MValue::Short(i) => Ok(i32::from(i)),
+            mv => Err(ConversionError::ValueType(format!(
+                "DbValueInto<i32> not implemented for {mv:?}"
+            ))),
+        }
+    }
+}
+impl DbValueInto<i64> for MValue {
+    fn try_into(self) -> Result<i64, ConversionError> {
+        match self {
+            MValue::BigInt(i) => Ok(i),
+            mv => Err(ConversionError::ValueType(format!(
+                "DbValueInto<i64> not implemented for {mv:?}"
+            ))),
+        }
+    }
+}
+impl DbValueInto<f32> for MValue {
+    fn try_into(self) -> Result<f32, ConversionError> {
+        Err(not_implemented("DbValueInto<f32>"))
+    }
+}
+impl DbValueInto<f64> for MValue {
+    fn try_into(self) -> Result<f64, ConversionError> {
+        match self {
+            MValue::Double(f) => Ok(f),
+            // lossy, on purpose
+            MValue::Decimal(d) => d
+                .to_string()
+                .parse()
+                .map_err(|e| ConversionError::Other(Box::new(e))),
+            mv => Err(ConversionError::ValueType(format!(
+                "DbValueInto<f64> not implemented for {mv:?}"
+            ))),
+        }
+    }
+}
+impl DbValueInto<String> for MValue {
+    fn try_into(self) -> Result<String, ConversionError> {
+        log::trace!("try_into -> String");
+        match self {
+            MValue::String(s) | MValue::Char(s) | MValue::Json(s) => Ok(s),
+            MValue::Timestamp(ts) => Ok(ts.to_string()),
+            MValue::Short(i) => Ok(i.to_string()),
+            MValue::BigInt(i) => Ok(i.to_string()),
+            MValue::Double(f) => Ok(f.to_string()),
+            MValue::Decimal(d) => Ok(d.to_string()),
+            mv => Err(ConversionError::ValueType(format!(
+                "DbValueInto<String> not implemented for {mv:?}"
+            ))),
+        }
+    }
+}
+impl DbValueInto<Vec<u8>> for MValue {
+    fn try_into(self) -> Result<Vec<u8>, ConversionError> {
+        match self {
+            MValue::RawText(bytes) | MValue::Lob(bytes) => Ok(bytes),
+            mv => Err(ConversionError::ValueType(format!(
+                "DbValueInto<Vec<u8>> not implemented for {mv:?}"
+            ))),
+        }
+    }
+}
+
+impl DeserializableResultset for Resultset {
+    type E = test_utils::Error;
+    type ROW = test_utils::Row;
+
+    fn has_multiple_rows(&mut self) -> Result<bool, DeserializationError> {
+        Ok(self.has_multiple_rows())
+    }
+
+    fn has_no_rows(&mut self) -> Result<bool, DeserializationError> {
+        Ok(self.is_empty())
+    }
+
+    fn next(&mut self) -> Result<Option<test_utils::Row>, DeserializationError> {
+        Ok(self.next())
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.number_of_fields()
+    }
+
+    fn fieldname(&self, i: usize) -> Option<&str> {
+        self.fieldname(i)
+    }
+
+    fn fieldtype(&self, i: usize) -> Option<&str> {
+        self.fieldtype(i)
+    }
+
+    fn remaining_rows(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn take_row(&mut self, idx: usize) -> Result<Option<test_utils::Row>, DeserializationError> {
+        Ok(self.take_row(idx))
+    }
+}
+
+impl DeserializableMultiResultset for MultiResultset {
+    type E = test_utils::Error;
+    type RS = Resultset;
+
+    fn next_resultset(&mut self) -> Result<Option<Resultset>, DeserializationError> {
+        Ok(self.next())
+    }
+}
+
+impl SerdeDbChunkedResultset for ChunkedResultset {
+    type E = test_utils::Error;
+    type ROW = test_utils::Row;
+
+    fn poll_next_chunk(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<test_utils::Row>>, test_utils::Error>> {
+        if let Some(chunk) = self.poll_fetch() {
+            Poll::Ready(Ok(chunk))
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+impl DeserializableOutputParameters for OutputParameters {
+    type E = test_utils::Error;
+    type V = MValue;
+
+    fn number_of_parameters(&self) -> usize {
+        self.len()
+    }
+
+    fn parameter_name(&self, idx: usize) -> Option<&str> {
+        self.name(idx)
+    }
+
+    fn next_value(&mut self) -> Option<MValue> {
+        self.next()
+    }
+}
+
+impl From<DeserializationError> for test_utils::Error {
+    fn from(e: DeserializationError) -> test_utils::Error {
+        test_utils::Error::DESERIALIZATION(e)
+    }
+}
@@ -0,0 +1,287 @@
+use crate::ser::{
+    type_error, DbvFactory, EnumRepresentation, SerializationError, Temporal, TemporalEncoding,
+};
+use crate::test_utils::{self, MValue, Parameter, ParameterType};
+use chrono::{NaiveDateTime, NaiveTime};
+use std::str::FromStr;
+
+impl DbvFactory for &ParameterType {
+    type DBV = MValue;
+
+    fn serialize_bool(&self, _value: bool) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("bool", self.descriptor()))
+    }
+    fn serialize_i8(&self, value: i8) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => {
+                Ok(MValue::Short(i16::from(value)))
+            }
+            _ => Err(type_error("i8", self.descriptor())),
+        }
+    }
+
+    fn serialize_i16(&self, value: i16) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => Ok(MValue::Short(value)),
+            _ => Err(type_error("i16", self.descriptor())),
+        }
+    }
+    fn serialize_i32(&self, value: i32) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => i16::try_from(value)
+                .map(MValue::Short)
+                .map_err(|_| SerializationError::Range("i32", self.descriptor())),
+            _ => Err(type_error("i32", self.descriptor())),
+        }
+    }
+    fn serialize_i64(&self, value: i64) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => i16::try_from(value)
+                .map(MValue::Short)
+                .map_err(|_| SerializationError::Range("i64", self.descriptor())),
+            _ => Err(type_error("i64", self.descriptor())),
+        }
+    }
+    fn serialize_u8(&self, value: u8) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => {
+                Ok(MValue::Short(i16::from(value)))
+            }
+            _ => Err(type_error("u8", self.descriptor())),
+        }
+    }
+    fn serialize_u16(&self, value: u16) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => i16::try_from(value)
+                .map(MValue::Short)
+                .map_err(|_| SerializationError::Range("u16", self.descriptor())),
+            _ => Err(type_error("u16", self.descriptor())),
+        }
+    }
+    fn serialize_u32(&self, value: u32) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => i16::try_from(value)
+                .map(MValue::Short)
+                .map_err(|_| SerializationError::Range("u32", self.descriptor())),
+            _ => Err(type_error("u32", self.descriptor())),
+        }
+    }
+    fn serialize_u64(&self, value: u64) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => i16::try_from(value)
+                .map(MValue::Short)
+                .map_err(|_| SerializationError::Range("u64", self.descriptor())),
+            _ => Err(type_error("u64", self.descriptor())),
+        }
+    }
+    fn serialize_f32(&self, _value: f32) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("f32", self.descriptor()))
+    }
+    fn serialize_f64(&self, _value: f64) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("f64", self.descriptor()))
+    }
+    fn serialize_char(&self, _value: char) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("char", self.descriptor()))
+    }
+    fn serialize_str(&self, value: &str) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::String | ParameterType::NullableString => {
+                Ok(MValue::String(value.to_owned()))
+            }
+            ParameterType::Timestamp | ParameterType::NullableTimestamp => {
+                Ok(MValue::Timestamp(mock_db_timestamp(value)?))
+            }
+            _ => Err(type_error("str", self.descriptor())),
+        }
+    }
+    fn serialize_bytes(&self, value: &[u8]) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Binary | ParameterType::NullableBinary => {
+                Ok(MValue::Lob(value.to_vec()))
+            }
+            _ => Err(type_error("bytes", self.descriptor())),
+        }
+    }
+    fn serialize_lob(
+        &self,
+        mut reader: Box<dyn std::io::Read + Send>,
+    ) -> Result<Self::DBV, SerializationError> {
+        use serde::ser::Error;
+        match *self {
+            ParameterType::Binary | ParameterType::NullableBinary => {
+                // a real driver would send the chunks to the database
+                let mut content = Vec::new();
+                let mut chunk = [0_u8; 4];
+                loop {
+                    match reader
+                        .read(&mut chunk)
+                        .map_err(SerializationError::custom)?
+                    {
+                        0 => break,
+                        n => content.extend_from_slice(&chunk[..n]),
+                    }
+                }
+                Ok(MValue::Lob(content))
+            }
+            _ => Err(type_error("lob", self.descriptor())),
+        }
+    }
+    fn serialize_none(&self) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::NullableShort
+            | ParameterType::NullableString
+            | ParameterType::NullableTimestamp
+            | ParameterType::NullableBinary => Ok(MValue::Null),
+            _ => Err(type_error("none", self.descriptor())),
+        }
+    }
+    fn descriptor(&self) -> String {
+        String::from(match *self {
+            ParameterType::Short => "Short",
+            ParameterType::NullableShort => "NullableShort",
+            ParameterType::String => "String",
+            ParameterType::NullableString => "NullableString",
+            ParameterType::Timestamp => "Timestamp",
+            ParameterType::NullableTimestamp => "NullableTimestamp",
+            ParameterType::Binary => "Binary",
+            ParameterType::NullableBinary => "NullableBinary",
+        })
+    }
+    fn is_nullable(&self) -> Option<bool> {
+        Some(matches!(
+            *self,
+            ParameterType::NullableShort
+                | ParameterType::NullableString
+                | ParameterType::NullableTimestamp
+                | ParameterType::NullableBinary
+        ))
+    }
+    fn enum_representation(&self) -> Option<EnumRepresentation> {
+        match *self {
+            ParameterType::Short | ParameterType::NullableShort => Some(EnumRepresentation::Index),
+            _ => None,
+        }
+    }
+    fn temporal_encoding(&self) -> Option<TemporalEncoding> {
+        match *self {
+            ParameterType::Timestamp | ParameterType::NullableTimestamp => {
+                Some(TemporalEncoding::Native)
+            }
+            _ => None,
+        }
+    }
+    fn serialize_temporal(&self, value: Temporal) -> Result<Self::DBV, SerializationError> {
+        let ts = match value {
+            Temporal::DateTime(ts) => ts,
+            Temporal::DateTimeWithOffset(ts) => ts.naive_utc(),
+            Temporal::Date(date) => date.and_time(NaiveTime::MIN),
+            Temporal::Time(_) => return Err(type_error("time", self.descriptor())),
+        };
+        match *self {
+            ParameterType::Timestamp | ParameterType::NullableTimestamp => {
+                Ok(MValue::Timestamp(test_utils::Timestamp(ts)))
+            }
+            _ => Err(type_error("temporal", self.descriptor())),
+        }
+    }
+}
+
+impl DbvFactory for &Parameter {
+    type DBV = MValue;
+
+    fn serialize_bool(&self, value: bool) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_bool(value)
+    }
+    fn serialize_i8(&self, value: i8) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_i8(value)
+    }
+    fn serialize_i16(&self, value: i16) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_i16(value)
+    }
+    fn serialize_i32(&self, value: i32) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_i32(value)
+    }
+    fn serialize_i64(&self, value: i64) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_i64(value)
+    }
+    fn serialize_u8(&self, value: u8) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_u8(value)
+    }
+    fn serialize_u16(&self, value: u16) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_u16(value)
+    }
+    fn serialize_u32(&self, value: u32) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_u32(value)
+    }
+    fn serialize_u64(&self, value: u64) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_u64(value)
+    }
+    fn serialize_f32(&self, value: f32) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_f32(value)
+    }
+    fn serialize_f64(&self, value: f64) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_f64(value)
+    }
+    fn serialize_char(&self, value: char) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_char(value)
+    }
+    fn serialize_str(&self, value: &str) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_str(value)
+    }
+    fn serialize_bytes(&self, value: &[u8]) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_bytes(value)
+    }
+    fn serialize_lob(
+        &self,
+        reader: Box<dyn std::io::Read + Send>,
+    ) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_lob(reader)
+    }
+    fn serialize_none(&self) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_none()
+    }
+    fn serialize_default(&self) -> Result<Self::DBV, SerializationError> {
+        match self.default {
+            Some(ref default) => Ok(default.clone()),
+            None => (&self.ptype).serialize_default(),
+        }
+    }
+    fn descriptor(&self) -> String {
+        (&self.ptype).descriptor()
+    }
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+    fn is_nullable(&self) -> Option<bool> {
+        (&self.ptype).is_nullable()
+    }
+    fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+    fn enum_representation(&self) -> Option<EnumRepresentation> {
+        (&self.ptype).enum_representation()
+    }
+    fn temporal_encoding(&self) -> Option<TemporalEncoding> {
+        match self.temporal_format {
+            Some(ref format) => Some(TemporalEncoding::Format(format.clone())),
+            None => (&self.ptype).temporal_encoding(),
+        }
+    }
+    fn serialize_temporal(&self, value: Temporal) -> Result<Self::DBV, SerializationError> {
+        (&self.ptype).serialize_temporal(value)
+    }
+}
+
+fn mock_db_timestamp(value: &str) -> Result<test_utils::Timestamp, SerializationError> {
+    use serde::ser::Error;
+    match NaiveDateTime::from_str(value) {
+        Ok(ts) => Ok(test_utils::Timestamp(ts)),
+        Err(e) => Err(SerializationError::custom(e)),
+    }
+}
+
+impl From<SerializationError> for test_utils::Error {
+    fn from(e: SerializationError) -> test_utils::Error {
+        test_utils::Error::SERIALIZATION(e)
+    }
+}
@@ -1,17 +1,4 @@
-// not all tests need all classes
-#![allow(dead_code)]
+// The mock driver is part of the crate, see `serde_db::test_utils`.
+#![allow(unused_imports)]
 
-mod error;
-mod fieldnames;
-mod mvalue;
-mod parameter_type;
-mod resultset;
-mod row;
-mod serde_db_impl;
-
-pub use self::error::*;
-pub use self::fieldnames::*;
-pub use self::mvalue::*;
-pub use self::parameter_type::*;
-pub use self::resultset::*;
-pub use self::row::*;
+pub use serde_db::test_utils::*;
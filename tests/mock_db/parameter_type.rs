@@ -1,9 +0,0 @@
-// Types that are accepted as parameters in SQL commands
-pub enum ParameterType {
-    Short,
-    NullableShort,
-    String,
-    NullableString,
-    Timestamp,
-    NullableTimestamp,
-}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    DeserializableResultset, DeserializationOptions, NullPolicy, NumericPolicy, RoundingMode,
+};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    amount: Option<f64>,
+}
+
+#[test] // cargo test --test test_aggregation -- --nocapture
+pub fn test_aggregation() {
+    let _loghandle = util::init_logger();
+    info!("=== NULL-safe aggregation ===");
+
+    info!("sum_column_ignoring_nulls()");
+    let sum = get_resultset().sum_column_ignoring_nulls::<f64>(1).unwrap();
+    assert_eq!(sum, 0.5 + 1.5 + 2.5);
+    let sum = get_resultset().sum_column_ignoring_nulls::<i32>(0).unwrap();
+    assert_eq!(sum, 15);
+
+    info!("count_nulls()");
+    assert_eq!(get_resultset().count_nulls(1).unwrap(), 3);
+    assert_eq!(get_resultset().count_nulls(0).unwrap(), 0);
+    assert!(get_resultset().count_nulls(2).is_err());
+
+    info!("try_fold_rows()");
+    let (ids, with_amount) = get_resultset()
+        .try_fold_rows((0, 0), |(ids, n), row: TestData| {
+            (ids + row.id, n + usize::from(row.amount.is_some()))
+        })
+        .unwrap();
+    assert_eq!((ids, with_amount), (15, 3));
+
+    info!("try_fold_rows_with()");
+    let options = DeserializationOptions::default().with_null_policy(NullPolicy::TypeDefault);
+    let total = get_resultset()
+        .try_fold_rows_with(0.0, |sum, (_id, amount): (i32, f64)| sum + amount, &options)
+        .unwrap();
+    assert_eq!(total, 0.5 + 1.5 + 2.5);
+    let result = get_resultset().try_fold_rows(0.0, |sum, (_id, amount): (i32, f64)| sum + amount);
+    assert!(result.is_err());
+
+    info!("try_fold_column() on the remaining rows");
+    let mut rs = get_resultset();
+    let _first = rs.next();
+    let max = rs
+        .try_fold_column(1, None, |max: Option<f64>, value: Option<f64>| {
+            match (max, value) {
+                (Some(m), Some(v)) => Some(m.max(v)),
+                (m, v) => m.or(v),
+            }
+        })
+        .unwrap();
+    assert_eq!(max, Some(2.5));
+    assert_eq!(DeserializableResultset::remaining_rows(&rs), Some(0));
+
+    let result = get_resultset().sum_column_ignoring_nulls::<i32>(1);
+    assert!(result.is_err());
+
+    info!("try_fold_column_with() and sum_column_ignoring_nulls_with()");
+    let options = DeserializationOptions::default()
+        .with_numeric_policy(NumericPolicy::Checked)
+        .with_rounding_mode(RoundingMode::Truncate);
+    let sum = get_resultset()
+        .sum_column_ignoring_nulls_with::<i32>(1, &options)
+        .unwrap();
+    assert_eq!(sum, 3);
+    let values = get_resultset()
+        .try_fold_column_with(
+            1,
+            Vec::new(),
+            |mut values, value: Option<i32>| {
+                values.push(value);
+                values
+            },
+            &options,
+        )
+        .unwrap();
+    assert_eq!(values, vec![None, Some(0), None, Some(1), None, Some(2)]);
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "amount"]);
+    for i in 0..6 {
+        rs.push(vec![
+            MValue::new_short(i),
+            if i % 2 == 0 {
+                MValue::new_null()
+            } else {
+                MValue::new_double(f64::from(i) / 2.0)
+            },
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, NullPolicy};
+
+#[derive(Debug, Deserialize)]
+struct Post {
+    id: i32,
+    scores: Vec<i32>,
+    tags: Option<Vec<String>>,
+    matrix: Vec<Vec<i16>>,
+}
+
+#[test] // cargo test --test test_array_columns -- --nocapture
+pub fn test_array_columns() {
+    let _loghandle = util::init_logger();
+    info!("=== Array columns ===");
+
+    let posts: Vec<Post> = get_resultset().try_into().unwrap();
+    debug!("{:?}", posts);
+    assert_eq!(posts[0].id, 1);
+    assert_eq!(posts[0].scores, vec![3, 1, 4]);
+    assert_eq!(
+        posts[0].tags.as_deref(),
+        Some(&["rust".to_string(), "serde".to_string()][..])
+    );
+    assert_eq!(posts[0].matrix, vec![vec![1, 2], vec![3]]);
+    assert!(posts[1].scores.is_empty());
+    assert!(posts[1].tags.is_none());
+
+    info!("Array elements follow the options");
+    let options = DeserializationOptions::default().with_null_policy(NullPolicy::TypeDefault);
+    let scores: Vec<i32> = MValue::new_array(vec![MValue::new_short(1), MValue::new_null()])
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(scores, vec![1, 0]);
+    let result: mock_db::Result<Vec<i32>> =
+        MValue::new_array(vec![MValue::new_short(1), MValue::new_null()]).try_into();
+    assert!(result.is_err());
+
+    info!("Tuples and Options of elements");
+    let pair: (i32, String) = MValue::new_array(vec![
+        MValue::new_short(7),
+        MValue::new_string("seven".to_string()),
+    ])
+    .try_into()
+    .unwrap();
+    assert_eq!(pair, (7, "seven".to_string()));
+    let sparse: Vec<Option<i32>> =
+        MValue::new_array(vec![MValue::new_null(), MValue::new_short(2)])
+            .try_into()
+            .unwrap();
+    assert_eq!(sparse, vec![None, Some(2)]);
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "scores", "tags", "matrix"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_array(vec![
+            MValue::new_short(3),
+            MValue::new_short(1),
+            MValue::new_short(4),
+        ]),
+        MValue::new_array(vec![
+            MValue::new_string("rust".to_string()),
+            MValue::new_string("serde".to_string()),
+        ]),
+        MValue::new_array(vec![
+            MValue::new_array(vec![MValue::new_short(1), MValue::new_short(2)]),
+            MValue::new_array(vec![MValue::new_short(3)]),
+        ]),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_array(vec![]),
+        MValue::new_null(),
+        MValue::new_array(vec![]),
+    ]);
+    rs
+}
@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate log;
+
+mod util;
+
+use serde::Deserialize;
+use serde_db::assert_maps;
+use serde_db::de::struct_fields;
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct TestData {
+    #[serde(rename = "ID")]
+    id: u32,
+    #[serde(rename = "NAME")]
+    name: String,
+    created_at: String,
+}
+
+#[test] // cargo test --test test_assert_maps -- --nocapture
+pub fn test_assert_maps() {
+    let _loghandle = util::init_logger();
+    info!("=== assert_maps! ===");
+
+    assert_maps!(TestData, ["ID", "NAME", "created_at"]);
+    assert_eq!(struct_fields::<(u32, String)>(), None);
+
+    info!("Mapping drift is detected");
+    let result = std::panic::catch_unwind(|| assert_maps!(TestData, ["ID", "NAME", "CREATED_AT"]));
+    let message = result.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    debug!("{message}");
+    assert!(message.contains("missing:    [\"CREATED_AT\"]"));
+    assert!(message.contains("unexpected: [\"created_at\"]"));
+}
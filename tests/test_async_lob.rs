@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::DeserializationError;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+// Polls the future to completion, and returns the result and the number of polls.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 1;
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, polls),
+            Poll::Pending => polls += 1,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Document {
+    id: i32,
+    content: String,
+}
+
+#[test] // cargo test --test test_async_lob -- --nocapture
+pub fn test_async_lob() {
+    let _loghandle = util::init_logger();
+    info!("=== Async LOB ===");
+    let chunks = vec![b"Hello, ".to_vec(), b"async ".to_vec(), b"world".to_vec()];
+
+    info!("Async entry point");
+    let mut out = Vec::new();
+    let (result, polls) = block_on(serde_db::de::write_lob_async(
+        MValue::new_async_lob(chunks.clone()),
+        &mut out,
+    ));
+    assert_eq!(result.unwrap(), 18);
+    assert_eq!(out, b"Hello, async world");
+    debug!("polls: {}", polls);
+    assert!(polls > chunks.len());
+
+    let mut out = Vec::new();
+    let (result, _) = block_on(serde_db::de::write_lob_async(
+        MValue::new_lob(b"sync".to_vec()),
+        &mut out,
+    ));
+    assert_eq!(result.unwrap(), 4);
+    assert_eq!(out, b"sync");
+
+    info!("Sync entry points reject async LOBs");
+    let result: mock_db::Result<String> = MValue::new_async_lob(chunks.clone()).try_into();
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::Usage(s))) => {
+            assert!(s.contains("write_lob_async"));
+        }
+        _ => panic!("expected Usage error"),
+    }
+    let mut out = Vec::new();
+    assert!(serde_db::de::write_lob(MValue::new_async_lob(chunks.clone()), &mut out).is_err());
+
+    let mut rs = Resultset::new(&["id", "content"]);
+    rs.push(vec![MValue::new_short(1), MValue::new_async_lob(chunks)]);
+    let result: mock_db::Result<Vec<Document>> = rs.try_into();
+    assert!(result.is_err());
+
+    let mut rs = Resultset::new(&["id", "content"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_string("plain".to_string()),
+    ]);
+    let docs: Vec<Document> = rs.try_into().unwrap();
+    assert_eq!((docs[0].id, docs[0].content.as_str()), (1, "plain"));
+}
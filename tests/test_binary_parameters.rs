@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde::{Serialize, Serializer};
+use serde_db::ser::{to_named_params, to_params, SerializationError};
+
+// Serializes like a field with `#[serde(with = "serde_bytes")]`.
+struct Bytes<'a>(&'a [u8]);
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+#[test] // cargo test --test test_binary_parameters -- --nocapture
+pub fn test_binary_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== binary parameters ===");
+
+    info!("Vec<u8>, &[u8], and serialize_bytes() produce a single binary parameter");
+    let data: Vec<u8> = vec![0, 1, 254, 255];
+    let metadata = [PT::Short, PT::Binary, PT::Binary, PT::NullableBinary];
+    let values: Vec<MValue> = to_params(
+        &(1_i16, &data, Bytes(&data[1..]), &data[..2]),
+        &mut metadata.iter(),
+    )
+    .unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(1),
+            MValue::Lob(data.clone()),
+            MValue::Lob(vec![1, 254, 255]),
+            MValue::Lob(vec![0, 1]),
+        ]
+    );
+
+    info!("Empty and optional byte sequences");
+    let values: Vec<MValue> = to_params(
+        &(2_i16, Vec::<u8>::new(), Bytes(&[]), None::<Vec<u8>>),
+        &mut metadata.iter(),
+    )
+    .unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(2),
+            MValue::Lob(vec![]),
+            MValue::Lob(vec![]),
+            MValue::Null
+        ]
+    );
+
+    info!("Struct fields, bound by position or by name");
+    #[derive(Serialize)]
+    struct Document {
+        id: i16,
+        content: Vec<u8>,
+    }
+    let document = Document {
+        id: 3,
+        content: b"%PDF".to_vec(),
+    };
+    let values: Vec<MValue> = to_params(&document, &mut [PT::Short, PT::Binary].iter()).unwrap();
+    assert_eq!(values[1], MValue::Lob(b"%PDF".to_vec()));
+    let parameters = [
+        Parameter::new("content", PT::Binary).with_max_length(3),
+        Parameter::new("id", PT::Short),
+    ];
+    let result = to_named_params(&document, &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { ref source, .. })
+            if matches!(**source, SerializationError::Range("bytes", _))
+    ));
+
+    info!("Binary values are not stringified");
+    let result = to_params(&(4_i16, &data), &mut [PT::Short, PT::String].iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { index: 1, ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "bytes", .. })
+    ));
+
+    info!("Nested sequences of other values are still flattened");
+    let values: Vec<MValue> = to_params(
+        &(5_i16, vec![6_i16, 7]),
+        &mut [PT::Short, PT::Short, PT::Short].iter(),
+    )
+    .unwrap();
+    assert_eq!(
+        values,
+        vec![MValue::Short(5), MValue::Short(6), MValue::Short(7)]
+    );
+}
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{BoolCoercion, DeserializationOptions};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    active: bool,
+    deleted: bool,
+    flag: Option<bool>,
+}
+
+#[test] // cargo test --test test_bool_coercion -- --nocapture
+pub fn test_bool_coercion() {
+    let _loghandle = util::init_logger();
+    info!("=== Boolean coercion ===");
+
+    info!("Without coercion");
+    let result: mock_db::Result<bool> = MValue::new_short(1).try_into();
+    assert!(result.is_err());
+
+    info!("Default coercion");
+    let options = DeserializationOptions::default().with_bool_coercion(BoolCoercion::default());
+    let data: Vec<TestData> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", data);
+    assert!(data[0].active);
+    assert!(!data[0].deleted);
+    assert_eq!(data[0].flag, Some(true));
+    assert!(!data[1].active);
+    assert!(data[1].deleted);
+    assert_eq!(data[1].flag, None);
+    assert!(data[2].active);
+    assert_eq!(data[2].flag, Some(false));
+
+    for invalid in [MValue::new_short(2), MValue::new_string("yes".to_string())] {
+        let result: mock_db::Result<bool> = invalid.try_into_with(&options);
+        assert!(result.is_err());
+    }
+
+    info!("Custom tokens, no integers");
+    let options = DeserializationOptions::default().with_bool_coercion(
+        BoolCoercion::default()
+            .with_tokens(&["J", "OUI"], &["N", "NON"])
+            .with_integers(false),
+    );
+    assert!(MValue::new_string("oui".to_string())
+        .try_into_with::<bool>(&options)
+        .unwrap());
+    assert!(!MValue::new_string("N".to_string())
+        .try_into_with::<bool>(&options)
+        .unwrap());
+    assert!(MValue::new_short(1)
+        .try_into_with::<bool>(&options)
+        .is_err());
+    assert!(MValue::new_string("T".to_string())
+        .try_into_with::<bool>(&options)
+        .is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["active", "deleted", "flag"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_string("N".to_string()),
+        MValue::new_string("true".to_string()),
+    ]);
+    rs.push(vec![
+        MValue::new_short(0),
+        MValue::new_string(" y".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_string("T".to_string()),
+        MValue::new_string("f".to_string()),
+        MValue::new_string("FALSE".to_string()),
+    ]);
+    rs
+}
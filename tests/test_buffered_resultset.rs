@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::MValue;
+use serde::Deserialize;
+use serde_db::de::{
+    BufferedResultset, DeserializableResultset, DeserializationError, DeserializationResult,
+};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    id: i64,
+    name: String,
+}
+
+#[test] // cargo test --test test_buffered_resultset -- --nocapture
+pub fn test_buffered_resultset() {
+    let _loghandle = util::init_logger();
+    info!("=== Buffered resultset ===");
+
+    info!("Rows from several chunks");
+    let (rs, fetches) = get_resultset(7, 3);
+    let lines: Vec<Line> = DeserializableResultset::try_into(rs).unwrap();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[6].id, 7);
+    assert_eq!(lines[6].name, "name 7");
+    assert_eq!(*fetches.lock().unwrap(), 4);
+
+    info!("Single row");
+    let (rs, _) = get_resultset(1, 3);
+    let line: Line = DeserializableResultset::try_into(rs).unwrap();
+    assert_eq!(line.id, 1);
+
+    info!("Fetching is lazy");
+    let (mut rs, fetches) = get_resultset(7, 3);
+    assert!(!rs.has_no_rows().unwrap());
+    assert_eq!(*fetches.lock().unwrap(), 1);
+    assert_eq!(rs.remaining_rows(), None);
+    let row = rs.take_row(4).unwrap().unwrap();
+    assert_eq!(*fetches.lock().unwrap(), 2);
+    let (id, _): (i64, String) = serde_db::de::DeserializableRow::try_into(row).unwrap();
+    assert_eq!(id, 5);
+    let rest: Vec<(i64, String)> = DeserializableResultset::try_into(&mut rs).unwrap();
+    assert_eq!(
+        rest.iter().map(|r| r.0).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 6, 7]
+    );
+    assert!(rs.is_complete());
+    assert_eq!(rs.remaining_rows(), Some(0));
+
+    info!("Fetch errors");
+    let rs: BufferedResultset<_> =
+        BufferedResultset::with_first_chunk(metadata(), vec![row_values(1)], || {
+            Err(DeserializationError::Usage("connection lost".to_string()))
+        });
+    let result: Result<Vec<Line>, DeserializationError> = DeserializableResultset::try_into(rs);
+    assert!(matches!(result, Err(DeserializationError::Usage(_))));
+}
+
+fn metadata() -> Arc<Vec<String>> {
+    Arc::new(vec!["id".to_string(), "name".to_string()])
+}
+
+fn row_values(id: i64) -> Vec<MValue> {
+    vec![
+        MValue::new_bigint(id),
+        MValue::new_string(format!("name {id}")),
+    ]
+}
+
+#[allow(clippy::type_complexity)]
+fn get_resultset(
+    rows: i64,
+    chunk_size: usize,
+) -> (
+    BufferedResultset<impl FnMut() -> DeserializationResult<Option<Vec<Vec<MValue>>>>>,
+    Arc<std::sync::Mutex<usize>>,
+) {
+    let mut pending: VecDeque<Vec<MValue>> = (1..=rows).map(row_values).collect();
+    let fetches = Arc::new(std::sync::Mutex::new(0));
+    let counter = Arc::clone(&fetches);
+    let rs = BufferedResultset::new(metadata(), move || {
+        *counter.lock().unwrap() += 1;
+        let chunk: Vec<Vec<MValue>> = pending.drain(..chunk_size.min(pending.len())).collect();
+        Ok(if chunk.is_empty() { None } else { Some(chunk) })
+    });
+    (rs, fetches)
+}
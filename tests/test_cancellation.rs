@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializationError, DeserializationObserver, DeserializationOptions};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// Raises the cancellation flag when the progress report arrives,
+// like a UI would do when the user presses "cancel".
+struct Canceller(Arc<AtomicBool>);
+
+impl DeserializationObserver for Canceller {
+    fn progress(&self, _rows: usize) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test] // cargo test --test test_cancellation -- --nocapture
+pub fn test_cancellation() {
+    let _loghandle = util::init_logger();
+    info!("=== Cancellation ===");
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let options = DeserializationOptions::default().with_cancellation_flag(Arc::clone(&flag));
+
+    info!("Flag not raised");
+    assert!(!options.is_cancelled());
+    let data: Vec<i64> = get_resultset(10).try_into_with(&options).unwrap();
+    assert_eq!(data.len(), 10);
+
+    info!("Flag raised during the conversion");
+    let options = options
+        .with_observer(Arc::new(Canceller(Arc::clone(&flag))))
+        .with_progress_interval(4);
+    let result: mock_db::Result<Vec<i64>> = get_resultset(10).try_into_with(&options);
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::Cancelled
+        ))
+    ));
+    assert!(options.is_cancelled());
+
+    info!("Flag raised before the conversion");
+    let result: mock_db::Result<Vec<i64>> = get_resultset(3).try_into_with(&options);
+    assert!(result.is_err());
+    flag.store(false, Ordering::Relaxed);
+    assert!(!options.is_cancelled());
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id"]);
+    for id in 1..=rows {
+        rs.push(vec![MValue::new_bigint(id)]);
+    }
+    rs
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    CharPadding, ConversionError, DeserializationError, DeserializationOptions, NullPolicy,
+};
+
+#[derive(Debug, Deserialize)]
+struct Flags {
+    active: char,
+    grade: Option<char>,
+}
+
+#[test] // cargo test --test test_char -- --nocapture
+pub fn test_char() {
+    let _loghandle = util::init_logger();
+    info!("=== char targets ===");
+
+    info!("Single-character columns");
+    let mut rs = Resultset::new(&["active", "grade"]);
+    rs.push(vec![
+        MValue::new_char("Y", 1),
+        MValue::new_string("ä".to_string()),
+    ]);
+    rs.push(vec![MValue::new_char("N", 1), MValue::new_null()]);
+    let flags: Vec<Flags> = rs.try_into().unwrap();
+    assert_eq!(flags[0].active, 'Y');
+    assert_eq!(flags[0].grade, Some('ä'));
+    assert_eq!(flags[1].active, 'N');
+    assert_eq!(flags[1].grade, None);
+
+    info!("Single values and resultsets");
+    let c: char = MValue::new_string("x".to_string()).try_into().unwrap();
+    assert_eq!(c, 'x');
+    let mut rs = Resultset::new(&["c"]);
+    rs.push(vec![MValue::new_string("z".to_string())]);
+    let c: char = rs.try_into().unwrap();
+    assert_eq!(c, 'z');
+
+    info!("Padding is removed according to the options");
+    let options = DeserializationOptions::default().with_char_padding(CharPadding::TrimFixedWidth);
+    let c: char = MValue::new_char("A", 3).try_into_with(&options).unwrap();
+    assert_eq!(c, 'A');
+
+    info!("Texts of other lengths");
+    for text in ["", "ab"] {
+        let result: mock_db::Result<char> = MValue::new_string(text.to_string()).try_into();
+        debug!("{:?}", result);
+        assert!(matches!(
+            result,
+            Err(mock_db::Error::DESERIALIZATION(
+                DeserializationError::ConversionError(ConversionError::ValueType(_))
+            ))
+        ));
+    }
+
+    info!("NULL with the TypeDefault policy");
+    let options = DeserializationOptions::default().with_null_policy(NullPolicy::TypeDefault);
+    let c: char = MValue::new_null().try_into_with(&options).unwrap();
+    assert_eq!(c, '\0');
+}
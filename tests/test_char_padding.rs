@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{CharPadding, DeserializationOptions};
+
+#[derive(Debug, Deserialize)]
+struct Country {
+    code: String,
+    name: String,
+    comment: Option<String>,
+}
+
+#[test] // cargo test --test test_char_padding -- --nocapture
+pub fn test_char_padding() {
+    let _loghandle = util::init_logger();
+    info!("=== CHAR padding ===");
+
+    info!("Keep");
+    let countries: Vec<Country> = get_resultset().try_into().unwrap();
+    debug!("{:?}", countries);
+    assert_eq!(countries[0].code, "DE   ");
+    assert_eq!(countries[0].name, "Germany  ");
+
+    info!("TrimFixedWidth");
+    let options = DeserializationOptions::default().with_char_padding(CharPadding::TrimFixedWidth);
+    let countries: Vec<Country> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", countries);
+    assert_eq!(countries[0].code, "DE");
+    assert_eq!(countries[0].name, "Germany  ");
+    assert_eq!(countries[0].comment.as_deref(), Some("EU"));
+    assert!(countries[1].comment.is_none());
+    let code: String = MValue::new_char("FR", 8).try_into_with(&options).unwrap();
+    assert_eq!(code, "FR");
+
+    info!("TrimAll");
+    let options = DeserializationOptions::default().with_char_padding(CharPadding::TrimAll);
+    let countries: Vec<Country> = get_resultset().try_into_with(&options).unwrap();
+    assert_eq!(countries[0].code, "DE");
+    assert_eq!(countries[0].name, "Germany");
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["code", "name", "comment"]);
+    rs.push(vec![
+        MValue::new_char("DE", 5),
+        MValue::new_string("Germany  ".to_string()),
+        MValue::new_char("EU", 10),
+    ]);
+    rs.push(vec![
+        MValue::new_char("CH", 5),
+        MValue::new_string("Switzerland".to_string()),
+        MValue::new_null(),
+    ]);
+    rs
+}
@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationOptions, NullPolicy};
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    id: i64,
+    amount: i16,
+}
+
+#[test] // cargo test --test test_collect_errors -- --nocapture
+pub fn test_collect_errors() {
+    let _loghandle = util::init_logger();
+    info!("=== Collect per-row errors ===");
+
+    let (lines, errors): (Vec<Line>, _) = get_resultset().try_into_collect_errors().unwrap();
+    debug!("errors: {:?}", errors);
+    assert_eq!(
+        lines.iter().map(|l| l.id).collect::<Vec<_>>(),
+        vec![1, 3, 5]
+    );
+    assert_eq!(lines.iter().map(|l| l.amount).sum::<i16>(), 90);
+    assert_eq!(
+        errors.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+
+    info!("Without errors");
+    let mut rs = Resultset::new(&["id", "amount"]);
+    rs.push(vec![MValue::new_bigint(1), MValue::new_short(10)]);
+    let (lines, errors): (Vec<Line>, _) = rs.try_into_collect_errors().unwrap();
+    assert_eq!(lines.len(), 1);
+    assert!(errors.is_empty());
+
+    info!("With options");
+    let options = DeserializationOptions::default().with_null_policy(NullPolicy::TypeDefault);
+    let (lines, errors): (Vec<Line>, _) = get_resultset()
+        .try_into_collect_errors_with(&options)
+        .unwrap();
+    assert_eq!(
+        lines.iter().map(|l| l.id).collect::<Vec<_>>(),
+        vec![1, 2, 3, 5]
+    );
+    assert_eq!(
+        errors.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        vec![3]
+    );
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "amount"]);
+    rs.push(vec![MValue::new_bigint(1), MValue::new_short(10)]);
+    rs.push(vec![MValue::new_bigint(2), MValue::new_null()]);
+    rs.push(vec![MValue::new_bigint(3), MValue::new_short(30)]);
+    rs.push(vec![
+        MValue::new_bigint(4),
+        MValue::new_string("forty".to_string()),
+    ]);
+    rs.push(vec![MValue::new_bigint(5), MValue::new_short(50)]);
+    rs
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializableResultset, DeserializationOptions, NullPolicy};
+
+#[test] // cargo test --test test_column_into -- --nocapture
+pub fn test_column_into() {
+    let _loghandle = util::init_logger();
+    info!("=== Single-column extraction ===");
+
+    info!("By name");
+    let names: Vec<String> = get_resultset().column_into("NAME").unwrap();
+    assert_eq!(names, vec!["Ann", "Bob", "Cid"]);
+
+    info!("By index, with NULL values");
+    let ages: Vec<Option<i32>> = get_resultset().column_at_into(2).unwrap();
+    assert_eq!(ages, vec![Some(31), None, Some(7)]);
+
+    info!("With options");
+    let options = DeserializationOptions::default().with_null_policy(NullPolicy::TypeDefault);
+    let ages: Vec<i32> = get_resultset().column_into_with("AGE", &options).unwrap();
+    assert_eq!(ages, vec![31, 0, 7]);
+    let ages: Vec<i32> = get_resultset().column_at_into_with(2, &options).unwrap();
+    assert_eq!(ages, vec![31, 0, 7]);
+
+    info!("First column, on a mutable reference");
+    let mut rs = get_resultset();
+    let ids: Vec<i64> = DeserializableResultset::column_at_into(&mut rs, 0).unwrap();
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    info!("Errors");
+    let result: mock_db::Result<Vec<String>> = get_resultset().column_into("NICKNAME");
+    assert!(result.is_err());
+    let result: mock_db::Result<Vec<String>> = get_resultset().column_at_into(3);
+    assert!(result.is_err());
+    let result: mock_db::Result<Vec<i32>> = get_resultset().column_into("AGE");
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["ID", "NAME", "AGE"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_string("Ann".to_string()),
+        MValue::new_short(31),
+    ]);
+    rs.push(vec![
+        MValue::new_bigint(2),
+        MValue::new_string("Bob".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_bigint(3),
+        MValue::new_string("Cid".to_string()),
+        MValue::new_short(7),
+    ]);
+    rs
+}
@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, UnknownColumnPolicy};
+
+#[derive(Debug, Deserialize)]
+struct Audit {
+    created_by: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: i64,
+    #[serde(alias = "CUSTOMER_NAME")]
+    customer: String,
+    #[serde(default)]
+    priority: i64,
+    #[serde(flatten)]
+    audit: Audit,
+}
+
+#[test] // cargo test --test test_column_order -- --nocapture
+pub fn test_column_order() {
+    let _loghandle = util::init_logger();
+    info!("=== Columns are matched by name ===");
+
+    let mut rs = Resultset::new(&["created_by", "CUSTOMER_NAME", "id"]);
+    rs.push(vec![
+        MValue::new_string("admin".to_string()),
+        MValue::new_string("ACME".to_string()),
+        MValue::new_bigint(17),
+    ]);
+
+    info!("Out-of-order columns, aliases, defaults and flattened fields");
+    let order: Order = rs.clone().try_into().unwrap();
+    assert_eq!(order.id, 17);
+    assert_eq!(order.customer, "ACME");
+    assert_eq!(order.priority, 0);
+    assert_eq!(order.audit.created_by, "admin");
+
+    info!("Aliases are also found when unknown columns are ignored");
+    let mut rs = Resultset::new(&["CUSTOMER_NAME", "id", "notes"]);
+    rs.push(vec![
+        MValue::new_string("ACME".to_string()),
+        MValue::new_bigint(17),
+        MValue::new_string("urgent".to_string()),
+    ]);
+    let options =
+        DeserializationOptions::default().with_unknown_column_policy(UnknownColumnPolicy::Ignore);
+    let order: Plain = rs.try_into_with(&options).unwrap();
+    assert_eq!(order.customer, "ACME");
+}
+
+#[derive(Debug, Deserialize)]
+struct Plain {
+    #[allow(dead_code)]
+    id: i64,
+    #[serde(alias = "CUSTOMER_NAME")]
+    customer: String,
+}
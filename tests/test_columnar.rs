@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationOptions, FieldNameMatching};
+
+#[derive(Debug, Deserialize)]
+struct Prices {
+    symbol: Vec<String>,
+    price: Vec<f64>,
+    volume: Vec<Option<i32>>,
+}
+
+#[test] // cargo test --test test_columnar -- --nocapture
+pub fn test_columnar() {
+    let _loghandle = util::init_logger();
+    info!("=== Columnar deserialization ===");
+
+    info!("Struct of Vecs");
+    let prices: Prices = get_resultset(&["symbol", "price", "volume"])
+        .try_into_columns()
+        .unwrap();
+    debug!("{:?}", prices);
+    assert_eq!(prices.symbol, vec!["ABC", "XYZ", "QRS"]);
+    assert_eq!(prices.price, vec![10.5, 20.0, 0.25]);
+    assert_eq!(prices.volume, vec![Some(100), None, Some(7)]);
+
+    info!("Tuple of Vecs");
+    let (symbols, _, volumes): (Vec<String>, Vec<f64>, Vec<Option<i16>>) =
+        get_resultset(&["symbol", "price", "volume"])
+            .try_into_columns()
+            .unwrap();
+    assert_eq!(symbols.len(), 3);
+    assert_eq!(volumes[2], Some(7));
+
+    info!("Options apply");
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive);
+    let prices: Prices = get_resultset(&["SYMBOL", "PRICE", "VOLUME"])
+        .try_into_columns_with(&options)
+        .unwrap();
+    assert_eq!(prices.symbol[1], "XYZ");
+
+    info!("Empty resultset");
+    let prices: Prices = Resultset::new(&["symbol", "price", "volume"])
+        .try_into_columns()
+        .unwrap();
+    assert!(prices.symbol.is_empty() && prices.price.is_empty() && prices.volume.is_empty());
+
+    info!("Errors");
+    let result: mock_db::Result<Prices> =
+        get_resultset(&["symbol", "price", "amount"]).try_into_columns();
+    assert!(result.is_err());
+    let mut rs = get_resultset(&["symbol", "price", "volume"]);
+    let result: mock_db::Result<Vec<Prices>> = DeserializableResultset::try_into_columns(&mut rs);
+    assert!(result.is_err());
+}
+
+fn get_resultset(fields: &[&'static str]) -> Resultset {
+    let mut rs = Resultset::new(fields);
+    rs.push(vec![
+        MValue::new_string("ABC".to_string()),
+        MValue::new_double(10.5),
+        MValue::new_short(100),
+    ]);
+    rs.push(vec![
+        MValue::new_string("XYZ".to_string()),
+        MValue::new_double(20.0),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_string("QRS".to_string()),
+        MValue::new_double(0.25),
+        MValue::new_short(7),
+    ]);
+    rs
+}
@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationOptions, FieldNameMatching};
+
+#[derive(Deserialize)]
+struct TestData {
+    f1: String,
+    f2: i32,
+}
+
+#[test] // cargo test --test test_conversion_estimate -- --nocapture
+pub fn test_conversion_estimate() {
+    let _loghandle = util::init_logger();
+    info!("=== Conversion estimate ===");
+
+    let mut rs = get_resultset(50);
+    let estimate = rs.estimate_conversion::<TestData>(10).unwrap();
+    debug!("{:?}", estimate.duration_per_row());
+    assert_eq!(estimate.sample.len(), 10);
+    assert_eq!(estimate.sample[9].f2, 9);
+    assert_eq!(estimate.remaining_rows, Some(40));
+    assert_eq!(estimate.total_rows(), Some(50));
+    assert!(estimate.duration_per_row().is_some());
+    assert!(estimate.estimated_remaining_duration().is_some());
+    // the sample contains the strings "0" to "9"
+    assert_eq!(estimate.sample_bytes, 10);
+    assert_eq!(
+        estimate.bytes_per_row(),
+        std::mem::size_of::<TestData>() + 1
+    );
+    assert_eq!(
+        estimate.estimated_total_bytes(),
+        Some(50 * (std::mem::size_of::<TestData>() + 1))
+    );
+
+    info!("The remaining rows are still available");
+    let rest: Vec<TestData> = rs.try_into().unwrap();
+    assert_eq!(rest.len(), 40);
+    assert_eq!(rest[0].f1, "10");
+
+    info!("Sample larger than resultset");
+    let estimate = get_resultset(3)
+        .estimate_conversion::<TestData>(10)
+        .unwrap();
+    assert_eq!(estimate.sample.len(), 3);
+    assert_eq!(
+        estimate.estimated_remaining_duration(),
+        Some(Default::default())
+    );
+
+    info!("Empty resultset");
+    let estimate = get_resultset(0)
+        .estimate_conversion::<TestData>(10)
+        .unwrap();
+    assert!(estimate.sample.is_empty());
+    assert_eq!(estimate.duration_per_row(), None);
+    assert_eq!(estimate.bytes_per_row(), std::mem::size_of::<TestData>());
+
+    info!("Heap data of the sampled values");
+    let mut rs = Resultset::new(&["F1", "F2"]);
+    for i in 0..4 {
+        rs.push(vec![MValue::String("x".repeat(1000)), MValue::Short(i)]);
+    }
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive);
+    let estimate = rs
+        .estimate_conversion_with::<TestData>(2, &options)
+        .unwrap();
+    assert_eq!(estimate.sample[1].f1.len(), 1000);
+    assert_eq!(estimate.sample_bytes, 2000);
+    assert!(estimate.bytes_per_row() >= 1000);
+    assert_eq!(
+        estimate.estimated_total_bytes(),
+        Some(4 * (std::mem::size_of::<TestData>() + 1000))
+    );
+}
+
+fn get_resultset(len: usize) -> Resultset {
+    let mut rs = Resultset::new(&["f1", "f2"]);
+    for i in 0..len {
+        rs.push(vec![
+            MValue::String(i.to_string()),
+            MValue::Short(i16::try_from(i).unwrap()),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, Utf8Policy};
+use std::borrow::Cow;
+
+#[derive(Debug, Deserialize)]
+struct Label<'a> {
+    #[serde(borrow)]
+    text: Cow<'a, str>,
+    note: Option<Cow<'static, str>>,
+}
+
+#[test] // cargo test --test test_cow -- --nocapture
+pub fn test_cow() {
+    let _loghandle = util::init_logger();
+    info!("=== Cow<str> targets ===");
+
+    info!("Cow fields, with and without #[serde(borrow)]");
+    let mut rs = Resultset::new(&["text", "note"]);
+    rs.push(vec![
+        MValue::new_string("Hello".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_char("World", 6),
+        MValue::new_string("ok".to_string()),
+    ]);
+    let labels: Vec<Label> = rs.try_into().unwrap();
+    assert_eq!(labels[0].text, "Hello");
+    assert_eq!(labels[0].note, None);
+    assert_eq!(labels[1].text, "World ");
+    assert_eq!(labels[1].note.as_deref(), Some("ok"));
+    // the rows own their values, so the text is moved into the target instead of being copied
+    assert!(matches!(labels[0].text, Cow::Owned(_)));
+
+    info!("Single values of other types");
+    let cow: Cow<str> = MValue::new_bigint(42).try_into().unwrap();
+    assert_eq!(cow, "42");
+    let cow: Cow<str> = MValue::new_raw_text(b"abc").try_into().unwrap();
+    assert_eq!(cow, "abc");
+
+    info!("Conversions from invalid UTF-8 follow the Utf8Policy");
+    let options = DeserializationOptions::default().with_utf8_policy(Utf8Policy::Lossy);
+    let cow: Cow<str> = MValue::new_raw_text(b"a\xffb")
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(cow, "a\u{fffd}b");
+    let result: mock_db::Result<Cow<str>> = MValue::new_raw_text(b"a\xffb").try_into();
+    assert!(result.is_err());
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{write_csv, write_csv_with, DeserializationError, DeserializationOptions};
+
+#[test] // cargo test --test test_csv -- --nocapture
+pub fn test_csv() {
+    let _loghandle = util::init_logger();
+    info!("=== CSV export ===");
+
+    info!("Header and rows, with quoting where necessary");
+    let mut rs = Resultset::new(&["id", "name", "price"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_string("plain".to_string()),
+        MValue::new_decimal(1995, -2),
+    ]);
+    rs.push(vec![
+        MValue::new_bigint(2),
+        MValue::new_string("with, comma and \"quotes\"".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_bigint(3),
+        MValue::new_string("two\nlines".to_string()),
+        MValue::new_double(0.5),
+    ]);
+    let mut out = Vec::<u8>::new();
+    let rows = write_csv(rs, &mut out).unwrap();
+    let csv = String::from_utf8(out).unwrap();
+    debug!("{csv}");
+    assert_eq!(rows, 3);
+    assert_eq!(
+        csv,
+        "id,name,price\r\n\
+         1,plain,19.95\r\n\
+         2,\"with, comma and \"\"quotes\"\"\",\r\n\
+         3,\"two\nlines\",0.5\r\n"
+    );
+
+    info!("Empty resultsets produce only the header");
+    let mut out = Vec::<u8>::new();
+    assert_eq!(write_csv(Resultset::new(&["a", "b"]), &mut out).unwrap(), 0);
+    assert_eq!(out, b"a,b\r\n");
+
+    info!("Options apply");
+    let mut rs = Resultset::new(&["n"]);
+    rs.push(vec![MValue::new_bigint(1)]);
+    rs.push(vec![MValue::new_bigint(2)]);
+    let options = DeserializationOptions::default().with_max_rows(1);
+    let mut out = Vec::<u8>::new();
+    let result = write_csv_with(rs, &mut out, &options);
+    assert!(matches!(
+        result,
+        Err(DeserializationError::RowLimitExceeded(1))
+    ));
+    assert_eq!(out, b"n\r\n1\r\n");
+
+    info!("Values that cannot be converted into text");
+    let mut rs = Resultset::new(&["list"]);
+    rs.push(vec![MValue::new_array(vec![MValue::new_short(1)])]);
+    let result = write_csv(rs, &mut Vec::<u8>::new());
+    debug!("{result:?}");
+    assert!(result.is_err());
+}
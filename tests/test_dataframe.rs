@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+#[test] // cargo test --test test_dataframe --features polars -- --nocapture
+pub fn test_dataframe() {
+    let _loghandle = util::init_logger();
+    info!("=== Polars data frames ===");
+
+    #[cfg(feature = "polars")]
+    dataframe::conversion();
+}
+
+#[cfg(feature = "polars")]
+mod dataframe {
+    use crate::mock_db::{self, MValue, Resultset};
+    use polars::prelude::DataType;
+    use serde_db::de::{DeserializableResultset, DeserializationError};
+
+    pub fn conversion() {
+        info!("Column types follow the kinds of the values");
+        let df = get_resultset().into_dataframe().unwrap();
+        debug!("{df:?}");
+        assert_eq!(df.height(), 3);
+        let types: Vec<(&str, &DataType)> = df
+            .columns()
+            .iter()
+            .map(|column| (column.name().as_str(), column.dtype()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("id", &DataType::Int64),
+                ("name", &DataType::String),
+                ("price", &DataType::Float64),
+                ("note", &DataType::Null),
+            ]
+        );
+        let ids = df.column("id").unwrap().i64().unwrap();
+        assert_eq!(
+            (0..3).map(|idx| ids.get(idx)).collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(3)]
+        );
+        let names = df.column("name").unwrap().str().unwrap();
+        assert_eq!(
+            (0..3).map(|idx| names.get(idx)).collect::<Vec<_>>(),
+            vec![Some("ABC"), None, Some("QRS")]
+        );
+        let prices = df.column("price").unwrap().f64().unwrap();
+        assert_eq!(prices.get(2), Some(0.25));
+
+        info!("Empty resultset");
+        let df = Resultset::new(&["id", "name"]).into_dataframe().unwrap();
+        assert_eq!(df.shape(), (0, 2));
+
+        info!("Values that don't fit to the type of their column");
+        let mut rs = Resultset::new(&["id"]);
+        rs.push(vec![MValue::new_short(1)]);
+        rs.push(vec![MValue::new_string("one".to_string())]);
+        let result: mock_db::Result<_> = rs.into_dataframe();
+        debug!("{result:?}");
+        assert!(matches!(
+            result,
+            Err(mock_db::Error::DESERIALIZATION(DeserializationError::AtField { ref path, .. }))
+                if path == "id"
+        ));
+    }
+
+    fn get_resultset() -> Resultset {
+        let mut rs = Resultset::new(&["id", "name", "price", "note"]);
+        rs.push(vec![
+            MValue::new_short(1),
+            MValue::new_string("ABC".to_string()),
+            MValue::new_double(10.5),
+            MValue::new_null(),
+        ]);
+        rs.push(vec![
+            MValue::new_bigint(2),
+            MValue::new_null(),
+            MValue::new_double(20.0),
+            MValue::new_null(),
+        ]);
+        rs.push(vec![
+            MValue::new_short(3),
+            MValue::new_string("QRS".to_string()),
+            MValue::new_double(0.25),
+            MValue::new_null(),
+        ]);
+        rs
+    }
+}
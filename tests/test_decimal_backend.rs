@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::MValue;
+use serde_db::de::{DecimalBackend, DecimalParts};
+use std::fmt::Debug;
+
+#[test] // cargo test --test test_decimal_backend -- --nocapture
+pub fn test_decimal_backend() {
+    let _loghandle = util::init_logger();
+    info!("=== Decimal backends ===");
+
+    info!("DecimalParts");
+    check_backend::<DecimalParts>();
+    assert_eq!(
+        DecimalParts::from_decimal_str("1.5E-3").unwrap(),
+        DecimalParts::new(15, -4)
+    );
+
+    #[cfg(feature = "bigdecimal")]
+    {
+        info!("BigDecimal");
+        check_backend::<bigdecimal::BigDecimal>();
+    }
+}
+
+// The conversion tests that every backend must pass.
+fn check_backend<D: DecimalBackend + PartialEq + Debug>() {
+    let d = |mantissa, exponent| D::from_decimal_parts(DecimalParts::new(mantissa, exponent));
+
+    // lossless conversion of decimal values
+    let big = 12_345_678_901_234_567_890_123_456_789_i128;
+    assert_eq!(
+        D::from_db_value(MValue::new_decimal(big, -9)).unwrap(),
+        d(big, -9).unwrap()
+    );
+
+    // parsing of other values
+    for (text, mantissa, exponent) in [
+        ("0", 0, 0),
+        ("-12.345", -12_345, -3),
+        ("+0.005", 5, -3),
+        (" 1200 ", 1200, 0),
+        ("1.5e3", 15, 2),
+    ] {
+        assert_eq!(
+            D::from_db_value(MValue::new_string(text.to_string())).unwrap(),
+            d(mantissa, exponent).unwrap(),
+            "{text}"
+        );
+    }
+    assert_eq!(
+        D::from_db_value(MValue::new_short(-7)).unwrap(),
+        d(-7, 0).unwrap()
+    );
+    for invalid in ["", "-", "1.2.3", "12a", "e5", "NaN"] {
+        assert!(
+            D::from_db_value(MValue::new_string(invalid.to_string())).is_err(),
+            "{invalid}"
+        );
+    }
+}
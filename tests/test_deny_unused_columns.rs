@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationError, DeserializationOptions, UnknownColumnPolicy};
+
+#[derive(Debug, Deserialize)]
+struct Narrow {
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Wide {
+    id: i64,
+    name: String,
+    remark: Option<String>,
+}
+
+#[test] // cargo test --test test_deny_unused_columns -- --nocapture
+pub fn test_deny_unused_columns() {
+    let _loghandle = util::init_logger();
+    info!("=== Deny unused columns ===");
+    let strict = DeserializationOptions::default().with_deny_unused_columns(true);
+    assert!(strict.deny_unused_columns());
+
+    info!("All columns used");
+    let lines: Vec<Wide> = get_resultset(2).try_into_with(&strict).unwrap();
+    assert_eq!(lines[1].remark.as_deref(), Some("remark 2"));
+
+    info!("Ignored columns");
+    let options = strict
+        .clone()
+        .with_unknown_column_policy(UnknownColumnPolicy::Ignore);
+    let result: mock_db::Result<Vec<Narrow>> = get_resultset(2).try_into_with(&options);
+    assert_unused(result, &["remark"]);
+
+    info!("Masked columns");
+    let options = strict.clone().with_field_mask(&["id", "name"]);
+    let result: mock_db::Result<Vec<Wide>> = get_resultset(2).try_into_with(&options);
+    assert_unused(result, &["remark"]);
+    let lines: Vec<Wide> = get_resultset(2)
+        .try_into_with(&DeserializationOptions::default().with_field_mask(&["id", "name"]))
+        .unwrap();
+    assert_eq!(lines[1].remark, None);
+
+    info!("Columns left over with positional assignment");
+    let result: mock_db::Result<Vec<Narrow>> =
+        get_resultset_without_names(2).try_into_with(&strict);
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::UnusedColumns(_)
+        ))
+    ));
+    let lines: Vec<Narrow> = get_resultset_without_names(2).try_into().unwrap();
+    assert_eq!(lines[1].name, "name 2");
+}
+
+fn assert_unused<T: std::fmt::Debug>(result: mock_db::Result<T>, expected: &[&str]) {
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::UnusedColumns(columns))) => {
+            assert_eq!(columns, expected);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    fill(Resultset::new(&["id", "name", "remark"]), rows)
+}
+
+fn get_resultset_without_names(rows: i64) -> Resultset {
+    fill(
+        Resultset::new(&["id", "name", "remark"]).without_fieldnames(),
+        rows,
+    )
+}
+
+fn fill(mut rs: Resultset, rows: i64) -> Resultset {
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("name {i}")),
+            MValue::new_string(format!("remark {i}")),
+        ]);
+    }
+    rs
+}
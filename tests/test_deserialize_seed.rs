@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::de::{DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializableRow};
+use std::collections::HashMap;
+use std::fmt;
+
+// A target type that refers to strings in an external interner.
+#[derive(Debug, PartialEq)]
+struct Symbol(usize);
+
+#[derive(Default)]
+struct Interner(HashMap<String, usize>);
+impl Interner {
+    fn intern(&mut self, s: String) -> Symbol {
+        let len = self.0.len();
+        Symbol(*self.0.entry(s).or_insert(len))
+    }
+}
+
+// Deserializes a single-column row into a Symbol.
+struct SymbolSeed<'a>(&'a mut Interner);
+impl<'de> DeserializeSeed<'de> for SymbolSeed<'_> {
+    type Value = Symbol;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Symbol, D::Error> {
+        Ok(self.0.intern(String::deserialize(deserializer)?))
+    }
+}
+
+// Deserializes a single-column resultset into a Vec of Symbols.
+struct SymbolsSeed<'a>(&'a mut Interner);
+impl<'de> DeserializeSeed<'de> for SymbolsSeed<'_> {
+    type Value = Vec<Symbol>;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<Symbol>, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+impl<'de> Visitor<'de> for SymbolsSeed<'_> {
+    type Value = Vec<Symbol>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of strings")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<Symbol>, A::Error> {
+        let mut symbols = Vec::new();
+        while let Some(s) = seq.next_element::<String>()? {
+            symbols.push(self.0.intern(s));
+        }
+        Ok(symbols)
+    }
+}
+
+#[test] // cargo test --test test_deserialize_seed -- --nocapture
+pub fn test_deserialize_seed() {
+    let _loghandle = util::init_logger();
+    info!("=== DeserializeSeed ===");
+
+    let mut interner = Interner::default();
+
+    info!("Resultsets");
+    let symbols = get_resultset(&["red", "green", "red"])
+        .try_into_seed(SymbolsSeed(&mut interner))
+        .unwrap();
+    assert_eq!(symbols, vec![Symbol(0), Symbol(1), Symbol(0)]);
+
+    info!("Rows");
+    let mut rs = get_resultset(&["blue", "green"]);
+    let row = rs.next().unwrap();
+    assert_eq!(
+        row.try_into_seed(SymbolSeed(&mut interner)).unwrap(),
+        Symbol(2)
+    );
+    let row = rs.next().unwrap();
+    assert_eq!(
+        row.try_into_seed(SymbolSeed(&mut interner)).unwrap(),
+        Symbol(1)
+    );
+    assert_eq!(interner.0.len(), 3);
+
+    info!("Plain types with PhantomData");
+    let colors: Vec<String> = DeserializableResultset::try_into_seed(
+        get_resultset(&["red", "green"]),
+        std::marker::PhantomData,
+    )
+    .unwrap();
+    assert_eq!(colors, vec!["red", "green"]);
+}
+
+fn get_resultset(colors: &[&str]) -> Resultset {
+    let mut rs = Resultset::new(&["color"]);
+    for color in colors {
+        rs.push(vec![MValue::new_string((*color).to_string())]);
+    }
+    rs
+}
@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializableResultset, DeserializableRow};
+
+#[test] // cargo test --test test_dimensions -- --nocapture
+pub fn test_dimensions() {
+    let _loghandle = util::init_logger();
+    info!("=== Dimensions of rows and resultsets ===");
+
+    let mut rs = Resultset::new(&["f1", "f2", "f3"]);
+    for i in 0..3 {
+        rs.push(vec![
+            MValue::new_short(i),
+            MValue::new_short(i * 10),
+            MValue::new_string(format!("{i}")),
+        ]);
+    }
+    assert_eq!(DeserializableResultset::number_of_columns(&rs), 3);
+    assert!(!rs.has_no_rows().unwrap());
+
+    info!("Rows");
+    let mut row = rs.next().unwrap();
+    assert_eq!(DeserializableRow::number_of_columns(&row), 3);
+    assert!(!DeserializableRow::is_empty(&row));
+    let f1: i32 = row.next_try_into().unwrap();
+    assert_eq!(f1, 0);
+    let rest: (i32, String) = row.try_into().unwrap();
+    assert_eq!(rest, (0, "0".to_string()));
+
+    info!("Consumed resultset");
+    let data: Vec<(i32, i32, String)> = DeserializableResultset::try_into(&mut rs).unwrap();
+    assert_eq!(data.len(), 2);
+    assert!(rs.has_no_rows().unwrap());
+    assert_eq!(DeserializableResultset::number_of_columns(&rs), 3);
+}
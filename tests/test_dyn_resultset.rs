@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    BufferedResultset, DeserializableResultset, DeserializableRow, DeserializationError,
+    DeserializationResult, DynResultset,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    id: i64,
+    name: String,
+}
+
+#[test] // cargo test --test test_dyn_resultset -- --nocapture
+pub fn test_dyn_resultset() {
+    let _loghandle = util::init_logger();
+    info!("=== Type-erased resultsets ===");
+
+    info!("Resultsets of different drivers");
+    let resultsets: Vec<Box<dyn DynResultset<MValue>>> = vec![
+        Box::new(get_mock_resultset(3)),
+        Box::new(get_buffered_resultset(2)),
+    ];
+    let counts = resultsets
+        .into_iter()
+        .map(|rs| {
+            assert_eq!(rs.number_of_fields(), 2);
+            assert_eq!(rs.fieldname(1), Some("name"));
+            let lines: Vec<Line> = DeserializableResultset::try_into(rs).unwrap();
+            assert_eq!(lines[0].id, 1);
+            assert_eq!(lines[0].name, "name 1");
+            lines.len()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(counts, vec![3, 2]);
+
+    info!("Type-erased rows");
+    let mut rs: Box<dyn DynResultset<MValue> + Send> = Box::new(get_buffered_resultset(2));
+    let row = rs.next().unwrap().unwrap();
+    assert_eq!(row.len(), 2);
+    let line: Line = DeserializableRow::try_into(row).unwrap();
+    assert_eq!(line.id, 1);
+    let line: Line = DeserializableResultset::try_into(rs).unwrap();
+    assert_eq!(line.id, 2);
+
+    info!("Errors");
+    let rs: Box<dyn DynResultset<MValue>> = Box::new(get_mock_resultset(3));
+    let result: DeserializationResult<Line> = DeserializableResultset::try_into(rs);
+    assert!(matches!(result, Err(DeserializationError::TrailingRows)));
+}
+
+fn get_mock_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "name"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("name {i}")),
+        ]);
+    }
+    rs
+}
+
+fn get_buffered_resultset(
+    rows: i64,
+) -> BufferedResultset<impl FnMut() -> DeserializationResult<Option<Vec<Vec<MValue>>>>> {
+    let mut chunks = (1..=rows)
+        .map(|i| {
+            vec![vec![
+                MValue::new_bigint(i),
+                MValue::new_string(format!("name {i}")),
+            ]]
+        })
+        .collect::<Vec<_>>()
+        .into_iter();
+    BufferedResultset::new(
+        Arc::new(vec!["id".to_string(), "name".to_string()]),
+        move || Ok(chunks.next()),
+    )
+}
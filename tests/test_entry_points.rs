@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, FieldNameMatching};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Data {
+    id: i32,
+    name: String,
+}
+
+#[test] // cargo test --test test_entry_points -- --nocapture
+pub fn test_entry_points() {
+    let _loghandle = util::init_logger();
+    info!("=== Free-function entry points ===");
+
+    info!("from_resultset");
+    let data: Vec<Data> = serde_db::de::from_resultset(get_resultset(&["id", "name"])).unwrap();
+    assert_eq!(data.len(), 2);
+    assert_eq!(data[1].name, "second");
+
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive);
+    let data: Vec<Data> =
+        serde_db::de::from_resultset_with(get_resultset(&["ID", "NAME"]), &options).unwrap();
+    assert_eq!(data[0].id, 1);
+
+    info!("from_row");
+    let mut rs = get_resultset(&["id", "name"]);
+    let row = rs.next().unwrap();
+    let data: Data = serde_db::de::from_row(row).unwrap();
+    assert_eq!(
+        data,
+        Data {
+            id: 1,
+            name: "first".to_string()
+        }
+    );
+    let mut rs = get_resultset(&["ID", "NAME"]);
+    let row = rs.next().unwrap();
+    let data: Data = serde_db::de::from_row_with(row, &options).unwrap();
+    assert_eq!(data.id, 1);
+
+    info!("from_value");
+    let i: i32 = serde_db::de::from_value(MValue::new_short(42)).unwrap();
+    assert_eq!(i, 42);
+    let s: Option<String> =
+        serde_db::de::from_value_with(MValue::new_null(), &DeserializationOptions::default())
+            .unwrap();
+    assert!(s.is_none());
+    assert!(serde_db::de::from_value::<_, i32>(MValue::new_null()).is_err());
+}
+
+fn get_resultset(fields: &[&'static str]) -> Resultset {
+    let mut rs = Resultset::new(fields);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_string("first".to_string()),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_string("second".to_string()),
+    ]);
+    rs
+}
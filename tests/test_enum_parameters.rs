@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde::Serialize;
+use serde_db::ser::{
+    to_named_params, to_params, to_params_with, EnumRepresentation, SerializationError,
+    SerializationOptions,
+};
+
+#[derive(Clone, Copy, Serialize)]
+enum Status {
+    Active,
+    #[serde(rename = "ON_HOLD")]
+    OnHold,
+    Closed,
+}
+
+#[test] // cargo test --test test_enum_parameters -- --nocapture
+pub fn test_enum_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== enum parameters ===");
+
+    info!("The descriptor decides: by position for integer parameters, by name otherwise");
+    let values: Vec<MValue> = to_params(
+        &(Status::Closed, Status::OnHold, Some(Status::Active)),
+        &mut [PT::Short, PT::String, PT::NullableString].iter(),
+    )
+    .unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(2),
+            MValue::String("ON_HOLD".to_string()),
+            MValue::String("Active".to_string()),
+        ]
+    );
+
+    info!("The options of the call take precedence");
+    let by_name =
+        SerializationOptions::default().with_enum_representation(EnumRepresentation::Name);
+    let result = to_params_with(&(Status::Closed,), &mut [PT::Short].iter(), &by_name);
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "str", .. })
+    ));
+    let by_index =
+        SerializationOptions::default().with_enum_representation(EnumRepresentation::Index);
+    let result = to_params_with(&(Status::Closed,), &mut [PT::String].iter(), &by_index);
+    assert!(result.is_err());
+    let values: Vec<MValue> = to_params_with(
+        &(Status::OnHold,),
+        &mut [PT::NullableShort].iter(),
+        &by_index,
+    )
+    .unwrap();
+    assert_eq!(values, vec![MValue::Short(1)]);
+
+    info!("Named parameters and length checks apply to enums as well");
+    #[derive(Serialize)]
+    struct Ticket {
+        status: Status,
+        priority: Status,
+    }
+    let parameters = [
+        Parameter::new("priority", PT::Short),
+        Parameter::new("status", PT::String).with_max_length(6),
+    ];
+    let ticket = Ticket {
+        status: Status::Active,
+        priority: Status::OnHold,
+    };
+    let values: Vec<MValue> = to_named_params(&ticket, &mut parameters.iter()).unwrap();
+    assert_eq!(
+        values,
+        vec![MValue::Short(1), MValue::String("Active".to_string())]
+    );
+    let ticket = Ticket {
+        status: Status::OnHold,
+        priority: Status::Closed,
+    };
+    let result = to_named_params(&ticket, &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(result.is_err());
+}
@@ -0,0 +1,123 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationError};
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+enum Status {
+    Active,
+    Inactive,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+enum Level {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    status: Status,
+    level: Level,
+    previous: Option<Status>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum Limit {
+    Max(i64),
+    Named(String),
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+enum Quota {
+    None,
+    Max(i64),
+}
+
+#[test] // cargo test --test test_enums -- --nocapture
+pub fn test_enums() {
+    let _loghandle = util::init_logger();
+    info!("=== Enums from string and integer columns ===");
+
+    info!("Rows into structs with enum fields");
+    let data: Vec<TestData> = get_resultset().try_into().unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[0].status, Status::Active);
+    assert_eq!(data[0].level, Level::Low);
+    assert_eq!(data[0].previous, None);
+    assert_eq!(data[1].status, Status::Inactive);
+    assert_eq!(data[1].level, Level::High);
+    assert_eq!(data[1].previous, Some(Status::Active));
+
+    info!("Single column into Vec of enums");
+    let mut rs = Resultset::new(&["level"]);
+    for i in [2, 1, 0] {
+        rs.push(vec![MValue::new_short(i)]);
+    }
+    let levels: Vec<Level> = rs.try_into().unwrap();
+    assert_eq!(levels, vec![Level::High, Level::Medium, Level::Low]);
+
+    info!("Single values");
+    let level: Level = MValue::new_string("Medium".to_string()).try_into().unwrap();
+    assert_eq!(level, Level::Medium);
+
+    info!("Columns of enums");
+    let levels: Vec<Level> = get_resultset().column_into("level").unwrap();
+    assert_eq!(levels, vec![Level::Low, Level::High]);
+
+    info!("Variants with data, from untagged enums");
+    let limits: Vec<Limit> = get_limits().try_into().unwrap();
+    assert_eq!(
+        limits,
+        vec![Limit::Max(5), Limit::Named("unlimited".to_string())]
+    );
+
+    info!("Variants with data cannot be identified by a plain value");
+    let result: mock_db::Result<Quota> = MValue::new_string("Max".to_string()).try_into();
+    debug!("{:?}", result);
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::Usage(ref s)))
+            if s.starts_with("variant Max of enum Quota has data")
+    ));
+    let quota: Quota = MValue::new_string("None".to_string()).try_into().unwrap();
+    assert_eq!(quota, Quota::None);
+
+    info!("Unknown variants");
+    let result: mock_db::Result<Status> = MValue::new_string("GONE".to_string()).try_into();
+    debug!("{:?}", result);
+    assert!(result.is_err());
+    let result: mock_db::Result<Level> = MValue::new_short(3).try_into();
+    debug!("{:?}", result);
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["status", "level", "previous"]);
+    rs.push(vec![
+        MValue::new_string("ACTIVE".to_string()),
+        MValue::new_short(0),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_string("INACTIVE".to_string()),
+        MValue::new_short(2),
+        MValue::new_string("ACTIVE".to_string()),
+    ]);
+    rs
+}
+
+fn get_limits() -> Resultset {
+    let mut rs = Resultset::new(&["limit"]);
+    rs.push(vec![MValue::new_bigint(5)]);
+    rs.push(vec![MValue::new_string("unlimited".to_string())]);
+    rs
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Deserialize)]
+struct Event {
+    #[serde(deserialize_with = "serde_db::de::epoch::seconds")]
+    created_at: DateTime<Utc>,
+    #[serde(deserialize_with = "serde_db::de::epoch::millis")]
+    updated_at: SystemTime,
+    #[serde(deserialize_with = "serde_db::de::epoch::option_micros")]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[test] // cargo test --test test_epoch -- --nocapture
+pub fn test_epoch() {
+    let _loghandle = util::init_logger();
+    info!("=== Temporal values from epoch integers ===");
+
+    let mut rs = Resultset::new(&["created_at", "updated_at", "deleted_at"]);
+    rs.push(vec![
+        MValue::new_bigint(1_700_000_000),
+        MValue::new_bigint(1_700_000_000_123),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_bigint(-86_400),
+        MValue::new_bigint(0),
+        MValue::new_bigint(1_000_000_500_000),
+    ]);
+    let events: Vec<Event> = rs.try_into().unwrap();
+    debug!("{:?}", events);
+    assert_eq!(
+        events[0].created_at,
+        Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20).unwrap()
+    );
+    assert_eq!(
+        events[0].updated_at,
+        UNIX_EPOCH + Duration::from_millis(1_700_000_000_123)
+    );
+    assert_eq!(events[0].deleted_at, None);
+    assert_eq!(
+        events[1].created_at,
+        Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap()
+    );
+    assert_eq!(events[1].updated_at, UNIX_EPOCH);
+    assert_eq!(
+        events[1].deleted_at,
+        Some(
+            Utc.with_ymd_and_hms(1970, 1, 12, 13, 46, 40).unwrap()
+                + chrono::Duration::milliseconds(500)
+        )
+    );
+
+    info!("Errors");
+    let mut rs = Resultset::new(&["created_at", "updated_at", "deleted_at"]);
+    rs.push(vec![
+        MValue::new_string("2023-11-14".to_string()),
+        MValue::new_bigint(0),
+        MValue::new_null(),
+    ]);
+    let result: mock_db::Result<Vec<Event>> = rs.try_into();
+    assert!(result.is_err());
+}
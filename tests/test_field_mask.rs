@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::DeserializationOptions;
+
+#[derive(Debug, Deserialize)]
+struct Product {
+    id: i32,
+    name: String,
+    #[serde(default)]
+    description: String,
+    price: Option<f64>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[test] // cargo test --test test_field_mask -- --nocapture
+pub fn test_field_mask() {
+    let _loghandle = util::init_logger();
+    info!("=== Runtime field mask ===");
+
+    info!("List view");
+    let options = DeserializationOptions::default().with_field_mask(&["id", "name"]);
+    let list: Vec<Product> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", list);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[1].id, 2);
+    assert_eq!(list[1].name, "Gadget");
+    assert_eq!(list[1].description, "");
+    assert_eq!(list[1].price, None);
+    assert!(list[1].tags.is_empty());
+
+    info!("Detail view");
+    let details: Vec<Product> = get_resultset().try_into().unwrap();
+    assert_eq!(details[1].description, "Does things");
+    assert_eq!(details[1].price, Some(9.5));
+    assert_eq!(details[1].tags, vec!["new".to_string()]);
+
+    info!("Masked-out columns are not converted");
+    let mut rs = Resultset::new(&["id", "name", "price"]);
+    rs.push(vec![
+        MValue::new_short(3),
+        MValue::new_string("Broken".to_string()),
+        MValue::new_string("not a number".to_string()),
+    ]);
+    let options = DeserializationOptions::default().with_field_mask(&["id", "name"]);
+    let product: Product = rs.try_into_with(&options).unwrap();
+    assert_eq!(product.name, "Broken");
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "name", "description", "price", "tags"]);
+    for (id, name, description, price, tags) in [
+        (1, "Widget", "Is useful", 1.25, vec![]),
+        (2, "Gadget", "Does things", 9.5, vec!["new"]),
+    ] {
+        rs.push(vec![
+            MValue::new_short(id),
+            MValue::new_string(name.to_string()),
+            MValue::new_string(description.to_string()),
+            MValue::new_double(price),
+            MValue::new_array(
+                tags.into_iter()
+                    .map(|tag| MValue::new_string(tag.to_string()))
+                    .collect(),
+            ),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, FieldNameMatching};
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    customer_id: i32,
+    order_name: String,
+    delivery: Option<Address>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[test] // cargo test --test test_field_name_matching -- --nocapture
+pub fn test_field_name_matching() {
+    let _loghandle = util::init_logger();
+    info!("=== Field-name matching ===");
+
+    info!("Exact matching fails");
+    let result: mock_db::Result<Vec<Order>> = get_resultset(&["CUSTOMER_ID", "ORDER_NAME"])
+        .try_into_with(&DeserializationOptions::default());
+    assert!(result.is_err());
+
+    info!("Case-insensitive matching");
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive);
+    let orders: Vec<Order> = get_resultset(&["CUSTOMER_ID", "ORDER_NAME"])
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(orders[1].customer_id, 2);
+    assert_eq!(orders[1].order_name, "order 2");
+    assert!(orders[1].delivery.is_none());
+
+    info!("snake_case-normalized matching");
+    let options =
+        DeserializationOptions::default().with_field_name_matching(FieldNameMatching::SnakeCase);
+    for columns in [["customerId", "OrderName"], ["CUSTOMER_ID", "order-name"]] {
+        let orders: Vec<Order> = get_resultset(&columns).try_into_with(&options).unwrap();
+        assert_eq!(orders[0].customer_id, 1);
+        assert_eq!(orders[0].order_name, "order 1");
+    }
+    let result: mock_db::Result<Vec<Order>> =
+        get_resultset(&["CUSTOMERID", "ORDER_NAME"]).try_into_with(&options);
+    assert!(result.is_err());
+
+    info!("Custom matching");
+    let options = DeserializationOptions::default().with_field_name_matching(
+        FieldNameMatching::Custom(Arc::new(|column, field| {
+            column
+                .strip_prefix("O_")
+                .is_some_and(|c| c.eq_ignore_ascii_case(field))
+        })),
+    );
+    let orders: Vec<Order> = get_resultset(&["O_CUSTOMER_ID", "O_ORDER_NAME"])
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(orders[0].order_name, "order 1");
+    debug!("{:?}", options);
+
+    info!("Matching of prefixes of nested structs");
+    let options = DeserializationOptions::default()
+        .with_prefix_separator("_")
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive);
+    let mut rs = Resultset::new(&[
+        "CUSTOMER_ID",
+        "ORDER_NAME",
+        "DELIVERY_STREET",
+        "DELIVERY_CITY",
+    ]);
+    rs.push(vec![
+        MValue::new_short(7),
+        MValue::new_string("order 7".to_string()),
+        MValue::new_string("Main St".to_string()),
+        MValue::new_string("Springfield".to_string()),
+    ]);
+    let orders: Vec<Order> = rs.try_into_with(&options).unwrap();
+    assert_eq!(orders[0].customer_id, 7);
+    assert_eq!(
+        orders[0].delivery,
+        Some(Address {
+            street: "Main St".to_string(),
+            city: "Springfield".to_string()
+        })
+    );
+}
+
+fn get_resultset(columns: &[&'static str]) -> Resultset {
+    let mut rs = Resultset::new(columns);
+    for i in 1..=2 {
+        rs.push(vec![
+            MValue::new_short(i),
+            MValue::new_string(format!("order {i}")),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,130 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{ConversionError, DeserializationError, DeserializationOptions};
+use serde_db::message::DefaultMessageFormatter;
+
+#[derive(Debug, Deserialize)]
+struct Address {
+    street: String,
+    number: i64,
+}
+
+#[cfg(feature = "json")]
+#[derive(Debug, Deserialize)]
+struct Item {
+    name: String,
+    qty: i64,
+}
+
+#[cfg(feature = "json")]
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: i64,
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Customer {
+    id: i64,
+    address: Address,
+    scores: Vec<i64>,
+}
+
+#[test] // cargo test --test test_field_paths -- --nocapture
+pub fn test_field_paths() {
+    let _loghandle = util::init_logger();
+    info!("=== Field paths in errors ===");
+    let options = DeserializationOptions::default()
+        .with_prefix_separator("_")
+        .with_field_paths(true);
+
+    info!("Valid data");
+    let customers: Vec<Customer> = get_resultset(MValue::new_bigint(7), MValue::new_bigint(1))
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(customers[0].address.number, 7);
+
+    info!("Nested structs");
+    let e = get_error(
+        get_resultset(
+            MValue::new_string("seven".to_string()),
+            MValue::new_bigint(1),
+        ),
+        &options,
+    );
+    assert_eq!(e.field_path(), Some("address.number"));
+    assert!(matches!(e.leaf(), DeserializationError::ConversionError(_)));
+    assert!(e
+        .render(&DefaultMessageFormatter)
+        .starts_with("at address.number: "));
+
+    info!("Array columns");
+    let e = get_error(
+        get_resultset(MValue::new_bigint(7), MValue::new_string("one".to_string())),
+        &options,
+    );
+    assert_eq!(e.field_path(), Some("scores[1]"));
+
+    #[cfg(feature = "json")]
+    json_columns(&options);
+
+    info!("Without the option, the plain error is produced");
+    let e = get_error(
+        get_resultset(
+            MValue::new_string("seven".to_string()),
+            MValue::new_bigint(1),
+        ),
+        &DeserializationOptions::default().with_prefix_separator("_"),
+    );
+    assert!(matches!(
+        e,
+        DeserializationError::ConversionError(ConversionError::ValueType(_))
+    ));
+    assert_eq!(e.field_path(), None);
+}
+
+fn get_error(rs: Resultset, options: &DeserializationOptions) -> DeserializationError {
+    let result: mock_db::Result<Vec<Customer>> = rs.try_into_with(options);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(e)) => e,
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+// Errors within JSON documents have the path of the column
+// and the position within the document.
+#[cfg(feature = "json")]
+fn json_columns(options: &DeserializationOptions) {
+    info!("JSON columns");
+    let mut rs = Resultset::new(&["id", "items"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_json(r#"[{"name": "pen", "qty": 2}, {"name": "ink", "qty": "many"}]"#),
+    ]);
+    let result: mock_db::Result<Vec<Order>> = rs.try_into_with(options);
+    let Err(mock_db::Error::DESERIALIZATION(e)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(e.field_path(), Some("items"));
+    let DeserializationError::ConversionError(ConversionError::Json(message)) = e.leaf() else {
+        panic!("unexpected error: {e:?}");
+    };
+    assert!(message.contains("column"), "{message}");
+}
+
+fn get_resultset(number: MValue, score: MValue) -> Resultset {
+    let mut rs = Resultset::new(&["id", "address_street", "address_number", "scores"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_string("Main Street".to_string()),
+        number,
+        MValue::new_array(vec![MValue::new_bigint(0), score]),
+    ]);
+    rs
+}
@@ -0,0 +1,73 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    DeserializationObserver, DeserializationOptions, DeserializationWarning, FieldnameFallback,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    name: String,
+    note: Option<String>,
+}
+
+// Collects the warnings.
+#[derive(Default)]
+struct Warnings(Mutex<Vec<DeserializationWarning>>);
+
+impl DeserializationObserver for Warnings {
+    fn warning(&self, warning: &DeserializationWarning) {
+        self.0.lock().unwrap().push(*warning);
+    }
+}
+
+#[test] // cargo test --test test_fieldname_fallback -- --nocapture
+pub fn test_fieldname_fallback() {
+    let _loghandle = util::init_logger();
+    info!("=== Rows without field names ===");
+
+    info!("Positional fallback");
+    let warnings = Arc::new(Warnings::default());
+    let options = DeserializationOptions::default().with_observer(warnings.clone());
+    let data: Vec<TestData> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[1].id, 1);
+    assert_eq!(data[1].name, "name 1");
+    assert_eq!(data[1].note, None);
+    // the warning is reported once per resultset
+    assert_eq!(
+        *warnings.0.lock().unwrap(),
+        vec![DeserializationWarning::PositionalFallback {
+            struct_name: "TestData"
+        }]
+    );
+
+    info!("Tuples are not affected");
+    let data: Vec<(i32, String, Option<String>)> = get_resultset().try_into().unwrap();
+    assert_eq!(data[0].1, "name 0");
+
+    info!("Failing");
+    let options =
+        DeserializationOptions::default().with_fieldname_fallback(FieldnameFallback::Error);
+    let result: mock_db::Result<Vec<TestData>> = get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "name", "note"]).without_fieldnames();
+    for i in 0..2 {
+        rs.push(vec![
+            MValue::new_short(i),
+            MValue::new_string(format!("name {i}")),
+            MValue::new_null(),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    DeserializableResultset, DeserializationError, DeserializationOptions, FieldNameMatching,
+};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Order {
+    id: i64,
+    item: String,
+}
+
+#[test] // cargo test --test test_first_into -- --nocapture
+pub fn test_first_into() {
+    let _loghandle = util::init_logger();
+    info!("=== first_into and at_most_one_into ===");
+
+    info!("first_into ignores the other rows");
+    let order: Order = get_resultset(3).first_into().unwrap();
+    assert_eq!(order.id, 1);
+    assert_eq!(order.item, "item 1");
+
+    info!("first_into fails on empty resultsets");
+    let result: mock_db::Result<Order> = get_resultset(0).first_into();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::NoRows
+        ))
+    ));
+
+    info!("at_most_one_into");
+    let order: Option<Order> = get_resultset(1).at_most_one_into().unwrap();
+    assert_eq!(order.unwrap().id, 1);
+    let order: Option<Order> = get_resultset(0).at_most_one_into().unwrap();
+    assert!(order.is_none());
+    let result: mock_db::Result<Option<Order>> = get_resultset(2).at_most_one_into();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::MultipleRows
+        ))
+    ));
+
+    info!("tuples");
+    let (id, item): (i64, String) = get_resultset(2).first_into().unwrap();
+    assert_eq!((id, item.as_str()), (1, "item 1"));
+
+    info!("first_into_with and at_most_one_into_with");
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive);
+    let order: Order = get_uppercase_resultset(3)
+        .first_into_with(&options)
+        .unwrap();
+    assert_eq!(order.id, 1);
+    let order: Option<Order> = get_uppercase_resultset(1)
+        .at_most_one_into_with(&options)
+        .unwrap();
+    assert_eq!(order.unwrap().item, "item 1");
+    assert!(get_uppercase_resultset(1).first_into::<Order>().is_err());
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "item"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("item {i}")),
+        ]);
+    }
+    rs
+}
+
+fn get_uppercase_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["ID", "ITEM"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("item {i}")),
+        ]);
+    }
+    rs
+}
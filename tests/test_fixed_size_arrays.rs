@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializationError, DeserializationOptions};
+
+#[test] // cargo test --test test_fixed_size_arrays -- --nocapture
+pub fn test_fixed_size_arrays() {
+    let _loghandle = util::init_logger();
+    info!("=== Fixed-size arrays ===");
+
+    info!("A row into an array");
+    let mut rs = Resultset::new(&["x", "y", "z"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_bigint(2),
+        MValue::new_bigint(3),
+    ]);
+    let point: [i64; 3] = rs.clone().try_into().unwrap();
+    assert_eq!(point, [1, 2, 3]);
+    let result: mock_db::Result<[i64; 4]> = rs.clone().try_into();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::Usage(ref s)))
+            if s == "the row has 3 columns, but the target has 4 elements"
+    ));
+
+    info!("Columns beyond the array are unused columns");
+    let prefix: [i64; 2] = rs.clone().try_into().unwrap();
+    assert_eq!(prefix, [1, 2]);
+    let options = DeserializationOptions::default().with_deny_unused_columns(true);
+    let result: mock_db::Result<[i64; 2]> = rs.try_into_with(&options);
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::UnusedColumns(_)
+        ))
+    ));
+
+    info!("A single-column resultset into an array");
+    let ids: [i64; 3] = get_ids(3).try_into().unwrap();
+    assert_eq!(ids, [0, 1, 2]);
+    let rows: [(i64,); 2] = get_ids(2).try_into().unwrap();
+    assert_eq!(rows, [(0,), (1,)]);
+
+    info!("The number of rows must match");
+    let result: mock_db::Result<[i64; 2]> = get_ids(3).try_into();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::Usage(ref s)))
+            if s == "the resultset has 3 rows, but the target has 2 elements"
+    ));
+    let result: mock_db::Result<[i64; 4]> = get_ids(3).try_into();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::Usage(_)
+        ))
+    ));
+}
+
+fn get_ids(n: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id"]);
+    for id in 0..n {
+        rs.push(vec![MValue::new_bigint(id)]);
+    }
+    rs
+}
@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+struct Customer {
+    id: i32,
+    name: String,
+    #[serde(flatten)]
+    address: Address,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Address {
+    street: String,
+    city: String,
+    zip: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WithExtras {
+    id: i32,
+    #[serde(flatten)]
+    extras: HashMap<String, Option<String>>,
+}
+
+#[test] // cargo test --test test_flatten -- --nocapture
+pub fn test_flatten() {
+    let _loghandle = util::init_logger();
+    info!("=== #[serde(flatten)] ===");
+
+    info!("Flattened sub-struct");
+    let customers: Vec<Customer> = get_resultset().try_into().unwrap();
+    debug!("{:?}", customers);
+    assert_eq!(customers[0].id, 1);
+    assert_eq!(customers[0].name, "Alice");
+    assert_eq!(
+        customers[0].address,
+        Address {
+            street: "Main Street 1".to_string(),
+            city: "Springfield".to_string(),
+            zip: None
+        }
+    );
+    assert_eq!(customers[1].address.zip, Some("12345".to_string()));
+
+    info!("Single row");
+    let customer: Customer = get_resultset().next().unwrap().try_into().unwrap();
+    assert_eq!(customer.address.city, "Springfield");
+
+    info!("Flattened map collects the remaining columns");
+    let data: Vec<WithExtras> = get_resultset().try_into().unwrap();
+    assert_eq!(data[1].id, 2);
+    assert_eq!(data[1].extras.len(), 4);
+    assert_eq!(data[1].extras["city"], Some("Shelbyville".to_string()));
+    assert_eq!(data[0].extras["zip"], None);
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "name", "street", "city", "zip"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_string("Alice".to_string()),
+        MValue::new_string("Main Street 1".to_string()),
+        MValue::new_string("Springfield".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_string("Bob".to_string()),
+        MValue::new_string("Side Road 2".to_string()),
+        MValue::new_string("Shelbyville".to_string()),
+        MValue::new_string("12345".to_string()),
+    ]);
+    rs
+}
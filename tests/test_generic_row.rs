@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::MValue;
+use serde::Deserialize;
+use serde_db::de::{DeserializableRow, Row};
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct Person {
+    id: i64,
+    name: String,
+    age: Option<i16>,
+}
+
+#[test] // cargo test --test test_generic_row -- --nocapture
+pub fn test_generic_row() {
+    let _loghandle = util::init_logger();
+    info!("=== Generic row ===");
+
+    let metadata = Arc::new(vec![
+        "id".to_string(),
+        "name".to_string(),
+        "age".to_string(),
+    ]);
+
+    info!("Deserialization");
+    let person: Person = DeserializableRow::try_into(get_row(&metadata)).unwrap();
+    assert_eq!(
+        (person.id, person.name.as_str(), person.age),
+        (1, "Ann", None)
+    );
+    let tuple: (i64, String, Option<i16>) =
+        DeserializableRow::try_into(get_row(&metadata)).unwrap();
+    assert_eq!(tuple.1, "Ann");
+
+    info!("Access to values");
+    let mut row = get_row(&metadata);
+    assert_eq!(row.column_index("name"), Some(1));
+    assert_eq!(
+        row.get_by_name("name"),
+        Some(&MValue::new_string("Ann".to_string()))
+    );
+    assert_eq!(row.get(2), Some(&MValue::new_null()));
+    assert_eq!(row.get(3), None);
+    assert_eq!(row.next(), Some(MValue::new_bigint(1)));
+    assert_eq!(row.len(), 2);
+    assert_eq!(row.get(0), None);
+    assert_eq!(
+        row.get_by_name("name"),
+        Some(&MValue::new_string("Ann".to_string()))
+    );
+    assert_eq!(
+        row.into_values(),
+        vec![MValue::new_string("Ann".to_string()), MValue::new_null()]
+    );
+
+    info!("Shared metadata");
+    let row = get_row(&metadata);
+    assert!(Arc::ptr_eq(row.metadata(), &metadata));
+}
+
+fn get_row(metadata: &Arc<Vec<String>>) -> Row<MValue> {
+    Row::new(
+        Arc::clone(metadata),
+        vec![
+            MValue::new_bigint(1),
+            MValue::new_string("Ann".to_string()),
+            MValue::new_null(),
+        ],
+    )
+}
@@ -0,0 +1,88 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use serde_db::de::DeserializationOptions;
+
+// Like many id or date types, chooses the representation of the value with
+// `is_human_readable()`, and remembers what it was told.
+#[derive(Debug, PartialEq)]
+enum Id {
+    Text(String),
+    Binary(Vec<u8>),
+}
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            String::deserialize(deserializer).map(Id::Text)
+        } else {
+            deserializer
+                .deserialize_byte_buf(BytesVisitor)
+                .map(Id::Binary)
+        }
+    }
+}
+
+struct BytesVisitor;
+impl serde::de::Visitor<'_> for BytesVisitor {
+    type Value = Vec<u8>;
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("bytes")
+    }
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+}
+
+struct HumanReadable(bool);
+impl<'de> Deserialize<'de> for HumanReadable {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let human_readable = deserializer.is_human_readable();
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(HumanReadable(human_readable))
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Item {
+    id: Id,
+}
+
+#[test] // cargo test --test test_human_readable -- --nocapture
+pub fn test_human_readable() {
+    let _loghandle = util::init_logger();
+    info!("=== Human readable ===");
+
+    info!("The deserializers are human readable by default");
+    let HumanReadable(human_readable) = MValue::new_bigint(1).try_into().unwrap();
+    assert!(human_readable);
+    let binary = DeserializationOptions::default().with_human_readable(false);
+    let HumanReadable(human_readable) = MValue::new_bigint(1).try_into_with(&binary).unwrap();
+    assert!(!human_readable);
+
+    info!("Values are requested in the configured representation");
+    let mut rs = Resultset::new(&["id"]);
+    rs.push(vec![MValue::new_string("abc".to_string())]);
+    let item: Item = rs.try_into().unwrap();
+    assert_eq!(item.id, Id::Text("abc".to_string()));
+
+    let mut rs = Resultset::new(&["id"]);
+    rs.push(vec![MValue::new_lob(vec![1, 2, 3])]);
+    let items: Vec<Item> = rs.try_into_with(&binary).unwrap();
+    assert_eq!(items[0].id, Id::Binary(vec![1, 2, 3]));
+
+    info!("Resultsets and rows follow the option as well");
+    let mut rs = Resultset::new(&["a", "b"]);
+    rs.push(vec![MValue::new_bigint(1), MValue::new_bigint(2)]);
+    rs.push(vec![MValue::new_bigint(3), MValue::new_bigint(4)]);
+    let HumanReadable(human_readable) = rs.try_into_with(&binary).unwrap();
+    assert!(!human_readable);
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct WithIgnoredField {
+    id: i64,
+    #[allow(dead_code)]
+    payload: IgnoredAny,
+}
+
+#[derive(Debug, Deserialize)]
+struct WithArray {
+    values: (i64, IgnoredAny),
+}
+
+#[test] // cargo test --test test_ignored_any -- --nocapture
+pub fn test_ignored_any() {
+    let _loghandle = util::init_logger();
+    info!("=== Ignored values ===");
+
+    info!("Ignored values are not converted");
+    let rs = get_resultset();
+    let rows: Vec<(i64, IgnoredAny, IgnoredAny)> = rs.try_into().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1].0, 2);
+
+    info!("Converting the same values fails");
+    let result: mock_db::Result<Vec<(i64, String, String)>> = get_resultset().try_into();
+    assert!(result.is_err());
+
+    info!("Struct fields of type IgnoredAny");
+    let rows: Vec<WithIgnoredField> = get_resultset_with_payload().try_into().unwrap();
+    assert_eq!(rows[0].id, 1);
+
+    info!("Ignored array elements");
+    let mut rs = Resultset::new(&["values"]);
+    rs.push(vec![MValue::new_array(vec![
+        MValue::new_bigint(1),
+        MValue::new_async_lob(vec![b"x".to_vec()]),
+    ])]);
+    let row: WithArray = rs.try_into().unwrap();
+    assert_eq!(row.values.0, 1);
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "raw", "lob"]);
+    for id in 1..=2 {
+        rs.push(vec![
+            MValue::new_bigint(id),
+            MValue::new_raw_text(b"\xff\xfe"),
+            MValue::new_async_lob(vec![b"chunk".to_vec()]),
+        ]);
+    }
+    rs
+}
+
+fn get_resultset_with_payload() -> Resultset {
+    let mut rs = Resultset::new(&["id", "payload"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_async_lob(vec![b"chunk".to_vec()]),
+    ]);
+    rs
+}
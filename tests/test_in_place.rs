@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializableRow};
+
+#[derive(Debug, Default, Deserialize)]
+struct Line {
+    id: i64,
+    name: String,
+}
+
+#[test] // cargo test --test test_in_place -- --nocapture
+pub fn test_in_place() {
+    let _loghandle = util::init_logger();
+    info!("=== Deserialization in place ===");
+
+    info!("Resultsets reuse the Vec");
+    let mut lines: Vec<Line> = Vec::with_capacity(10);
+    let buffer = lines.as_ptr();
+    get_resultset(1, 5).try_into_in_place(&mut lines).unwrap();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[4].name, "name 5");
+    get_resultset(11, 3).try_into_in_place(&mut lines).unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].id, 11);
+    assert_eq!(lines[2].name, "name 13");
+    assert_eq!(lines.as_ptr(), buffer);
+
+    info!("Rows reuse the struct");
+    let mut line = Line::default();
+    let mut rs = get_resultset(1, 3);
+    while let Some(row) = rs.next() {
+        let id = line.id;
+        row.try_into_in_place(&mut line).unwrap();
+        assert_eq!(line.id, id + 1);
+        assert_eq!(line.name, format!("name {}", line.id));
+    }
+
+    info!("Conversion errors");
+    let mut rs = Resultset::new(&["id", "name"]);
+    rs.push(vec![
+        MValue::new_string("x".to_string()),
+        MValue::new_string("name x".to_string()),
+    ]);
+    assert!(DeserializableResultset::try_into_in_place(rs, &mut lines).is_err());
+}
+
+fn get_resultset(first: i64, rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "name"]);
+    for i in first..first + rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("name {i}")),
+        ]);
+    }
+    rs
+}
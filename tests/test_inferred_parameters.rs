@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT};
+use serde::Serialize;
+use serde_db::ser::{
+    infer_parameters, to_params_inferred, InferredParameter, LobStream, ParameterKind,
+};
+
+#[derive(Serialize)]
+enum Status {
+    Open,
+}
+
+#[derive(Serialize)]
+struct Ticket {
+    id: i16,
+    title: Option<String>,
+    assignee: Option<String>,
+    status: Status,
+    attachment: Vec<u8>,
+    log: LobStream,
+}
+
+fn ticket() -> Ticket {
+    Ticket {
+        id: 1,
+        title: Some("Printer on fire".to_string()),
+        assignee: None,
+        status: Status::Open,
+        attachment: vec![1, 2, 3],
+        log: LobStream::from_chunks(vec![b"smoke".to_vec()]),
+    }
+}
+
+// The driver's choice of the database types.
+fn describe(p: &InferredParameter) -> &'static PT {
+    match (p.kind, p.nullable) {
+        (ParameterKind::I16, false) => &PT::Short,
+        (ParameterKind::I16, true) => &PT::NullableShort,
+        (ParameterKind::Bytes | ParameterKind::Lob, false) => &PT::Binary,
+        (ParameterKind::Bytes | ParameterKind::Lob, true) => &PT::NullableBinary,
+        (_, false) => &PT::String,
+        (_, true) => &PT::NullableString,
+    }
+}
+
+#[test] // cargo test --test test_inferred_parameters -- --nocapture
+pub fn test_inferred_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== inferred parameters ===");
+
+    info!("The descriptions are inferred from the value");
+    let inferred = infer_parameters(&ticket()).unwrap();
+    debug!("{inferred:?}");
+    let summary: Vec<(Option<&str>, ParameterKind, bool)> = inferred
+        .iter()
+        .map(|p| (p.field, p.kind, p.nullable))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (Some("id"), ParameterKind::I16, false),
+            (Some("title"), ParameterKind::Str, true),
+            (Some("assignee"), ParameterKind::Null, true),
+            (Some("status"), ParameterKind::Str, false),
+            (Some("attachment"), ParameterKind::Bytes, false),
+            (Some("log"), ParameterKind::Lob, false),
+        ]
+    );
+    assert_eq!(inferred[5].index, 5);
+
+    let inferred = infer_parameters(&(1_i32, 2.5_f64, 'x')).unwrap();
+    assert_eq!(
+        inferred.iter().map(|p| p.kind).collect::<Vec<_>>(),
+        vec![ParameterKind::I32, ParameterKind::F64, ParameterKind::Char]
+    );
+    assert_eq!(inferred[0].field, None);
+
+    info!("The values are produced with the descriptors that the driver chose");
+    let values: Vec<MValue> = to_params_inferred(&ticket(), describe).unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(1),
+            MValue::String("Printer on fire".to_string()),
+            MValue::Null,
+            MValue::String("Open".to_string()),
+            MValue::Lob(vec![1, 2, 3]),
+            MValue::Lob(b"smoke".to_vec()),
+        ]
+    );
+
+    info!("Conversion errors of the second phase are reported");
+    let result = to_params_inferred(&(70_000_i32,), |_| &PT::Short);
+    debug!("{result:?}");
+    assert!(result.is_err());
+}
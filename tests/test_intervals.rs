@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{ConversionError, DbInterval, DeserializationError};
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+struct Job {
+    name: String,
+    timeout: Duration,
+    retention: DbInterval,
+    grace: Option<Duration>,
+}
+
+#[test] // cargo test --test test_intervals -- --nocapture
+pub fn test_intervals() {
+    let _loghandle = util::init_logger();
+    info!("=== interval values ===");
+
+    info!("Intervals as struct fields");
+    let mut rs = Resultset::new(&["name", "timeout", "retention", "grace"]);
+    rs.push(vec![
+        MValue::new_string("backup".to_string()),
+        MValue::new_interval(0, 0, 90_000_000),
+        MValue::new_interval(3, 0, 0),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_string("cleanup".to_string()),
+        MValue::new_interval(0, 1, 500),
+        MValue::new_interval(0, -2, 0),
+        MValue::new_interval(0, 0, 1_000_000),
+    ]);
+    let jobs: Vec<Job> = rs.try_into().unwrap();
+    assert_eq!(jobs[0].name, "backup");
+    assert_eq!(jobs[0].timeout, Duration::from_secs(90));
+    assert_eq!(jobs[0].retention, DbInterval::new(3, 0, 0));
+    assert_eq!(jobs[0].grace, None);
+    assert_eq!(jobs[1].timeout, Duration::from_micros(86_400_000_500));
+    assert_eq!(jobs[1].retention.days, -2);
+    assert_eq!(jobs[1].grace, Some(Duration::from_secs(1)));
+
+    info!("Single values and resultsets");
+    let d: Duration = MValue::new_interval(0, 0, 2_500_000).try_into().unwrap();
+    assert_eq!(d, Duration::from_millis(2_500));
+    let mut rs = Resultset::new(&["i"]);
+    rs.push(vec![MValue::new_interval(1, 2, 3)]);
+    let i: DbInterval = rs.try_into().unwrap();
+    assert_eq!(i, DbInterval::new(1, 2, 3));
+
+    info!("Conversions of DbInterval");
+    let i = DbInterval::new(0, -1, 0);
+    assert_eq!(i.total_microseconds().unwrap(), -86_400_000_000);
+    assert!(matches!(
+        i.to_std_duration(),
+        Err(ConversionError::NumberRange(_))
+    ));
+    #[cfg(feature = "chrono")]
+    assert_eq!(i.to_chrono_duration().unwrap(), chrono::Duration::days(-1));
+
+    info!("Intervals with months or negative length cannot become a Duration");
+    for interval in [
+        MValue::new_interval(1, 0, 0),
+        MValue::new_interval(0, 0, -1),
+    ] {
+        let result: mock_db::Result<Duration> = interval.try_into();
+        debug!("{:?}", result);
+        assert!(matches!(
+            result,
+            Err(mock_db::Error::DESERIALIZATION(
+                DeserializationError::ConversionError(
+                    ConversionError::ValueType(_) | ConversionError::NumberRange(_)
+                )
+            ))
+        ));
+    }
+
+    info!("Other values are no intervals");
+    let result: mock_db::Result<DbInterval> = MValue::new_bigint(5).try_into();
+    assert!(result.is_err());
+}
@@ -0,0 +1,162 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+#[test] // cargo test --test test_json_columns --features json -- --nocapture
+pub fn test_json_columns() {
+    let _loghandle = util::init_logger();
+    info!("=== JSON columns ===");
+
+    #[cfg(feature = "json")]
+    json_columns::conversion();
+}
+
+#[cfg(feature = "json")]
+mod json_columns {
+    use crate::mock_db::{self, MValue, Resultset};
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Config {
+        name: String,
+        tags: Vec<String>,
+        limits: Option<Limits>,
+        mode: Mode,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Limits {
+        max: u64,
+        ratio: f64,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Mode {
+        Off,
+        Fixed(i32),
+        Range { from: i32, to: i32 },
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct TestData {
+        id: i32,
+        config: Config,
+        extra: Option<HashMap<String, i64>>,
+    }
+
+    pub(super) fn conversion() {
+        info!("Deserialization of rows with a JSON column");
+        let data: Vec<TestData> = get_resultset().try_into().unwrap();
+        assert_eq!(data.len(), 3);
+        assert_eq!(data[2].id, 3);
+        assert_eq!(
+            data[0].config,
+            Config {
+                name: "first \"one\"".to_string(),
+                tags: vec!["a".to_string(), "b\u{e9}".to_string()],
+                limits: Some(Limits {
+                    max: 10,
+                    ratio: 0.5
+                }),
+                mode: Mode::Off,
+            }
+        );
+        assert_eq!(data[1].config.mode, Mode::Fixed(-3));
+        assert_eq!(data[1].config.limits, None);
+        assert_eq!(data[2].config.mode, Mode::Range { from: 1, to: 2 });
+        assert_eq!(data[0].extra.as_ref().unwrap()["x"], -1);
+        assert!(data[1].extra.is_none());
+        assert!(data[2].extra.as_ref().unwrap().is_empty());
+        debug!("Got {:?}", data);
+
+        info!("Deserialization of single JSON values");
+        let row = get_resultset().next().unwrap();
+        let config: Config = row.field_into(1).unwrap();
+        assert_eq!(config.tags.len(), 2);
+        let raw: String = row.field_into(1).unwrap();
+        assert!(raw.starts_with("{\"name\""));
+        let numbers: Vec<i64> = MValue::new_json(" [1, 2, 3] ").try_into().unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        let pair: (String, bool) = MValue::new_json(r#"["x", true]"#).try_into().unwrap();
+        assert_eq!(pair, ("x".to_string(), true));
+
+        info!("Invalid JSON documents");
+        for invalid in [
+            r#"{"max": 1,}"#,
+            r#"{"max": 1} x"#,
+            r#"{"max": 1"#,
+            r#"{"max": "1", "ratio": 1.0}"#,
+        ] {
+            let result: mock_db::Result<Limits> = MValue::new_json(invalid).try_into();
+            debug!("{invalid}: {:?}", result);
+            assert!(result.is_err());
+        }
+        // RFC 8259 is strict about numbers, strings, and escapes
+        for invalid in ["01", "-01", "+1", "-", "1.", ".5", "1e", "1.e5", "[1, 2,]"] {
+            let result: mock_db::Result<Vec<f64>> =
+                MValue::new_json(&format!("[{invalid}]")).try_into();
+            debug!("{invalid}: {:?}", result);
+            assert!(result.is_err(), "{invalid} was accepted");
+        }
+        for invalid in [
+            "\"a\tb\"",
+            "\"\u{1}\"",
+            r#""\u+abc""#,
+            r#""\u12""#,
+            r#""\x""#,
+        ] {
+            let result: mock_db::Result<Vec<String>> =
+                MValue::new_json(&format!("[{invalid}]")).try_into();
+            debug!("{invalid}: {:?}", result);
+            assert!(result.is_err(), "{invalid} was accepted");
+        }
+        let numbers: Vec<f64> = MValue::new_json("[0, -0.5, 1E2, 2e-1]").try_into().unwrap();
+        assert_eq!(numbers, vec![0.0, -0.5, 100.0, 0.2]);
+        // DEL is not a control character in the sense of RFC 8259
+        let texts: Vec<String> = MValue::new_json("[\"a\u{7f}b\"]").try_into().unwrap();
+        assert_eq!(texts, vec!["a\u{7f}b".to_string()]);
+
+        info!("Integers beyond 64 bits");
+        let big: Vec<u128> = MValue::new_json("[18446744073709551616]")
+            .try_into()
+            .unwrap();
+        assert_eq!(big, vec![1 << 64]);
+        let small: Vec<i128> = MValue::new_json("[-9223372036854775809]")
+            .try_into()
+            .unwrap();
+        assert_eq!(small, vec![-(1 << 63) - 1]);
+        let result: mock_db::Result<Vec<u64>> =
+            MValue::new_json("[18446744073709551616]").try_into();
+        assert!(result.is_err());
+    }
+
+    fn get_resultset() -> Resultset {
+        let mut rs = Resultset::new(&["id", "config", "extra"]);
+        rs.push(vec![
+            MValue::new_short(1),
+            MValue::new_json(
+                r#"{"name": "first \"one\"", "tags": ["a", "bé"],
+                    "limits": {"max": 10, "ratio": 5e-1}, "mode": "Off"}"#,
+            ),
+            MValue::new_json(r#"{"x": -1, "y": 2}"#),
+        ]);
+        rs.push(vec![
+            MValue::new_short(2),
+            MValue::new_json(
+                r#"{"name": "second", "tags": [], "limits": null, "mode": {"Fixed": -3}}"#,
+            ),
+            MValue::new_null(),
+        ]);
+        rs.push(vec![
+            MValue::new_short(3),
+            MValue::new_json(
+                r#"{"mode": {"Range": {"from": 1, "to": 2}}, "name": "third", "tags": ["c"]}"#,
+            ),
+            MValue::new_json("{}"),
+        ]);
+        rs
+    }
+}
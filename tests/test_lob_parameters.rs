@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde::{Serialize, Serializer};
+use serde_db::ser::{to_named_params, to_params, LobStream, SerializationError};
+
+#[test] // cargo test --test test_lob_parameters -- --nocapture
+pub fn test_lob_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== LOB parameters ===");
+
+    info!("A LobStream passes its reader through to the driver");
+    let content: Vec<u8> = (0..=255).collect();
+    let values: Vec<MValue> = to_params(
+        &(1_i16, LobStream::new(std::io::Cursor::new(content.clone()))),
+        &mut [PT::Short, PT::Binary].iter(),
+    )
+    .unwrap();
+    assert_eq!(values, vec![MValue::Short(1), MValue::Lob(content)]);
+
+    info!("... also when the content is provided in chunks");
+    #[derive(Serialize)]
+    struct Document {
+        id: i16,
+        content: Option<LobStream>,
+    }
+    let chunks = vec![b"%PDF".to_vec(), Vec::new(), b"-1.7".to_vec()];
+    let document = Document {
+        id: 2,
+        content: Some(LobStream::from_chunks(chunks)),
+    };
+    let parameters = [
+        Parameter::new("content", PT::NullableBinary),
+        Parameter::new("id", PT::Short),
+    ];
+    let values: Vec<MValue> = to_named_params(&document, &mut parameters.iter()).unwrap();
+    assert_eq!(
+        values,
+        vec![MValue::Lob(b"%PDF-1.7".to_vec()), MValue::Short(2)]
+    );
+
+    info!("A LobStream can only be serialized once");
+    let result = to_named_params(&document, &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { index: 0, ref source, .. })
+            if matches!(**source, SerializationError::Serde(_))
+    ));
+
+    info!("A failed or foreign serialization leaves the LobStream reusable");
+    let lob = LobStream::new(std::io::Cursor::new(b"abc".to_vec()));
+    let result = to_params(&(1_i16, &lob), &mut [PT::Short].iter());
+    debug!("{result:?}");
+    assert!(result.is_err());
+    assert_eq!(serde_json::to_string(&lob).unwrap(), "null");
+    let values: Vec<MValue> = to_params(&(&lob,), &mut [PT::Binary].iter()).unwrap();
+    assert_eq!(values, vec![MValue::Lob(b"abc".to_vec())]);
+
+    info!("... and no reader is left behind on the thread");
+    struct FakeLob;
+    impl Serialize for FakeLob {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_newtype_struct("$serde_db::LobParameter", &())
+        }
+    }
+    let result = to_params(&(FakeLob,), &mut [PT::Binary].iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { ref source, .. })
+            if matches!(**source, SerializationError::StructuralMismatch(_))
+    ));
+
+    info!("Parameters that don't support streaming produce an error");
+    let result = to_params(
+        &(LobStream::from_chunks(vec![vec![1_u8]]),),
+        &mut [PT::String].iter(),
+    );
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "lob", .. })
+    ));
+}
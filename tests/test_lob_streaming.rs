@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::{Deserialize, Deserializer};
+use std::io::Write;
+
+// A writer that records the sizes of the chunks it gets.
+#[derive(Debug, Default)]
+struct ChunkRecorder {
+    content: Vec<u8>,
+    chunks: Vec<usize>,
+}
+
+impl Write for ChunkRecorder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.chunks.push(buf.len());
+        self.content.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn record<'de, D: Deserializer<'de>>(d: D) -> Result<ChunkRecorder, D::Error> {
+    let mut recorder = ChunkRecorder::default();
+    serde_db::de::stream_into(d, &mut recorder)?;
+    Ok(recorder)
+}
+
+#[derive(Debug, Deserialize)]
+struct Document {
+    id: i32,
+    #[serde(deserialize_with = "record")]
+    content: ChunkRecorder,
+}
+
+#[test] // cargo test --test test_lob_streaming -- --nocapture
+pub fn test_lob_streaming() {
+    let _loghandle = util::init_logger();
+    info!("=== LOB streaming ===");
+    let large: Vec<u8> = (0..200_000_u32).map(|i| (i % 251) as u8).collect();
+
+    info!("Row struct with streamed field");
+    let mut rs = Resultset::new(&["id", "content"]);
+    rs.push(vec![MValue::new_short(1), MValue::new_lob(large.clone())]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_string("small".to_string()),
+    ]);
+    let docs: Vec<Document> = rs.try_into().unwrap();
+    assert_eq!(docs[0].id, 1);
+    assert_eq!(docs[0].content.content, large);
+    debug!("chunks: {:?}", docs[0].content.chunks);
+    assert!(docs[0].content.chunks.len() > 1);
+    assert!(docs[0].content.chunks.iter().all(|n| *n <= 64 * 1024));
+    assert_eq!(docs[1].content.content, b"small");
+    assert_eq!(docs[1].content.chunks.len(), 1);
+
+    info!("Single value into writer");
+    let mut out = Vec::new();
+    let written = serde_db::de::write_lob(MValue::new_lob(large.clone()), &mut out).unwrap();
+    assert_eq!(written, 200_000);
+    assert_eq!(out, large);
+    assert!(serde_db::de::write_lob(MValue::new_null(), &mut out).is_err());
+}
@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde_db::message::DefaultMessageFormatter;
+use serde_db::ser::{to_named_params, SerializationError};
+use std::collections::{BTreeMap, HashMap};
+
+#[test] // cargo test --test test_map_parameters -- --nocapture
+pub fn test_map_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== maps into named parameters ===");
+
+    let parameters = [
+        Parameter::new("ID", PT::Short),
+        Parameter::new("NAME", PT::NullableString),
+    ];
+
+    info!("The entries of maps are bound by key");
+    let mut input: HashMap<String, Option<String>> = HashMap::new();
+    input.insert("NAME".to_string(), Some("Alice".to_string()));
+    input.insert("ID".to_string(), Some("7".to_string()));
+    let result = to_named_params(&input, &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { index: 0, .. })
+    ));
+    let mut input: BTreeMap<&str, Option<i16>> = BTreeMap::new();
+    input.insert("NAME", None);
+    input.insert("ID", Some(7));
+    let values: Vec<MValue> = to_named_params(&input, &mut parameters.iter()).unwrap();
+    assert_eq!(values, vec![MValue::Short(7), MValue::Null]);
+
+    info!("Errors list all unknown keys and all missing parameters");
+    let mut input: BTreeMap<String, i16> = BTreeMap::new();
+    input.insert("IDENT".to_string(), 7);
+    input.insert("AGE".to_string(), 42);
+    let e = to_named_params(&input, &mut parameters.iter()).unwrap_err();
+    debug!("{e:?}");
+    assert!(matches!(
+        e,
+        SerializationError::UnmatchedParameters { ref unknown_keys, ref missing }
+            if unknown_keys == &["AGE", "IDENT"] && missing == &["ID", "NAME"]
+    ));
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "no parameters for the keys AGE, IDENT; no values for the parameters ID, NAME"
+    );
+    input.insert("ID".to_string(), 7);
+    input.remove("IDENT");
+    let result = to_named_params(&input, &mut parameters.iter());
+    assert!(matches!(
+        result,
+        Err(SerializationError::UnmatchedParameters { ref missing, .. }) if missing == &["NAME"]
+    ));
+    input.remove("AGE");
+    let result = to_named_params(&input, &mut parameters.iter());
+    assert!(matches!(
+        result,
+        Err(SerializationError::MissingParameters(ref names)) if names == &["NAME"]
+    ));
+
+    info!("Keys must be strings");
+    let mut input: BTreeMap<i16, i16> = BTreeMap::new();
+    input.insert(1, 7);
+    let result = to_named_params(&input, &mut parameters.iter());
+    assert!(matches!(
+        result,
+        Err(SerializationError::StructuralMismatch(_))
+    ));
+}
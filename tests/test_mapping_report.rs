@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::Resultset;
+use serde::Deserialize;
+use serde_db::de::{
+    mapping_report, Binding, DeserializationError, DeserializationOptions, FieldNameMatching,
+    FieldnameFallback,
+};
+
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct Address {
+    street: String,
+    number: i64,
+}
+
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct Customer {
+    customer_id: i64,
+    name: String,
+    address: Address,
+    remark: Option<String>,
+}
+
+#[test] // cargo test --test test_mapping_report -- --nocapture
+pub fn test_mapping_report() {
+    let _loghandle = util::init_logger();
+    info!("=== Mapping report ===");
+
+    let rs = Resultset::new(&[
+        "CUSTOMER_ID",
+        "name",
+        "address_street",
+        "address_number",
+        "notes",
+    ])
+    .with_fieldtypes(&["BIGINT", "NVARCHAR", "NVARCHAR", "INT", "NCLOB"]);
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive)
+        .with_prefix_separator("_");
+
+    info!("Each column is reported with its binding");
+    let report = mapping_report::<Customer, _>(&rs, &options).unwrap();
+    let bindings: Vec<(&str, Binding)> = report
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.binding))
+        .collect();
+    assert_eq!(
+        bindings,
+        vec![
+            ("CUSTOMER_ID", Binding::Field("customer_id")),
+            ("name", Binding::Field("name")),
+            ("address_street", Binding::Nested("address")),
+            ("address_number", Binding::Nested("address")),
+            ("notes", Binding::Unused),
+        ]
+    );
+    assert_eq!(report.columns[3].index, 3);
+    assert_eq!(report.columns[3].column_type.as_deref(), Some("INT"));
+    assert_eq!(report.unbound_fields, vec!["remark"]);
+    assert_eq!(report.unused_columns(), vec!["notes"]);
+    assert!(!report.is_complete());
+
+    info!("Columns outside the field mask are reported as masked");
+    let report =
+        mapping_report::<Customer, _>(&rs, &options.clone().with_field_mask(&["customer_id"]))
+            .unwrap();
+    assert_eq!(report.columns[0].binding, Binding::Field("customer_id"));
+    assert_eq!(report.columns[1].binding, Binding::Masked);
+    assert_eq!(report.columns[4].binding, Binding::Masked);
+    assert_eq!(report.unbound_fields, vec!["name", "address", "remark"]);
+
+    info!("Without field names, the columns are bound by position");
+    let rs = Resultset::new(&["a", "b"]).without_fieldnames();
+    let report = mapping_report::<Customer, _>(&rs, &DeserializationOptions::default()).unwrap();
+    assert_eq!(report.columns[0].name, "#0");
+    assert_eq!(report.columns[1].binding, Binding::Positional("name"));
+    assert_eq!(report.unbound_fields, vec!["address", "remark"]);
+    let result = mapping_report::<Customer, _>(
+        &rs,
+        &DeserializationOptions::default().with_fieldname_fallback(FieldnameFallback::Error),
+    );
+    assert!(matches!(result, Err(DeserializationError::Usage(_))));
+
+    info!("Targets that are not plain structs are rejected");
+    let result = mapping_report::<(i64, String), _>(&rs, &DeserializationOptions::default());
+    assert!(matches!(result, Err(DeserializationError::Usage(_))));
+}
@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::Resultset;
+use serde_db::de::{metadata_diff, RetypedColumn};
+
+#[test] // cargo test --test test_metadata_diff -- --nocapture
+pub fn test_metadata_diff() {
+    let _loghandle = util::init_logger();
+    info!("=== Metadata diff ===");
+
+    info!("Identical metadata");
+    let rs_a = Resultset::new(&["f1", "f2"]).with_fieldtypes(&["SHORT", "STRING"]);
+    let rs_b = Resultset::new(&["f1", "f2"]).with_fieldtypes(&["SHORT", "STRING"]);
+    assert!(metadata_diff(&rs_a, &rs_b).is_empty());
+
+    info!("Added, removed, and retyped columns");
+    let rs_a = Resultset::new(&["f1", "f2", "f3"]).with_fieldtypes(&["SHORT", "STRING", "DOUBLE"]);
+    let rs_b = Resultset::new(&["f4", "f2", "f1"]).with_fieldtypes(&["SHORT", "STRING", "DOUBLE"]);
+    let diff = metadata_diff(&rs_a, &rs_b);
+    debug!("{:?}", diff);
+    assert_eq!(diff.added, vec!["f4".to_string()]);
+    assert_eq!(diff.removed, vec!["f3".to_string()]);
+    assert_eq!(
+        diff.retyped,
+        vec![RetypedColumn {
+            name: "f1".to_string(),
+            old_type: "SHORT".to_string(),
+            new_type: "DOUBLE".to_string(),
+        }]
+    );
+
+    info!("Without type descriptors, only names are compared");
+    let rs_a = Resultset::new(&["f1", "f2"]);
+    let rs_b = Resultset::new(&["f2", "f1"]).with_fieldtypes(&["SHORT", "STRING"]);
+    assert!(metadata_diff(&rs_a, &rs_b).is_empty());
+}
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, MultiResultset, Resultset};
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Header {
+    order_id: i32,
+    customer: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    order_id: i32,
+    pos: i16,
+    article: String,
+}
+
+#[test] // cargo test --test test_multi_resultset -- --nocapture
+pub fn test_multi_resultset() {
+    let _loghandle = util::init_logger();
+    info!("=== Multiple resultsets ===");
+
+    info!("Tuple of Vecs");
+    let (headers, lines): (Vec<Header>, Vec<Line>) = get_multi_resultset().try_into().unwrap();
+    debug!("{:?} {:?}", headers, lines);
+    assert_eq!(headers.len(), 1);
+    assert_eq!(headers[0].order_id, 17);
+    assert_eq!(headers[0].customer, "ACME");
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].order_id, 17);
+    assert_eq!(lines[1].pos, 2);
+    assert_eq!(lines[1].article, "nails");
+
+    info!("Structural simplification applies per resultset");
+    let (header, articles): (Header, Vec<(i32, i16, String)>) =
+        get_multi_resultset().try_into().unwrap();
+    assert_eq!(header.customer, "ACME");
+    assert_eq!(articles[0].2, "hammer");
+
+    info!("Resultsets can be ignored");
+    let (_, lines): (IgnoredAny, Vec<Line>) = get_multi_resultset().try_into().unwrap();
+    assert_eq!(lines.len(), 2);
+
+    info!("Mismatching number of resultsets");
+    let result: mock_db::Result<(Vec<Header>,)> = get_multi_resultset().try_into();
+    assert!(result.is_err());
+    let result: mock_db::Result<(Vec<Header>, Vec<Line>, Vec<Line>)> =
+        get_multi_resultset().try_into();
+    assert!(result.is_err());
+    let result: mock_db::Result<Vec<Header>> = get_multi_resultset().try_into();
+    assert!(result.is_err());
+}
+
+fn get_multi_resultset() -> MultiResultset {
+    let mut headers = Resultset::new(&["order_id", "customer"]);
+    headers.push(vec![
+        MValue::new_short(17),
+        MValue::new_string("ACME".to_string()),
+    ]);
+
+    let mut lines = Resultset::new(&["order_id", "pos", "article"]);
+    lines.push(vec![
+        MValue::new_short(17),
+        MValue::new_short(1),
+        MValue::new_string("hammer".to_string()),
+    ]);
+    lines.push(vec![
+        MValue::new_short(17),
+        MValue::new_short(2),
+        MValue::new_string("nails".to_string()),
+    ]);
+
+    let mut mrs = MultiResultset::new();
+    mrs.push(headers);
+    mrs.push(lines);
+    mrs
+}
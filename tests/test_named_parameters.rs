@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde::Serialize;
+use serde_db::ser::{to_named_params, SerializationError};
+
+#[derive(Serialize)]
+struct Input {
+    name: String,
+    id: i16,
+    nick: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Wrapper(Input);
+
+#[test] // cargo test --test test_named_parameters -- --nocapture
+pub fn test_named_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== named parameters ===");
+
+    let parameters = vec![
+        Parameter::new("id", PT::Short),
+        Parameter::new("nick", PT::NullableString),
+        Parameter::new("name", PT::String),
+    ];
+    let input = Input {
+        name: "Ann".to_string(),
+        id: 7,
+        nick: None,
+    };
+
+    info!("Fields are bound to the parameters with their names");
+    let values: Vec<MValue> = to_named_params(&input, &mut parameters.iter()).unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(7),
+            MValue::Null,
+            MValue::String("Ann".to_string())
+        ]
+    );
+    let values: Vec<MValue> = to_named_params(&Wrapper(input), &mut parameters.iter()).unwrap();
+    assert_eq!(values[0], MValue::Short(7));
+
+    info!("Fields without parameter");
+    let input = Input {
+        name: "Bob".to_string(),
+        id: 8,
+        nick: None,
+    };
+    let result = to_named_params(&input, &mut parameters[..2].iter());
+    debug!("{result:?}");
+    assert!(matches!(result, Err(SerializationError::UnknownParameter(ref f)) if f == "name"));
+
+    info!("Parameters without field");
+    let mut parameters = parameters;
+    parameters.push(Parameter::new("created", PT::Timestamp));
+    parameters.push(Parameter::new("city", PT::String));
+    let result = to_named_params(&input, &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::MissingParameters(ref names)) if names == &["created", "city"]
+    ));
+
+    info!("Parameters without names, and values that are no structs");
+    let result = to_named_params(&input, &mut [PT::Short, PT::String].iter());
+    assert!(matches!(
+        result,
+        Err(SerializationError::StructuralMismatch(_))
+    ));
+    let result = to_named_params(&(1_i16, "x"), &mut parameters.iter());
+    assert!(matches!(
+        result,
+        Err(SerializationError::StructuralMismatch(_))
+    ));
+}
@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::DeserializationOptions;
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: i32,
+    created_at: String,
+    customer: Customer,
+    note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Customer {
+    name: String,
+    address: Address,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[test] // cargo test --test test_nested_prefixes -- --nocapture
+pub fn test_nested_prefixes() {
+    let _loghandle = util::init_logger();
+    info!("=== Nested structs via column-name prefixes ===");
+    let options = DeserializationOptions::default().with_prefix_separator("_");
+
+    info!("Two levels of nesting");
+    let orders: Vec<Order> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", orders);
+    assert_eq!(orders.len(), 2);
+    assert_eq!(orders[0].id, 1);
+    assert_eq!(orders[0].created_at, "today");
+    assert_eq!(
+        orders[0].customer,
+        Customer {
+            name: "Alice".to_string(),
+            address: Address {
+                street: "Main Street 1".to_string(),
+                city: "Springfield".to_string()
+            }
+        }
+    );
+    assert_eq!(orders[0].note, None);
+    assert_eq!(orders[1].customer.address.city, "Shelbyville");
+    assert_eq!(orders[1].note, Some("urgent".to_string()));
+
+    info!("Other separators");
+    let options = DeserializationOptions::default().with_prefix_separator(".");
+    let mut rs = Resultset::new(&["name", "address.street", "address.city"]);
+    rs.push(vec![
+        MValue::new_string("Carl".to_string()),
+        MValue::new_string("Hill 3".to_string()),
+        MValue::new_string("Ogdenville".to_string()),
+    ]);
+    let customer: Customer = rs.try_into_with(&options).unwrap();
+    assert_eq!(customer.address.street, "Hill 3");
+
+    info!("Without the option, prefixed columns are unknown fields");
+    let result: mock_db::Result<Vec<Order>> = get_resultset().try_into();
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&[
+        "id",
+        "created_at",
+        "customer_name",
+        "customer_address_street",
+        "customer_address_city",
+        "note",
+    ]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_string("today".to_string()),
+        MValue::new_string("Alice".to_string()),
+        MValue::new_string("Main Street 1".to_string()),
+        MValue::new_string("Springfield".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_string("yesterday".to_string()),
+        MValue::new_string("Bob".to_string()),
+        MValue::new_string("Side Road 2".to_string()),
+        MValue::new_string("Shelbyville".to_string()),
+        MValue::new_string("urgent".to_string()),
+    ]);
+    rs
+}
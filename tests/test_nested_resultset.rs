@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: i32,
+    lines: Vec<Line>,
+    main_line: Option<Line>,
+    positions: Vec<i16>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    pos: i16,
+    article: String,
+    parts: Vec<String>,
+}
+
+#[test] // cargo test --test test_nested_resultset -- --nocapture
+pub fn test_nested_resultset() {
+    let _loghandle = util::init_logger();
+    info!("=== Nested resultsets ===");
+
+    let orders: Vec<Order> = get_resultset().try_into().unwrap();
+    debug!("{:?}", orders);
+    assert_eq!(orders[0].id, 1);
+    assert_eq!(orders[0].lines.len(), 2);
+    assert_eq!(orders[0].lines[1].pos, 2);
+    assert_eq!(orders[0].lines[1].article, "nails");
+    assert_eq!(orders[0].lines[0].parts, vec!["handle", "head"]);
+    assert!(orders[0].lines[1].parts.is_empty());
+    let main_line = orders[0].main_line.as_ref().unwrap();
+    assert_eq!((main_line.pos, main_line.article.as_str()), (1, "hammer"));
+    assert_eq!(orders[0].positions, vec![1, 2]);
+    assert!(orders[1].lines.is_empty());
+    assert!(orders[1].main_line.is_none());
+
+    info!("Nested resultset with several rows into a single struct");
+    let result: mock_db::Result<Line> = lines().try_into();
+    assert!(result.is_err());
+}
+
+fn lines() -> MValue {
+    MValue::new_nested(
+        &["pos", "article", "parts"],
+        vec![
+            vec![
+                MValue::new_short(1),
+                MValue::new_string("hammer".to_string()),
+                MValue::new_nested(
+                    &["name"],
+                    vec![
+                        vec![MValue::new_string("handle".to_string())],
+                        vec![MValue::new_string("head".to_string())],
+                    ],
+                ),
+            ],
+            vec![
+                MValue::new_short(2),
+                MValue::new_string("nails".to_string()),
+                MValue::new_nested(&["name"], vec![]),
+            ],
+        ],
+    )
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "lines", "main_line", "positions"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        lines(),
+        MValue::new_nested(
+            &["pos", "article", "parts"],
+            vec![vec![
+                MValue::new_short(1),
+                MValue::new_string("hammer".to_string()),
+                MValue::new_nested(&["name"], vec![]),
+            ]],
+        ),
+        MValue::new_nested(
+            &["pos"],
+            vec![vec![MValue::new_short(1)], vec![MValue::new_short(2)]],
+        ),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_nested(&["pos", "article", "parts"], vec![]),
+        MValue::new_null(),
+        MValue::new_nested(&["pos"], vec![]),
+    ]);
+    rs
+}
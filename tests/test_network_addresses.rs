@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+#[derive(Debug, Deserialize)]
+struct Host {
+    address: IpAddr,
+    v4: Option<Ipv4Addr>,
+    v6: Ipv6Addr,
+    endpoint: SocketAddr,
+}
+
+#[test] // cargo test --test test_network_addresses -- --nocapture
+pub fn test_network_addresses() {
+    let _loghandle = util::init_logger();
+    info!("=== Network addresses from text columns ===");
+
+    let mut rs = Resultset::new(&["address", "v4", "v6", "endpoint"]);
+    rs.push(vec![
+        MValue::new_string("192.168.0.1".to_string()),
+        MValue::new_string("10.0.0.1".to_string()),
+        MValue::new_string("::1".to_string()),
+        MValue::new_string("[fe80::1]:8080".to_string()),
+    ]);
+    rs.push(vec![
+        MValue::new_string("2001:db8::7".to_string()),
+        MValue::new_null(),
+        MValue::new_string("2001:db8::8".to_string()),
+        MValue::new_string("10.0.0.2:443".to_string()),
+    ]);
+
+    info!("Structs with address fields");
+    let hosts: Vec<Host> = rs.try_into().unwrap();
+    assert_eq!(hosts[0].address, IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    assert_eq!(hosts[0].v4, Some(Ipv4Addr::new(10, 0, 0, 1)));
+    assert_eq!(hosts[0].v6, Ipv6Addr::LOCALHOST);
+    assert_eq!(hosts[0].endpoint.port(), 8080);
+    assert!(hosts[1].address.is_ipv6());
+    assert_eq!(hosts[1].v4, None);
+    assert_eq!(hosts[1].endpoint, "10.0.0.2:443".parse().unwrap());
+
+    info!("Invalid addresses");
+    let result: mock_db::Result<IpAddr> = MValue::new_string("10.0.0.256".to_string()).try_into();
+    debug!("{:?}", result);
+    assert!(result.is_err());
+}
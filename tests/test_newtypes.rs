@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct UserId(i64);
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Name(String);
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Marker;
+
+#[derive(Debug, Deserialize)]
+struct User {
+    id: UserId,
+    name: Option<Name>,
+    legacy: Marker,
+}
+
+#[test] // cargo test --test test_newtypes -- --nocapture
+pub fn test_newtypes() {
+    let _loghandle = util::init_logger();
+    info!("=== Newtype and unit structs ===");
+
+    info!("Struct fields");
+    let users: Vec<User> = get_resultset().try_into().unwrap();
+    assert_eq!(users[0].id, UserId(1));
+    assert_eq!(users[0].name, Some(Name("alice".to_string())));
+    assert_eq!(users[1].name, None);
+    assert_eq!(users[1].legacy, Marker);
+
+    info!("Tuple elements");
+    let users: Vec<(UserId, Option<Name>, ())> = get_resultset().try_into().unwrap();
+    assert_eq!(users[1].0, UserId(2));
+
+    info!("Plain values");
+    let mut rs = Resultset::new(&["id"]);
+    rs.push(vec![MValue::new_bigint(3)]);
+    let id: UserId = rs.try_into().unwrap();
+    assert_eq!(id, UserId(3));
+    let marker: Marker = MValue::new_string(String::new()).try_into().unwrap();
+    assert_eq!(marker, Marker);
+    let marker: Marker = MValue::new_null().try_into().unwrap();
+    assert_eq!(marker, Marker);
+
+    info!("Values with content");
+    let result: mock_db::Result<Marker> = MValue::new_string("x".to_string()).try_into();
+    assert!(result.is_err());
+    let result: mock_db::Result<()> = MValue::new_bigint(0).try_into();
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "name", "legacy"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_string("alice".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_bigint(2),
+        MValue::new_null(),
+        MValue::new_string(String::new()),
+    ]);
+    rs
+}
@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    ConversionError, DecimalBackend, DecimalParts, DeserializationError, DeserializationOptions,
+    NonFinitePolicy,
+};
+
+#[derive(Debug, Deserialize)]
+struct Measurement {
+    id: i32,
+    value: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PlainMeasurement {
+    id: i32,
+    value: f64,
+}
+
+#[test] // cargo test --test test_non_finite_policy -- --nocapture
+pub fn test_non_finite_policy() {
+    let _loghandle = util::init_logger();
+    info!("=== Non-finite policy ===");
+
+    info!("PassThrough");
+    let data: Vec<Measurement> = get_resultset().try_into().unwrap();
+    debug!("{:?}", data);
+    assert!(data[0].value.unwrap().is_nan());
+    assert_eq!(data[1].value, Some(f64::INFINITY));
+    assert_eq!(data[2].value, Some(1.5));
+    let data: Vec<PlainMeasurement> = get_resultset().try_into().unwrap();
+    assert_eq!(data[1].id, 2);
+    assert!(data[0].value.is_nan());
+
+    info!("NoneForOption");
+    let options =
+        DeserializationOptions::default().with_non_finite_policy(NonFinitePolicy::NoneForOption);
+    let data: Vec<Measurement> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[0].id, 1);
+    assert!(data[0].value.is_none());
+    assert!(data[1].value.is_none());
+    assert_eq!(data[2].value, Some(1.5));
+    let result: mock_db::Result<Vec<PlainMeasurement>> = get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+
+    info!("Error");
+    let options = DeserializationOptions::default().with_non_finite_policy(NonFinitePolicy::Error);
+    let result: mock_db::Result<f64> =
+        MValue::new_double(f64::NEG_INFINITY).try_into_with(&options);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::ConversionError(
+            ConversionError::NonFinite(f),
+        ))) => {
+            assert_eq!(f, f64::NEG_INFINITY);
+        }
+        _ => panic!("expected NonFinite"),
+    }
+    let result: mock_db::Result<Vec<Measurement>> = get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+    let value: f64 = MValue::new_double(2.5).try_into_with(&options).unwrap();
+    assert_eq!(value, 2.5);
+
+    info!("Decimals");
+    match DecimalParts::from_db_value(MValue::new_double(f64::NAN)) {
+        Err(ConversionError::NonFinite(f)) => assert!(f.is_nan()),
+        _ => panic!("expected NonFinite"),
+    }
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "value"]);
+    rs.push(vec![MValue::new_short(1), MValue::new_double(f64::NAN)]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_double(f64::INFINITY),
+    ]);
+    rs.push(vec![MValue::new_short(3), MValue::new_double(1.5)]);
+    rs
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{ConversionError, DeserializationError, DeserializationOptions, NumericPolicy};
+use serde_db::message::DefaultMessageFormatter;
+use std::num::{NonZeroI64, NonZeroU32};
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: NonZeroI64,
+    parent: Option<NonZeroI64>,
+}
+
+#[test] // cargo test --test test_non_zero -- --nocapture
+pub fn test_non_zero() {
+    let _loghandle = util::init_logger();
+    info!("=== Non-zero integers ===");
+
+    info!("Non-zero values");
+    let orders: Vec<Order> = get_resultset(&[(1, None), (2, Some(1))])
+        .try_into()
+        .unwrap();
+    assert_eq!(orders[0].id.get(), 1);
+    assert_eq!(orders[0].parent, None);
+    assert_eq!(orders[1].parent, NonZeroI64::new(1));
+
+    info!("Zero values");
+    let result: mock_db::Result<Vec<Order>> = get_resultset(&[(1, None), (0, None)]).try_into();
+    debug!("{:?}", result);
+    let Err(mock_db::Error::DESERIALIZATION(e)) = result else {
+        panic!("zero must not be accepted");
+    };
+    assert!(matches!(
+        e,
+        DeserializationError::ConversionError(ConversionError::Zero(ref s)) if s == "i64"
+    ));
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "zero is not a valid non-zero i64"
+    );
+
+    info!("Other primitive types");
+    let options = DeserializationOptions::default().with_numeric_policy(NumericPolicy::Checked);
+    let value: NonZeroU32 = MValue::new_bigint(7).try_into_with(&options).unwrap();
+    assert_eq!(value.get(), 7);
+    let result: mock_db::Result<NonZeroU32> = MValue::new_bigint(0).try_into_with(&options);
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::ConversionError(ConversionError::Zero(_))
+        ))
+    ));
+}
+
+fn get_resultset(rows: &[(i64, Option<i64>)]) -> Resultset {
+    let mut rs = Resultset::new(&["id", "parent"]);
+    for (id, parent) in rows {
+        rs.push(vec![
+            MValue::new_bigint(*id),
+            parent.map_or_else(MValue::new_null, MValue::new_bigint),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde::Serialize;
+use serde_db::ser::{
+    to_named_params_with, to_params, to_params_with, NonePolicy, SerializationError,
+    SerializationOptions,
+};
+
+#[derive(Serialize)]
+struct Customer {
+    id: i16,
+    name: Option<String>,
+    segment: Option<String>,
+}
+
+#[test] // cargo test --test test_none_policy -- --nocapture
+pub fn test_none_policy() {
+    let _loghandle = util::init_logger();
+    info!("=== none policy ===");
+
+    let parameters = [
+        Parameter::new("id", PT::Short),
+        Parameter::new("name", PT::NullableString),
+        Parameter::new("segment", PT::String).with_default(MValue::String("retail".to_string())),
+    ];
+    let customer = Customer {
+        id: 1,
+        name: None,
+        segment: None,
+    };
+
+    info!("By default, None values are bound as NULL");
+    let result = to_params(&customer, &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { index: 2, ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "none", .. })
+    ));
+
+    info!("With NonePolicy::Skip, the database applies the default value");
+    let skip = SerializationOptions::default().with_none_policy(NonePolicy::Skip);
+    let result = to_params_with(&customer, &mut parameters.iter(), &skip);
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { index: 1, ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "default", .. })
+    ));
+    info!("A unit value is bound as NULL, independent of the policy");
+    let values: Vec<MValue> =
+        to_params_with(&(1_i16, (), "online"), &mut parameters.iter(), &skip).unwrap();
+    assert_eq!(values[1], MValue::Null);
+    let parameters = [
+        parameters[0].clone(),
+        parameters[1].clone().with_default(MValue::Null),
+        parameters[2].clone(),
+    ];
+    let values: Vec<MValue> = to_params_with(&customer, &mut parameters.iter(), &skip).unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(1),
+            MValue::Null,
+            MValue::String("retail".to_string())
+        ]
+    );
+    let values: Vec<MValue> =
+        to_named_params_with(&customer, &mut parameters.iter(), &skip).unwrap();
+    assert_eq!(values[2], MValue::String("retail".to_string()));
+
+    info!("Some values are not affected");
+    let customer = Customer {
+        id: 2,
+        name: Some("Bob".to_string()),
+        segment: Some("online".to_string()),
+    };
+    let values: Vec<MValue> = to_params_with(&customer, &mut parameters.iter(), &skip).unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Short(2),
+            MValue::String("Bob".to_string()),
+            MValue::String("online".to_string())
+        ]
+    );
+}
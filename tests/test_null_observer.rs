@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationObserver, DeserializationOptions, NullEvent, NullOutcome};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    #[allow(dead_code)]
+    f1: i32,
+    f2: Option<String>,
+    f3: Option<i32>,
+}
+
+// Counts the NULL values per column.
+#[derive(Default)]
+struct NullCounter(Mutex<HashMap<(String, NullOutcome), usize>>);
+
+impl DeserializationObserver for NullCounter {
+    fn null_encountered(&self, event: &NullEvent) {
+        let column = event.column_name.unwrap_or("?").to_string();
+        *self
+            .0
+            .lock()
+            .unwrap()
+            .entry((column, event.outcome))
+            .or_default() += 1;
+    }
+}
+
+#[test] // cargo test --test test_null_observer -- --nocapture
+pub fn test_null_observer() {
+    let _loghandle = util::init_logger();
+    info!("=== NULL observer ===");
+
+    let counter = Arc::new(NullCounter::default());
+    let options = DeserializationOptions::default().with_observer(counter.clone());
+
+    info!("Structs");
+    let data: Vec<TestData> = get_resultset().try_into_with(&options).unwrap();
+    assert_eq!(data.len(), 5);
+    assert!(data[0].f2.is_none());
+    assert_eq!(data[3].f3, Some(3));
+    {
+        let counts = counter.0.lock().unwrap();
+        debug!("{:?}", counts);
+        assert_eq!(counts[&("f2".to_string(), NullOutcome::Accepted)], 3);
+        assert_eq!(counts[&("f3".to_string(), NullOutcome::Accepted)], 3);
+        assert_eq!(counts.len(), 2);
+    }
+
+    info!("Tuples");
+    let _data: Vec<(i32, Option<String>, Option<i32>)> =
+        get_resultset().try_into_with(&options).unwrap();
+    assert_eq!(
+        counter.0.lock().unwrap()[&("f3".to_string(), NullOutcome::Accepted)],
+        6
+    );
+
+    info!("Errors");
+    let result: mock_db::Result<Vec<(i32, String, Option<i32>)>> =
+        get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+    assert_eq!(
+        counter.0.lock().unwrap()[&("f2".to_string(), NullOutcome::Error)],
+        1
+    );
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["f1", "f2", "f3"]);
+    for i in 0..5 {
+        rs.push(vec![
+            MValue::new_short(i),
+            if i % 2 == 0 {
+                MValue::new_null()
+            } else {
+                MValue::new_string(i.to_string())
+            },
+            if i < 3 {
+                MValue::new_null()
+            } else {
+                MValue::new_short(i)
+            },
+        ]);
+    }
+    rs
+}
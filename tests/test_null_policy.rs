@@ -0,0 +1,128 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    DeserializationObserver, DeserializationOptions, NullEvent, NullOutcome, NullPolicy,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Deserialize)]
+struct Legacy {
+    id: i32,
+    name: String,
+    score: f64,
+    level: i16,
+    note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WithDefaults {
+    id: i32,
+    #[serde(default = "unknown")]
+    name: String,
+    #[serde(default)]
+    score: f64,
+    #[serde(default = "one")]
+    level: i16,
+    note: Option<String>,
+}
+
+fn unknown() -> String {
+    "<unknown>".to_string()
+}
+
+fn one() -> i16 {
+    1
+}
+
+// Collects the outcomes of all NULL values.
+#[derive(Default)]
+struct Outcomes(Mutex<Vec<NullOutcome>>);
+
+impl DeserializationObserver for Outcomes {
+    fn null_encountered(&self, event: &NullEvent) {
+        self.0.lock().unwrap().push(event.outcome);
+    }
+}
+
+#[test] // cargo test --test test_null_policy -- --nocapture
+pub fn test_null_policy() {
+    let _loghandle = util::init_logger();
+    info!("=== NULL policy ===");
+
+    info!("Strict");
+    let result: mock_db::Result<Vec<Legacy>> = get_resultset().try_into();
+    assert!(result.is_err());
+
+    info!("TypeDefault");
+    let outcomes = Arc::new(Outcomes::default());
+    let options = DeserializationOptions::default()
+        .with_null_policy(NullPolicy::TypeDefault)
+        .with_observer(outcomes.clone());
+    let data: Vec<Legacy> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[0].name, "");
+    assert_eq!(data[0].score, 0.0);
+    assert_eq!(data[0].level, 0);
+    assert!(data[0].note.is_none());
+    assert_eq!(data[1].id, 2);
+    assert_eq!(data[1].name, "second");
+    assert_eq!(
+        *outcomes.0.lock().unwrap(),
+        vec![
+            NullOutcome::Defaulted,
+            NullOutcome::Defaulted,
+            NullOutcome::Defaulted,
+            NullOutcome::Accepted
+        ]
+    );
+    let value: bool = MValue::new_null().try_into_with(&options).unwrap();
+    assert!(!value);
+    let tuples: Vec<(i32, String)> = get_resultset().try_into_with(&options).unwrap();
+    assert_eq!(tuples[0], (1, String::new()));
+
+    info!("Omit");
+    let outcomes = Arc::new(Outcomes::default());
+    let options = DeserializationOptions::default()
+        .with_null_policy(NullPolicy::Omit)
+        .with_observer(outcomes.clone());
+    let data: Vec<WithDefaults> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[0].id, 1);
+    assert_eq!(data[0].name, "<unknown>");
+    assert_eq!(data[0].score, 0.0);
+    assert_eq!(data[0].level, 1);
+    assert!(data[0].note.is_none());
+    assert_eq!(data[1].name, "second");
+    assert_eq!(data[1].score, 2.5);
+    assert_eq!(data[1].level, 5);
+    assert_eq!(data[1].note.as_deref(), Some("note"));
+    assert_eq!(*outcomes.0.lock().unwrap(), vec![NullOutcome::Omitted; 4]);
+
+    let result: mock_db::Result<Vec<Legacy>> = get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "name", "score", "level", "note"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_null(),
+        MValue::new_null(),
+        MValue::new_null(),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_string("second".to_string()),
+        MValue::new_double(2.5),
+        MValue::new_short(5),
+        MValue::new_string("note".to_string()),
+    ]);
+    rs
+}
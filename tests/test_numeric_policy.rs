@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, NumericPolicy};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    small: u8,
+    count: i64,
+    ratio: f32,
+}
+
+#[test] // cargo test --test test_numeric_policy -- --nocapture
+pub fn test_numeric_policy() {
+    let _loghandle = util::init_logger();
+    info!("=== Numeric conversion policy ===");
+
+    let strict = DeserializationOptions::default().with_numeric_policy(NumericPolicy::Strict);
+    let checked = DeserializationOptions::default().with_numeric_policy(NumericPolicy::Checked);
+    let lossy = DeserializationOptions::default().with_numeric_policy(NumericPolicy::Lossy);
+
+    info!("Without policy, the driver decides");
+    let result: mock_db::Result<i64> = MValue::new_short(7).try_into();
+    assert!(result.is_err());
+
+    info!("Float to integer");
+    let value = || MValue::new_double(3.7);
+    assert!(value().try_into_with::<i32>(&strict).is_err());
+    assert_eq!(value().try_into_with::<i32>(&checked).unwrap(), 3);
+    assert_eq!(value().try_into_with::<i32>(&lossy).unwrap(), 3);
+    assert_eq!(
+        MValue::new_double(4.0)
+            .try_into_with::<u8>(&strict)
+            .unwrap(),
+        4
+    );
+    assert!(MValue::new_double(-1.0)
+        .try_into_with::<u64>(&checked)
+        .is_err());
+    assert_eq!(
+        MValue::new_double(-1.0)
+            .try_into_with::<u64>(&lossy)
+            .unwrap(),
+        0
+    );
+
+    info!("Integer narrowing");
+    let value = || MValue::new_short(-300);
+    assert!(value().try_into_with::<i8>(&strict).is_err());
+    assert!(value().try_into_with::<i8>(&checked).is_err());
+    assert_eq!(value().try_into_with::<i8>(&lossy).unwrap(), i8::MIN);
+    assert_eq!(
+        MValue::new_short(100).try_into_with::<i8>(&strict).unwrap(),
+        100
+    );
+
+    info!("Decimals");
+    assert_eq!(
+        MValue::new_decimal(12_500, -2)
+            .try_into_with::<u8>(&strict)
+            .unwrap(),
+        125
+    );
+    assert!(MValue::new_decimal(12_345, -2)
+        .try_into_with::<u8>(&strict)
+        .is_err());
+    assert_eq!(
+        MValue::new_decimal(12_345, -2)
+            .try_into_with::<u8>(&checked)
+            .unwrap(),
+        123
+    );
+    assert_eq!(
+        MValue::new_decimal(12_345, -2)
+            .try_into_with::<f64>(&strict)
+            .unwrap(),
+        123.45
+    );
+
+    info!("Rows");
+    let mut rs = Resultset::new(&["small", "count", "ratio"]);
+    rs.push(vec![
+        MValue::new_double(200.0),
+        MValue::new_short(42),
+        MValue::new_double(0.5),
+    ]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_double(1e20),
+        MValue::new_double(0.1),
+    ]);
+    let data: Vec<TestData> = rs.try_into_with(&lossy).unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[0].small, 200);
+    assert_eq!(data[0].count, 42);
+    assert_eq!(data[0].ratio, 0.5);
+    assert_eq!(data[1].count, i64::MAX);
+
+    let mut rs = Resultset::new(&["small", "count", "ratio"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_short(2),
+        MValue::new_double(0.1),
+    ]);
+    let result: mock_db::Result<Vec<TestData>> = rs.try_into_with(&strict);
+    assert!(result.is_err());
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, OutputParameters};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, FieldNameMatching, NullPolicy};
+
+#[derive(Debug, Deserialize)]
+struct Totals {
+    net: f64,
+    tax: f64,
+    currency: String,
+    comment: Option<String>,
+}
+
+#[test] // cargo test --test test_output_parameters -- --nocapture
+pub fn test_output_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== Output parameters ===");
+
+    info!("Struct");
+    let totals: Totals = get_output_parameters().try_into().unwrap();
+    debug!("{:?}", totals);
+    assert_eq!(totals.net, 100.0);
+    assert_eq!(totals.tax, 19.0);
+    assert_eq!(totals.currency, "EUR");
+    assert!(totals.comment.is_none());
+
+    info!("Tuple");
+    let (net, tax, currency, comment): (f64, f64, String, Option<String>) =
+        get_output_parameters().try_into().unwrap();
+    assert_eq!((net, tax, currency.as_str()), (100.0, 19.0, "EUR"));
+    assert!(comment.is_none());
+
+    info!("Single parameter into plain value");
+    let mut params = OutputParameters::new();
+    params.push("COUNT", MValue::new_short(42));
+    let count: i32 = params.try_into().unwrap();
+    assert_eq!(count, 42);
+
+    info!("Options apply");
+    let mut params = OutputParameters::new();
+    params.push("NET", MValue::new_double(1.0));
+    params.push("TAX", MValue::new_null());
+    params.push("CURRENCY", MValue::new_string("CHF".to_string()));
+    params.push("COMMENT", MValue::new_null());
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive)
+        .with_null_policy(NullPolicy::TypeDefault);
+    let totals: Totals = params.try_into_with(&options).unwrap();
+    assert_eq!(totals.tax, 0.0);
+    assert_eq!(totals.currency, "CHF");
+
+    info!("Unknown parameter");
+    let mut params = get_output_parameters();
+    params.push("extra", MValue::new_short(1));
+    let result: mock_db::Result<Totals> = params.try_into();
+    assert!(result.is_err());
+}
+
+fn get_output_parameters() -> OutputParameters {
+    let mut params = OutputParameters::new();
+    params.push("net", MValue::new_double(100.0));
+    params.push("tax", MValue::new_double(19.0));
+    params.push("currency", MValue::new_string("EUR".to_string()));
+    params.push("comment", MValue::new_null());
+    params
+}
@@ -0,0 +1,127 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde::Serialize;
+use serde_db::message::DefaultMessageFormatter;
+use serde_db::ser::{
+    to_named_params_batch, to_named_params_batch_with, to_params_batch, to_params_batch_with,
+    EnumRepresentation, SerializationError, SerializationOptions,
+};
+use std::collections::BTreeMap;
+
+#[derive(Serialize)]
+struct Item {
+    id: i32,
+    name: Option<String>,
+}
+
+#[derive(Clone, Copy, Serialize)]
+enum Kind {
+    Plain,
+    Special,
+}
+
+#[test] // cargo test --test test_parameter_batches -- --nocapture
+pub fn test_parameter_batches() {
+    let _loghandle = util::init_logger();
+    info!("=== batches of parameter rows ===");
+
+    let metadata = vec![PT::Short, PT::NullableString];
+
+    info!("One row of values per input");
+    let items = vec![
+        Item {
+            id: 1,
+            name: Some("one".to_string()),
+        },
+        Item { id: 2, name: None },
+    ];
+    let rows: Vec<Vec<MValue>> = to_params_batch(&items, &metadata).unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            vec![MValue::Short(1), MValue::String("one".to_string())],
+            vec![MValue::Short(2), MValue::Null],
+        ]
+    );
+    let rows: Vec<Vec<MValue>> = to_params_batch::<Item, _>(&[], &metadata).unwrap();
+    assert!(rows.is_empty());
+
+    info!("Errors report the failing row");
+    let tuples = [(1_i16, "a"), (2_i16, "b"), (100_i16, "c")];
+    let rows = to_params_batch(&tuples, &metadata).unwrap();
+    assert_eq!(rows.len(), 3);
+    let items = vec![
+        Item { id: 1, name: None },
+        Item {
+            id: 70_000,
+            name: None,
+        },
+    ];
+    let e = to_params_batch(&items, &metadata).unwrap_err();
+    debug!("{e:?}");
+    assert!(matches!(e, SerializationError::AtRow { index: 1, .. }));
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "in row 1: at parameter 0 from field id: value of type i32 does not fit into the range of SQL type Short"
+    );
+
+    info!("With options");
+    let kinds = [(1_i16, Kind::Plain), (2_i16, Kind::Special)];
+    let rows: Vec<Vec<MValue>> = to_params_batch(&kinds, &metadata).unwrap();
+    assert_eq!(rows[1][1], MValue::String("Special".to_string()));
+    let metadata = vec![PT::Short, PT::NullableShort];
+    let by_index =
+        SerializationOptions::default().with_enum_representation(EnumRepresentation::Index);
+    let rows: Vec<Vec<MValue>> = to_params_batch_with(&kinds, &metadata, &by_index).unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            vec![MValue::Short(1), MValue::Short(0)],
+            vec![MValue::Short(2), MValue::Short(1)],
+        ]
+    );
+    let by_name =
+        SerializationOptions::default().with_enum_representation(EnumRepresentation::Name);
+    let e = to_params_batch_with(&kinds, &metadata, &by_name).unwrap_err();
+    assert!(matches!(e, SerializationError::AtRow { index: 0, .. }));
+
+    info!("Named parameters");
+    let parameters = vec![
+        Parameter::new("name", PT::NullableString),
+        Parameter::new("id", PT::Short),
+    ];
+    let items = vec![
+        Item {
+            id: 1,
+            name: Some("one".to_string()),
+        },
+        Item { id: 2, name: None },
+    ];
+    let rows: Vec<Vec<MValue>> = to_named_params_batch(&items, &parameters).unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            vec![MValue::String("one".to_string()), MValue::Short(1)],
+            vec![MValue::Null, MValue::Short(2)],
+        ]
+    );
+    let maps: Vec<BTreeMap<String, i16>> = vec![
+        BTreeMap::from([("id".to_string(), 1)]),
+        BTreeMap::from([("id".to_string(), 2), ("nick".to_string(), 3)]),
+    ];
+    let parameters = vec![Parameter::new("id", PT::Short)];
+    let e = to_named_params_batch(&maps, &parameters).unwrap_err();
+    debug!("{e:?}");
+    assert!(matches!(e, SerializationError::AtRow { index: 1, .. }));
+    let rows: Vec<Vec<MValue>> =
+        to_named_params_batch_with(&maps[..1], &parameters, &SerializationOptions::default())
+            .unwrap();
+    assert_eq!(rows, vec![vec![MValue::Short(1)]]);
+    let e = to_named_params_batch(&items, &metadata).unwrap_err();
+    assert!(matches!(e, SerializationError::StructuralMismatch(_)));
+}
@@ -0,0 +1,136 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde_db::ser::{to_params, type_error, DbvFactory, SerializationError};
+
+// A descriptor whose conversions depend on the declared database type.
+enum Column {
+    BigInt,
+    Decimal { precision: u32, scale: u32 },
+}
+
+impl DbvFactory for &Column {
+    type DBV = MValue;
+
+    fn serialize_i64(&self, value: i64) -> Result<MValue, SerializationError> {
+        match self.precision() {
+            None => Ok(MValue::new_bigint(value)),
+            Some((precision, scale)) => {
+                let mantissa = i128::from(value) * 10_i128.pow(scale);
+                if mantissa.unsigned_abs() >= 10_u128.pow(precision) {
+                    return Err(SerializationError::Range("i64", self.descriptor()));
+                }
+                Ok(MValue::new_decimal(mantissa, -(scale as i32)))
+            }
+        }
+    }
+    fn serialize_bool(&self, _: bool) -> Result<MValue, SerializationError> {
+        Err(type_error("bool", self.descriptor()))
+    }
+    fn serialize_i8(&self, _: i8) -> Result<MValue, SerializationError> {
+        Err(type_error("i8", self.descriptor()))
+    }
+    fn serialize_i16(&self, _: i16) -> Result<MValue, SerializationError> {
+        Err(type_error("i16", self.descriptor()))
+    }
+    fn serialize_i32(&self, _: i32) -> Result<MValue, SerializationError> {
+        Err(type_error("i32", self.descriptor()))
+    }
+    fn serialize_u8(&self, _: u8) -> Result<MValue, SerializationError> {
+        Err(type_error("u8", self.descriptor()))
+    }
+    fn serialize_u16(&self, _: u16) -> Result<MValue, SerializationError> {
+        Err(type_error("u16", self.descriptor()))
+    }
+    fn serialize_u32(&self, _: u32) -> Result<MValue, SerializationError> {
+        Err(type_error("u32", self.descriptor()))
+    }
+    fn serialize_u64(&self, _: u64) -> Result<MValue, SerializationError> {
+        Err(type_error("u64", self.descriptor()))
+    }
+    fn serialize_f32(&self, _: f32) -> Result<MValue, SerializationError> {
+        Err(type_error("f32", self.descriptor()))
+    }
+    fn serialize_f64(&self, _: f64) -> Result<MValue, SerializationError> {
+        Err(type_error("f64", self.descriptor()))
+    }
+    fn serialize_char(&self, _: char) -> Result<MValue, SerializationError> {
+        Err(type_error("char", self.descriptor()))
+    }
+    fn serialize_str(&self, _: &str) -> Result<MValue, SerializationError> {
+        Err(type_error("str", self.descriptor()))
+    }
+    fn serialize_bytes(&self, _: &[u8]) -> Result<MValue, SerializationError> {
+        Err(type_error("bytes", self.descriptor()))
+    }
+    fn serialize_none(&self) -> Result<MValue, SerializationError> {
+        Ok(MValue::new_null())
+    }
+    fn descriptor(&self) -> String {
+        match self.precision() {
+            None => "BIGINT".to_string(),
+            Some((precision, scale)) => format!("DECIMAL({precision}, {scale})"),
+        }
+    }
+    fn is_nullable(&self) -> Option<bool> {
+        Some(false)
+    }
+    fn precision(&self) -> Option<(u32, u32)> {
+        match **self {
+            Column::BigInt => None,
+            Column::Decimal { precision, scale } => Some((precision, scale)),
+        }
+    }
+}
+
+#[test] // cargo test --test test_parameter_descriptors -- --nocapture
+pub fn test_parameter_descriptors() {
+    let _loghandle = util::init_logger();
+    info!("=== parameter descriptors ===");
+
+    info!("The same rust type is converted according to the descriptor");
+    let columns = [
+        Column::BigInt,
+        Column::Decimal {
+            precision: 5,
+            scale: 2,
+        },
+    ];
+    let values: Vec<MValue> = to_params(&(12_i64, 12_i64), &mut columns.iter()).unwrap();
+    assert_eq!(
+        values,
+        vec![MValue::new_bigint(12), MValue::new_decimal(1200, -2)]
+    );
+    let result = to_params(&(12_i64, 1_000_i64), &mut columns.iter());
+    debug!("{result:?}");
+    assert!(result.is_err());
+
+    info!("NULL is rejected for non-nullable parameters before the conversion");
+    let result = to_params(&(None::<i64>, 1_i64), &mut columns.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { index: 0, ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "none", .. })
+    ));
+    let result = to_params(&(None::<i16>,), &mut [PT::Short].iter());
+    assert!(result.is_err());
+    let values: Vec<MValue> = to_params(&(None::<i16>,), &mut [PT::NullableShort].iter()).unwrap();
+    assert_eq!(values, vec![MValue::Null]);
+
+    info!("Texts must not exceed the maximum length");
+    let parameters = [Parameter::new("code", PT::String).with_max_length(3)];
+    let values: Vec<MValue> = to_params(&("äöü",), &mut parameters.iter()).unwrap();
+    assert_eq!(values, vec![MValue::String("äöü".to_string())]);
+    let result = to_params(&("abcd",), &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { ref source, .. })
+            if matches!(**source, SerializationError::Range("str", _))
+    ));
+}
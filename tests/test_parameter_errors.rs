@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{Parameter, ParameterType as PT};
+use serde::{Serialize, Serializer};
+use serde_db::message::DefaultMessageFormatter;
+use serde_db::ser::{to_params, SerializationError};
+
+// A value whose serialization fails within serde.
+struct Unserializable;
+impl Serialize for Unserializable {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("not today"))
+    }
+}
+
+#[derive(Serialize)]
+struct Address {
+    city: String,
+    zip: i32,
+}
+
+#[derive(Serialize)]
+struct Customer {
+    id: i16,
+    address: Address,
+}
+
+#[test] // cargo test --test test_parameter_errors -- --nocapture
+pub fn test_parameter_errors() {
+    let _loghandle = util::init_logger();
+    info!("=== parameter errors ===");
+
+    info!("Errors contain the position, the name, the database type, and the field");
+    let parameters = [
+        Parameter::new("ID", PT::Short),
+        Parameter::new("CITY", PT::String),
+        Parameter::new("ZIP", PT::Short),
+    ];
+    let customer = Customer {
+        id: 1,
+        address: Address {
+            city: "Walldorf".to_string(),
+            zip: 69_190,
+        },
+    };
+    let e = to_params(&customer, &mut parameters.iter()).unwrap_err();
+    debug!("{e:?}");
+    match e {
+        SerializationError::AtParameter {
+            index,
+            ref name,
+            ref db_type,
+            field,
+            ref source,
+        } => {
+            assert_eq!(index, 2);
+            assert_eq!(name.as_deref(), Some("ZIP"));
+            assert_eq!(db_type, "Short");
+            assert_eq!(field, Some("zip"));
+            assert!(matches!(**source, SerializationError::Range("i32", _)));
+        }
+        _ => panic!("unexpected error: {e:?}"),
+    }
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "at parameter 2 (ZIP) from field zip: \
+         value of type i32 does not fit into the range of SQL type Short"
+    );
+
+    info!("The database type is added for errors that do not name it");
+    let e = to_params(
+        &(1_i16, Unserializable),
+        &mut [PT::Short, PT::String].iter(),
+    )
+    .unwrap_err();
+    debug!("{e:?}");
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "at parameter 1 of SQL type String: serde error: not today"
+    );
+}
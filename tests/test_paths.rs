@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize)]
+struct Attachment {
+    path: PathBuf,
+    original_name: OsString,
+    thumbnail: Option<PathBuf>,
+}
+
+#[test] // cargo test --test test_paths -- --nocapture
+pub fn test_paths() {
+    let _loghandle = util::init_logger();
+    info!("=== PathBuf and OsString from text columns ===");
+
+    let mut rs = Resultset::new(&["path", "original_name", "thumbnail"]);
+    rs.push(vec![
+        MValue::new_string("/var/files/4711.pdf".to_string()),
+        MValue::new_string("Übersicht.pdf".to_string()),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_string("/var/files/4712.png".to_string()),
+        MValue::new_char("logo.png", 12),
+        MValue::new_string("/var/thumbs/4712.png".to_string()),
+    ]);
+
+    info!("Structs with path fields");
+    let attachments: Vec<Attachment> = rs.try_into().unwrap();
+    assert_eq!(attachments[0].path, PathBuf::from("/var/files/4711.pdf"));
+    assert_eq!(
+        attachments[0].original_name,
+        OsString::from("Übersicht.pdf")
+    );
+    assert_eq!(attachments[0].thumbnail, None);
+    assert_eq!(attachments[1].original_name, OsString::from("logo.png    "));
+    assert_eq!(
+        attachments[1].thumbnail.as_deref(),
+        Some(std::path::Path::new("/var/thumbs/4712.png"))
+    );
+
+    info!("Single values");
+    let name: OsString = MValue::new_string("a.txt".to_string()).try_into().unwrap();
+    assert_eq!(name, "a.txt");
+
+    info!("Other enums named OsString");
+    let os: user::OsString = MValue::new_string("Linux".to_string()).try_into().unwrap();
+    assert_eq!(os, user::OsString::Linux);
+}
+
+mod user {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    pub enum OsString {
+        Linux,
+        Windows,
+    }
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT};
+use serde_db::message::DefaultMessageFormatter;
+use serde_db::ser::{to_params, SerializationError};
+
+#[test] // cargo test --test test_positional_parameters -- --nocapture
+pub fn test_positional_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== positional parameters ===");
+
+    let metadata = [PT::Short, PT::Short, PT::NullableShort];
+    let expected = vec![MValue::Short(1), MValue::Short(2), MValue::Short(3)];
+
+    info!("Tuples, arrays, and Vecs");
+    let values: Vec<MValue> =
+        to_params(&(1_i16, 2_i32, Some(3_i64)), &mut metadata.iter()).unwrap();
+    assert_eq!(values, expected);
+    let values: Vec<MValue> = to_params(&[1_i16, 2, 3], &mut metadata.iter()).unwrap();
+    assert_eq!(values, expected);
+    let values: Vec<MValue> = to_params(&vec![1_i16, 2, 3], &mut metadata.iter()).unwrap();
+    assert_eq!(values, expected);
+
+    info!("Too few and too many values");
+    let result = to_params(&vec![1_i16, 2], &mut metadata.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::ParameterCount {
+            expected: 3,
+            provided: 2
+        })
+    ));
+    let result = to_params(&vec![1_i16, 2, 3, 4], &mut metadata.iter());
+    assert!(matches!(
+        result,
+        Err(SerializationError::StructuralMismatch(_))
+    ));
+
+    info!("Conversion errors report the position of the parameter");
+    let result = to_params(&(1_i16, "two", 3_i16), &mut metadata.iter());
+    debug!("{result:?}");
+    let e = result.unwrap_err();
+    assert!(matches!(
+        e,
+        SerializationError::AtParameter { index: 1, name: None, ref source, .. }
+            if matches!(**source, SerializationError::Type { value_type: "str", .. })
+    ));
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "at parameter 1: value of type str cannot be converted into SQL type Short"
+    );
+
+    info!("... and its name, if known");
+    let parameters = [
+        Parameter::new("id", PT::Short),
+        Parameter::new("count", PT::Short),
+    ];
+    let result = to_params(&(1_i16, 100_000_i32), &mut parameters.iter());
+    let e = result.unwrap_err();
+    assert_eq!(
+        e.render(&DefaultMessageFormatter),
+        "at parameter 1 (count): value of type i32 does not fit into the range of SQL type Short"
+    );
+}
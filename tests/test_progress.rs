@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializationObserver, DeserializationOptions};
+use std::sync::{Arc, Mutex};
+
+// Records the reported progress.
+#[derive(Default)]
+struct ProgressRecorder(Mutex<Vec<usize>>);
+
+impl DeserializationObserver for ProgressRecorder {
+    fn progress(&self, rows: usize) {
+        self.0.lock().unwrap().push(rows);
+    }
+}
+
+#[test] // cargo test --test test_progress -- --nocapture
+pub fn test_progress() {
+    let _loghandle = util::init_logger();
+    info!("=== Progress reports ===");
+
+    let recorder = Arc::new(ProgressRecorder::default());
+    let options = DeserializationOptions::default()
+        .with_observer(recorder.clone())
+        .with_progress_interval(3);
+    assert_eq!(options.progress_interval(), Some(3));
+
+    info!("Every third row");
+    let data: Vec<i64> = get_resultset(10).try_into_with(&options).unwrap();
+    assert_eq!(data.len(), 10);
+    assert_eq!(*recorder.0.lock().unwrap(), vec![3, 6, 9]);
+
+    info!("No reports after a failure");
+    recorder.0.lock().unwrap().clear();
+    let mut rs = get_resultset(2);
+    rs.push(vec![MValue::new_null()]);
+    rs.push(vec![MValue::new_bigint(4)]);
+    let result: mock_db::Result<Vec<i64>> = rs.try_into_with(&options);
+    assert!(result.is_err());
+    assert!(recorder.0.lock().unwrap().is_empty());
+
+    info!("Disabled");
+    let options = options.with_progress_interval(0);
+    assert_eq!(options.progress_interval(), None);
+    recorder.0.lock().unwrap().clear();
+    let _data: Vec<i64> = get_resultset(10).try_into_with(&options).unwrap();
+    assert!(recorder.0.lock().unwrap().is_empty());
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id"]);
+    for id in 1..=rows {
+        rs.push(vec![MValue::new_bigint(id)]);
+    }
+    rs
+}
@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializableRow, DeserializationError};
+
+#[derive(Debug, Deserialize)]
+struct Narrow {
+    id: i64,
+    name: String,
+}
+
+#[test] // cargo test --test test_projection -- --nocapture
+pub fn test_projection() {
+    let _loghandle = util::init_logger();
+    info!("=== Column projection ===");
+
+    info!("By name");
+    let projection = get_resultset(3).project(&["name", "id"]).unwrap();
+    let lines: Vec<Narrow> = DeserializableResultset::try_into(projection).unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[2].id, 3);
+    assert_eq!(lines[2].name, "name 3");
+
+    info!("By index, in the given order");
+    let mut projection = get_resultset(2).project_at(&[2, 0]).unwrap();
+    assert_eq!(projection.number_of_fields(), 2);
+    assert_eq!(projection.fieldname(0), Some("remark"));
+    assert_eq!(projection.fieldname(1), Some("id"));
+    let row = projection.next().unwrap().unwrap();
+    assert_eq!(row.len(), 2);
+    let (remark, id): (String, i64) = DeserializableRow::try_into(row).unwrap();
+    assert_eq!((remark.as_str(), id), ("remark 1", 1));
+    let rest: Vec<(String, i64)> = DeserializableResultset::try_into(projection).unwrap();
+    assert_eq!(rest, vec![("remark 2".to_string(), 2)]);
+
+    info!("Single columns");
+    let projection = get_resultset(3).project(&["id"]).unwrap();
+    let ids: Vec<i64> = DeserializableResultset::try_into(projection).unwrap();
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    info!("Errors");
+    assert!(matches!(
+        get_resultset(1).project(&["id", "unknown"]),
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::Usage(_)
+        ))
+    ));
+    assert!(matches!(
+        get_resultset(1).project_at(&[3]),
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::Usage(_)
+        ))
+    ));
+    assert!(matches!(
+        get_resultset(1).project_at(&[1, 1]),
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::Usage(_)
+        ))
+    ));
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "name", "remark"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("name {i}")),
+            MValue::new_string(format!("remark {i}")),
+        ]);
+    }
+    rs
+}
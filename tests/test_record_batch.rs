@@ -0,0 +1,124 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+#[test] // cargo test --test test_record_batch --features arrow -- --nocapture
+pub fn test_record_batch() {
+    let _loghandle = util::init_logger();
+    info!("=== Arrow record batches ===");
+
+    #[cfg(feature = "arrow")]
+    record_batch::conversion();
+}
+
+#[cfg(feature = "arrow")]
+mod record_batch {
+    use crate::mock_db::{self, MValue, Resultset};
+    use arrow_array::{Array, Float64Array, Int64Array, StringArray};
+    use arrow_schema::DataType;
+    use serde_db::de::{DeserializableResultset, DeserializationError, DeserializationOptions};
+
+    pub fn conversion() {
+        info!("Column types follow the kinds of the values");
+        let batch = get_resultset().into_record_batch().unwrap();
+        debug!("{batch:?}");
+        assert_eq!(batch.num_rows(), 3);
+        let schema = batch.schema();
+        let types: Vec<(&str, &DataType)> = schema
+            .fields()
+            .iter()
+            .map(|field| (field.name().as_str(), field.data_type()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("id", &DataType::Int64),
+                ("name", &DataType::Utf8),
+                ("price", &DataType::Float64),
+                ("amount", &DataType::Utf8),
+                ("note", &DataType::Null),
+            ]
+        );
+        let ids = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(ids.values(), &[1, 2, 3]);
+        let names = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(names.value(0), "ABC");
+        assert!(names.is_null(1));
+        let prices = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert!((prices.value(2) - 0.25).abs() < f64::EPSILON);
+        let amounts = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(amounts.value(0).starts_with("12.3"));
+        assert_eq!(batch.column(4).len(), 3);
+
+        info!("Empty resultset");
+        let batch = Resultset::new(&["id", "name"]).into_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.num_columns(), 2);
+
+        info!("Values that don't fit to the type of their column");
+        let mut rs = Resultset::new(&["id"]);
+        rs.push(vec![MValue::new_short(1)]);
+        rs.push(vec![MValue::new_string("one".to_string())]);
+        let result: mock_db::Result<_> = rs.into_record_batch();
+        debug!("{result:?}");
+        assert!(matches!(
+            result,
+            Err(mock_db::Error::DESERIALIZATION(DeserializationError::AtField { ref path, .. }))
+                if path == "id"
+        ));
+
+        info!("Options apply");
+        let options = DeserializationOptions::default().with_max_rows(2);
+        let result: mock_db::Result<_> = get_resultset().into_record_batch_with(&options);
+        assert!(matches!(
+            result,
+            Err(mock_db::Error::DESERIALIZATION(
+                DeserializationError::RowLimitExceeded(2)
+            ))
+        ));
+    }
+
+    fn get_resultset() -> Resultset {
+        let mut rs = Resultset::new(&["id", "name", "price", "amount", "note"]);
+        rs.push(vec![
+            MValue::new_short(1),
+            MValue::new_string("ABC".to_string()),
+            MValue::new_double(10.5),
+            MValue::new_decimal(1234, -2),
+            MValue::new_null(),
+        ]);
+        rs.push(vec![
+            MValue::new_bigint(2),
+            MValue::new_null(),
+            MValue::new_double(20.0),
+            MValue::new_decimal(5, 0),
+            MValue::new_null(),
+        ]);
+        rs.push(vec![
+            MValue::new_short(3),
+            MValue::new_string("QRS".to_string()),
+            MValue::new_double(0.25),
+            MValue::new_null(),
+            MValue::new_null(),
+        ]);
+        rs
+    }
+}
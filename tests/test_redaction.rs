@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationOptions, FieldNameMatching};
+use serde_db::message::DefaultMessageFormatter;
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+struct Login {
+    user: String,
+    pin: i16,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+struct Logins {
+    user: Vec<String>,
+    pin: Vec<i16>,
+}
+
+#[test] // cargo test --test test_redaction -- --nocapture
+pub fn test_redaction() {
+    let _loghandle = util::init_logger();
+    info!("=== redaction of sensitive values ===");
+
+    info!("Without redaction, the driver's message contains the value");
+    let message = render(get_resultset(&["user", "pin"]).try_into::<Vec<Login>>());
+    debug!("{message}");
+    assert!(message.contains("hunter2"));
+
+    info!("Values of sensitive columns are redacted");
+    let options = DeserializationOptions::default()
+        .with_sensitive_columns(&["pin"])
+        .with_field_paths(true);
+    let message = render(get_resultset(&["user", "pin"]).try_into_with::<Vec<Login>>(&options));
+    debug!("{message}");
+    assert!(!message.contains("hunter2"));
+    assert!(message.contains("column pin"));
+    assert!(message.contains("i16"));
+
+    info!("Column names are matched like field names");
+    let options = DeserializationOptions::default()
+        .with_field_name_matching(FieldNameMatching::CaseInsensitive)
+        .with_sensitive_columns(&["pin"]);
+    let message = render(get_resultset(&["USER", "PIN"]).try_into_with::<Vec<Login>>(&options));
+    assert!(!message.contains("hunter2"));
+    assert!(message.contains("column PIN"));
+
+    info!("Tuples and columnar targets");
+    let options = DeserializationOptions::default().with_sensitive_columns(&["pin"]);
+    let message =
+        render(get_resultset(&["user", "pin"]).try_into_with::<Vec<(String, i16)>>(&options));
+    assert!(!message.contains("hunter2"));
+    let message = render(get_resultset(&["user", "pin"]).try_into_columns_with::<Logins>(&options));
+    debug!("{message}");
+    assert!(!message.contains("hunter2"));
+    assert!(message.contains("column pin"));
+}
+
+fn get_resultset(fields: &[&'static str]) -> Resultset {
+    let mut rs = Resultset::new(fields);
+    rs.push(vec![
+        MValue::new_string("alice".to_string()),
+        MValue::new_string("hunter2".to_string()),
+    ]);
+    rs
+}
+
+fn render<T: std::fmt::Debug>(result: mock_db::Result<T>) -> String {
+    match result.unwrap_err() {
+        mock_db::Error::DESERIALIZATION(e) => e.render(&DefaultMessageFormatter),
+        e => panic!("unexpected error: {e:?}"),
+    }
+}
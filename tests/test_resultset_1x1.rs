@@ -15,7 +15,7 @@ pub fn test_resultset_1x1() {
     match evaluate_field_rs(&mut loghandle) {
         Err(e) => {
             error!("test_resultset_1x1() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_resultset_1x1() ended successful"),
     }
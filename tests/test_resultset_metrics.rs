@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializationObserver, DeserializationOptions, ResultsetMetrics};
+use std::sync::{Arc, Mutex};
+
+// Collects the metrics of all converted resultsets.
+#[derive(Default)]
+struct MetricsCollector(Mutex<Vec<ResultsetMetrics>>);
+
+impl DeserializationObserver for MetricsCollector {
+    fn resultset_deserialized(&self, metrics: &ResultsetMetrics) {
+        self.0.lock().unwrap().push(*metrics);
+    }
+}
+
+#[test] // cargo test --test test_resultset_metrics -- --nocapture
+pub fn test_resultset_metrics() {
+    let _loghandle = util::init_logger();
+    info!("=== Resultset metrics ===");
+
+    let collector = Arc::new(MetricsCollector::default());
+    let options = DeserializationOptions::default().with_observer(collector.clone());
+
+    info!("Successful conversions");
+    let data: Vec<(i64, String)> = get_resultset(4).try_into_with(&options).unwrap();
+    assert_eq!(data.len(), 4);
+    let (id, _): (i64, String) = get_resultset(1).try_into_with(&options).unwrap();
+    assert_eq!(id, 1);
+
+    info!("Failing conversion");
+    let result: mock_db::Result<Vec<(i64, i16)>> = get_resultset(3).try_into_with(&options);
+    assert!(result.is_err());
+
+    let metrics = collector.0.lock().unwrap();
+    debug!("{:?}", metrics);
+    assert_eq!(metrics.len(), 3);
+    assert_eq!((metrics[0].rows, metrics[0].succeeded), (4, true));
+    assert_eq!((metrics[1].rows, metrics[1].succeeded), (1, true));
+    assert_eq!((metrics[2].rows, metrics[2].succeeded), (1, false));
+
+    info!("Bytes of the values");
+    // "name 1" to "name 4"
+    assert_eq!(metrics[0].bytes, 4 * 6);
+    assert_eq!(metrics[1].bytes, 6);
+    assert_eq!(metrics[2].bytes, 6);
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "name"]);
+    for id in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(id),
+            MValue::new_string(format!("name {id}")),
+        ]);
+    }
+    rs
+}
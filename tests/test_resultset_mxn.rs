@@ -19,7 +19,7 @@ pub fn test_resultset_mxn() {
     match evaluate_matrix_rs(&mut loghandle) {
         Err(e) => {
             error!("test_resultset_mxn() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_resultset_mxn() ended successful"),
     }
@@ -103,7 +103,7 @@ fn not_into_vec_field(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<Vec<String>> = get_resultset_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -113,7 +113,7 @@ fn not_into_struct(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<TestData> = get_resultset_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -123,7 +123,7 @@ fn not_into_field(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<String> = get_resultset_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -144,7 +144,7 @@ fn not_into_short_struct(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let test: mock_db::Result<Vec<ShortData>> =
         get_resultset_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -165,7 +165,7 @@ fn not_rows_into_long_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()
         let test: Result<(String, NaiveDateTime, i32, Option<i32>, i32), mock_db::Error> =
             row.try_into();
         match test {
-            Ok(_) => assert!(false, "Failed \"{}\"", s),
+            Ok(_) => panic!("Failed \"{}\"", s),
             Err(e) => info!("--> Exception: {:?}", e),
         }
     }
@@ -182,13 +182,12 @@ fn rows_into_short_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
 }
 fn rows_map_fold(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("Iterate over rows, map, fold");
-    let sum = get_resultset_string_ts_short_short(SIZE)
-        .into_iter()
+    let sum: i32 = get_resultset_string_ts_short_short(SIZE)
         .map(|r| {
             let i: i32 = r.cloned_value(2).unwrap().try_into().unwrap();
             i
         })
-        .fold(0, |acc, i| acc + i);
+        .sum();
     assert_eq!(sum as usize, SIZE * (SIZE + 1) / 2);
     Ok(())
 }
@@ -222,7 +221,7 @@ fn not_rows_into_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let resultset = get_resultset_string_ts_short_short(4);
     let test: Result<(String, chrono::NaiveDateTime, i32, i32), _> = resultset.try_into();
     match test {
-        Ok(_) => assert!(false, "Deserialization was unexpectedly successful"),
+        Ok(_) => panic!("Deserialization was unexpectedly successful"),
         Err(e) => {
             info!("--> Exception: {:?}", e);
         }
@@ -233,15 +232,11 @@ fn not_rows_into_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
 fn not_row_into_value(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let s = "Negative test: no conversion of row into field if two or more colums";
     info!("{}", s);
-    for row in get_resultset_string_ts_short_short(1) {
-        let test: Result<String, _> = row.try_into();
-        match test {
-            Ok(_) => assert!(false, "Failed \"{}\"", s),
-            Err(e) => {
-                info!("--> Exception: {:?}", e);
-                break;
-            }
-        }
+    let row = get_resultset_string_ts_short_short(1).next().unwrap();
+    let test: Result<String, _> = row.try_into();
+    match test {
+        Ok(_) => panic!("Failed \"{}\"", s),
+        Err(e) => info!("--> Exception: {:?}", e),
     }
 
     Ok(())
@@ -259,7 +254,7 @@ fn not_rows_into_vec_of_short_struct(_loghandle: &mut LoggerHandle) -> mock_db::
     let resultset = get_resultset_string_ts_short_short(4);
     let test: Result<Vec<TestData>, _> = resultset.try_into();
     match test {
-        Ok(test) => assert!(false, "Could deserialize \"{:?}\"", test),
+        Ok(test) => panic!("Could deserialize \"{:?}\"", test),
         Err(e) => {
             info!("--> Exception: {:?}", e);
         }
@@ -272,9 +267,10 @@ fn not_rows_into_vec_of_long_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Re
     let s = "Negative test: no conversion of rows into vec of too long tuple";
     info!("{}", s);
     let resultset = get_resultset_string_ts_short_short(4);
-    let test: Result<Vec<(String, NaiveDateTime, i32, i32, i32)>, _> = resultset.try_into();
+    type LongTuple = (String, NaiveDateTime, i32, i32, i32);
+    let test: Result<Vec<LongTuple>, _> = resultset.try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{:?}\"", s),
+        Ok(_) => panic!("Failed \"{:?}\"", s),
         Err(e) => {
             info!("--> Exception: {:?}", e);
         }
@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{DeserializationOptions, NumericPolicy, RoundingMode};
+
+#[test] // cargo test --test test_rounding_mode -- --nocapture
+pub fn test_rounding_mode() {
+    let _loghandle = util::init_logger();
+    info!("=== Rounding mode ===");
+
+    let checked = DeserializationOptions::default().with_numeric_policy(NumericPolicy::Checked);
+
+    info!("Truncate");
+    let values: Vec<i32> = get_resultset().try_into_with(&checked).unwrap();
+    assert_eq!(values, vec![2, 3, -2, 1, 7]);
+
+    info!("HalfEven");
+    let options = checked.clone().with_rounding_mode(RoundingMode::HalfEven);
+    let values: Vec<i32> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", values);
+    assert_eq!(values, vec![2, 4, -2, 2, 7]);
+    let value: i16 = MValue::new_double(-0.7).try_into_with(&options).unwrap();
+    assert_eq!(value, -1);
+
+    info!("Reject");
+    let options = checked.with_rounding_mode(RoundingMode::Reject);
+    let result: mock_db::Result<Vec<i32>> = get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+    let value: i32 = MValue::new_double(7.0).try_into_with(&options).unwrap();
+    assert_eq!(value, 7);
+
+    info!("Floating point targets are not rounded");
+    let value: f64 = MValue::new_decimal(1_25, -2)
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(value, 1.25);
+
+    info!("Strict ignores the rounding mode");
+    let options = DeserializationOptions::default()
+        .with_numeric_policy(NumericPolicy::Strict)
+        .with_rounding_mode(RoundingMode::HalfEven);
+    let result: mock_db::Result<i32> = MValue::new_double(2.5).try_into_with(&options);
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["value"]);
+    rs.push(vec![MValue::new_double(2.5)]);
+    rs.push(vec![MValue::new_double(3.5)]);
+    rs.push(vec![MValue::new_double(-2.5)]);
+    rs.push(vec![MValue::new_decimal(1_51, -2)]);
+    rs.push(vec![MValue::new_short(7)]);
+    rs
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializableRow, ROW_INDEX_FIELD};
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    #[serde(rename = "__row_index")]
+    row_index: usize,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OptionalIndex {
+    #[serde(rename = "__row_index", default)]
+    row_index: Option<i64>,
+    name: String,
+}
+
+#[test] // cargo test --test test_row_index -- --nocapture
+pub fn test_row_index() {
+    let _loghandle = util::init_logger();
+    info!("=== Row index fields ===");
+    assert_eq!(ROW_INDEX_FIELD, "__row_index");
+
+    info!("Resultsets");
+    let lines: Vec<Line> = get_resultset(3).try_into().unwrap();
+    assert_eq!(
+        lines.iter().map(|l| l.row_index).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(lines[2].name, "name 2");
+
+    info!("Single rows");
+    let line: Line = get_resultset(1).try_into().unwrap();
+    assert_eq!(line.row_index, 0);
+
+    info!("Collected errors");
+    let (lines, errors) = get_resultset(4)
+        .try_into_collect_errors::<OptionalIndex>()
+        .unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(lines[3].row_index, Some(3));
+
+    info!("Individual rows have no index");
+    let mut rs = get_resultset(2);
+    let row = rs.next().unwrap();
+    let line: OptionalIndex = DeserializableRow::try_into(row).unwrap();
+    assert_eq!(line.row_index, None);
+    assert_eq!(line.name, "name 0");
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["name"]);
+    for i in 0..rows {
+        rs.push(vec![MValue::new_string(format!("name {i}"))]);
+    }
+    rs
+}
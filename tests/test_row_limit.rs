@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationError, DeserializationOptions};
+
+#[derive(Debug, Deserialize)]
+struct Columns {
+    id: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TextColumns {
+    #[allow(dead_code)]
+    text: Vec<String>,
+}
+
+#[test] // cargo test --test test_row_limit -- --nocapture
+pub fn test_row_limit() {
+    let _loghandle = util::init_logger();
+    info!("=== Row limit ===");
+
+    let options = DeserializationOptions::default().with_max_rows(5);
+    assert_eq!(options.max_rows(), Some(5));
+
+    info!("Within the limit");
+    let data: Vec<i64> = get_resultset(5).try_into_with(&options).unwrap();
+    assert_eq!(data.len(), 5);
+
+    info!("Beyond the limit");
+    let result: mock_db::Result<Vec<i64>> = get_resultset(6).try_into_with(&options);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(e)) => {
+            assert!(matches!(e, DeserializationError::RowLimitExceeded(5)));
+            assert_eq!(e.to_string(), "the resultset exceeds the limit of 5 rows");
+        }
+        _ => panic!("unexpected result: {result:?}"),
+    }
+
+    info!("Columnar conversion");
+    let columns: Columns = get_resultset(5).try_into_columns_with(&options).unwrap();
+    assert_eq!(columns.id.len(), 5);
+    let result: mock_db::Result<Columns> = get_resultset(7).try_into_columns_with(&options);
+    assert!(result.is_err());
+
+    info!("Byte limit");
+    let options = DeserializationOptions::default().with_max_bytes(25);
+    assert_eq!(options.max_bytes(), Some(25));
+    let data: Vec<String> = get_text_resultset(5).try_into_with(&options).unwrap();
+    assert_eq!(data.len(), 5);
+    let result: mock_db::Result<Vec<String>> = get_text_resultset(6).try_into_with(&options);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(e)) => {
+            assert!(matches!(e, DeserializationError::ByteLimitExceeded(25)));
+            assert_eq!(
+                e.to_string(),
+                "the resultset exceeds the budget of 25 bytes"
+            );
+        }
+        _ => panic!("unexpected result: {result:?}"),
+    }
+
+    info!("Byte limit of columnar conversions and of single rows");
+    let result: mock_db::Result<TextColumns> =
+        get_text_resultset(6).try_into_columns_with(&options);
+    assert!(result.is_err());
+    let options = DeserializationOptions::default().with_max_bytes(4);
+    let result: mock_db::Result<String> = get_text_resultset(1).try_into_with(&options);
+    assert!(result.is_err());
+
+    info!("Rows that do not report their size");
+    // the rows of a projection do not implement byte_len()
+    let result: mock_db::Result<Vec<String>> = get_text_resultset(1)
+        .project(&["text"])
+        .unwrap()
+        .try_into_with(&options);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(e)) => {
+            assert!(matches!(e, DeserializationError::Usage(_)));
+        }
+        _ => panic!("unexpected result: {result:?}"),
+    }
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id"]);
+    for id in 1..=rows {
+        rs.push(vec![MValue::new_bigint(id)]);
+    }
+    rs
+}
+
+// each value has 5 bytes
+fn get_text_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["text"]);
+    for id in 1..=rows {
+        rs.push(vec![MValue::new_string(format!("t{id:04}"))]);
+    }
+    rs
+}
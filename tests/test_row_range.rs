@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::DeserializableResultset;
+
+#[test] // cargo test --test test_row_range -- --nocapture
+pub fn test_row_range() {
+    let _loghandle = util::init_logger();
+    info!("=== Row ranges ===");
+
+    info!("skip_rows and take_rows");
+    let page = get_resultset(10).skip_rows(3).take_rows(4);
+    assert_eq!(page.remaining_rows(), Some(4));
+    let ids: Vec<i64> = DeserializableResultset::try_into(page).unwrap();
+    assert_eq!(ids, vec![4, 5, 6, 7]);
+
+    let ids: Vec<i64> = DeserializableResultset::try_into(get_resultset(5).skip_rows(3)).unwrap();
+    assert_eq!(ids, vec![4, 5]);
+    let ids: Vec<i64> = DeserializableResultset::try_into(get_resultset(5).take_rows(2)).unwrap();
+    assert_eq!(ids, vec![1, 2]);
+    let ids: Vec<i64> =
+        DeserializableResultset::try_into(get_resultset(5).take_rows(3).skip_rows(1)).unwrap();
+    assert_eq!(ids, vec![2, 3]);
+
+    info!("Single rows");
+    let id: i64 =
+        DeserializableResultset::try_into(get_resultset(5).skip_rows(2).take_rows(1)).unwrap();
+    assert_eq!(id, 3);
+
+    info!("Ranges");
+    let ids: Vec<i64> = get_resultset(10).try_into_range(2..5).unwrap();
+    assert_eq!(ids, vec![3, 4, 5]);
+    let ids: Vec<i64> = get_resultset(10).try_into_range(8..).unwrap();
+    assert_eq!(ids, vec![9, 10]);
+    let ids: Vec<i64> = get_resultset(10).try_into_range(..=1).unwrap();
+    assert_eq!(ids, vec![1, 2]);
+    let ids: Vec<i64> = get_resultset(3).try_into_range(5..8).unwrap();
+    assert!(ids.is_empty());
+
+    info!("Empty ranges");
+    let mut range = get_resultset(3).skip_rows(3);
+    assert!(range.has_no_rows().unwrap());
+    assert!(range.next().unwrap().is_none());
+    let mut range = get_resultset(3).take_rows(0);
+    assert!(range.has_no_rows().unwrap());
+    assert_eq!(range.remaining_rows(), Some(0));
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id"]);
+    for i in 1..=rows {
+        rs.push(vec![MValue::new_bigint(i)]);
+    }
+    rs
+}
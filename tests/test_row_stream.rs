@@ -0,0 +1,118 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{ChunkedResultset, MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::RowStream;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+// A minimal executor, which polls the future until it is ready.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 1;
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, polls),
+            Poll::Pending => polls += 1,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    id: i64,
+    amount: Option<i16>,
+}
+
+#[test] // cargo test --test test_row_stream -- --nocapture
+pub fn test_row_stream() {
+    let _loghandle = util::init_logger();
+    info!("=== Stream of typed rows ===");
+
+    info!("Consume all rows");
+    let (lines, polls) = block_on(async {
+        let mut stream = RowStream::<_, Line>::new(ChunkedResultset::new(get_resultset(5), 2));
+        let mut lines = Vec::new();
+        while let Some(line) = stream.next().await {
+            lines.push(line.unwrap());
+        }
+        lines
+    });
+    assert_eq!(
+        lines.iter().map(|l| l.id).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(lines[2].amount, None);
+    // three chunks plus the final empty fetch, each of which is pending once
+    assert_eq!(polls, 5);
+
+    info!("Conversion errors are reported per row");
+    let (results, _) = block_on(async {
+        let mut stream =
+            RowStream::<_, (i64, i16)>::new(ChunkedResultset::new(get_resultset(4), 3));
+        let mut results = Vec::new();
+        while let Some(result) = stream.next().await {
+            results.push(result);
+        }
+        results
+    });
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok() && results[1].is_ok() && results[3].is_ok());
+    assert!(results[2].is_err());
+
+    info!("Empty resultset");
+    let (next, _) = block_on(async {
+        RowStream::<_, Line>::new(ChunkedResultset::new(get_resultset(0), 2))
+            .next()
+            .await
+    });
+    assert!(next.is_none());
+
+    #[cfg(feature = "futures")]
+    row_stream_as_futures_stream();
+}
+
+#[cfg(feature = "futures")]
+fn row_stream_as_futures_stream() {
+    use futures_core::Stream;
+
+    // Collects the items of any stream.
+    async fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+        let mut items = Vec::new();
+        while let Some(item) =
+            std::future::poll_fn(|cx| std::pin::Pin::new(&mut stream).poll_next(cx)).await
+        {
+            items.push(item);
+        }
+        items
+    }
+
+    info!("RowStream as futures::Stream");
+    let (lines, _) = block_on(collect(RowStream::<_, Line>::new(ChunkedResultset::new(
+        get_resultset(5),
+        2,
+    ))));
+    assert_eq!(
+        lines.into_iter().map(|l| l.unwrap().id).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "amount"]);
+    for id in 1..=rows {
+        let amount = if id == 3 {
+            MValue::new_null()
+        } else {
+            MValue::new_short(i16::try_from(id * 10).unwrap())
+        };
+        rs.push(vec![MValue::new_bigint(id), amount]);
+    }
+    rs
+}
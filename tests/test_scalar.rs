@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde_db::de::{
+    DeserializableResultset, DeserializationError, DeserializationOptions, NullPolicy,
+};
+
+#[test] // cargo test --test test_scalar -- --nocapture
+pub fn test_scalar() {
+    let _loghandle = util::init_logger();
+    info!("=== Scalar extraction ===");
+
+    info!("Exactly one value");
+    let mut rs = Resultset::new(&["COUNT"]);
+    rs.push(vec![MValue::new_bigint(42)]);
+    let count: i64 = rs.scalar().unwrap();
+    assert_eq!(count, 42);
+
+    info!("NULL value");
+    let mut rs = Resultset::new(&["MAX"]);
+    rs.push(vec![MValue::new_null()]);
+    let max: Option<i32> = rs.scalar().unwrap();
+    assert_eq!(max, None);
+
+    info!("With options");
+    let mut rs = Resultset::new(&["MAX"]);
+    rs.push(vec![MValue::new_null()]);
+    let options = DeserializationOptions::default().with_null_policy(NullPolicy::TypeDefault);
+    let max: i32 = rs.scalar_with(&options).unwrap();
+    assert_eq!(max, 0);
+
+    info!("No rows");
+    let result: mock_db::Result<i64> = Resultset::new(&["COUNT"]).scalar();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::NoRows
+        ))
+    ));
+
+    info!("More than one row");
+    let mut rs = Resultset::new(&["COUNT"]);
+    rs.push(vec![MValue::new_bigint(1)]);
+    rs.push(vec![MValue::new_bigint(2)]);
+    let result: mock_db::Result<i64> = rs.scalar();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::MultipleRows
+        ))
+    ));
+
+    info!("More than one column");
+    let mut rs = Resultset::new(&["A", "B"]);
+    rs.push(vec![MValue::new_bigint(1), MValue::new_bigint(2)]);
+    let result: mock_db::Result<i64> = rs.scalar();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::MultipleColumns
+        ))
+    ));
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+#[test] // cargo test --test test_secret_columns --features secrecy -- --nocapture
+pub fn test_secret_columns() {
+    let _loghandle = util::init_logger();
+    info!("=== Secret columns ===");
+
+    #[cfg(feature = "secrecy")]
+    secrets::secret_columns();
+}
+
+#[cfg(feature = "secrecy")]
+mod secrets {
+    use crate::mock_db::{MValue, Resultset};
+    use secrecy::{ExposeSecret, SecretString, SecretVec};
+    use serde::Deserialize;
+    use serde_db::de::DbValueInto;
+
+    #[derive(Deserialize)]
+    struct Credentials {
+        user: String,
+        password: SecretString,
+        #[serde(deserialize_with = "serde_db::de::deserialize_secret_bytes")]
+        key: SecretVec<u8>,
+    }
+
+    pub fn secret_columns() {
+        info!("Deserialization of rows with secret fields");
+        let credentials: Vec<Credentials> = get_resultset().try_into().unwrap();
+        assert_eq!(credentials.len(), 2);
+        assert_eq!(credentials[0].user, "alice");
+        assert_eq!(credentials[0].password.expose_secret(), "hunter2");
+        assert_eq!(credentials[0].key.expose_secret(), &vec![1_u8, 2, 3]);
+        assert_eq!(credentials[1].key.expose_secret(), b"k3y");
+        // the secrets don't show up in the debug output
+        let debug = format!("{:?}", credentials[0].password);
+        debug!("{debug}");
+        assert!(!debug.contains("hunter2"));
+
+        info!("Conversion of individual values with DbValueInto");
+        let secret: SecretString =
+            DbValueInto::try_into(MValue::new_string("s3cret".to_string())).unwrap();
+        assert_eq!(secret.expose_secret(), "s3cret");
+        let secret: SecretVec<u8> = DbValueInto::try_into(MValue::new_lob(vec![7_u8])).unwrap();
+        assert_eq!(secret.expose_secret(), &vec![7_u8]);
+    }
+
+    fn get_resultset() -> Resultset {
+        let mut rs = Resultset::new(&["user", "password", "key"]);
+        rs.push(vec![
+            MValue::new_string("alice".to_string()),
+            MValue::new_string("hunter2".to_string()),
+            MValue::new_lob(vec![1, 2, 3]),
+        ]);
+        rs.push(vec![
+            MValue::new_string("bob".to_string()),
+            MValue::new_string("correct horse".to_string()),
+            MValue::new_lob(b"k3y".to_vec()),
+        ]);
+        rs
+    }
+}
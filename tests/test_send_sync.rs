@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    ConversionError, DeserializableResultset, DeserializableRow, DeserializationError,
+    DeserializationOptions,
+};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    id: i64,
+    name: String,
+}
+
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+#[test] // cargo test --test test_send_sync -- --nocapture
+pub fn test_send_sync() {
+    let _loghandle = util::init_logger();
+    info!("=== Send and Sync ===");
+
+    assert_send_sync::<ConversionError>();
+    assert_send_sync::<DeserializationError>();
+    assert_send_sync::<DeserializationOptions>();
+    assert_send_sync::<mock_db::Error>();
+
+    info!("Resultsets are deserialized in another thread");
+    let options = Arc::new(DeserializationOptions::default());
+    let rs = get_resultset(3);
+    let handle = {
+        let options = Arc::clone(&options);
+        thread::spawn(move || -> mock_db::Result<Vec<Line>> {
+            DeserializableResultset::try_into_with(rs, &options)
+        })
+    };
+    let lines = handle.join().unwrap().unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[2].name, "name 3");
+
+    info!("Rows are deserialized in worker threads, errors are sent back");
+    let (sender, receiver) = mpsc::channel::<(usize, mock_db::Error)>();
+    let mut rs = get_resultset(4);
+    rs.push(vec![
+        MValue::new_string("five".to_string()),
+        MValue::new_string("name 5".to_string()),
+    ]);
+    let mut handles = Vec::new();
+    let mut idx = 0;
+    while let Some(row) = rs.next() {
+        let sender = sender.clone();
+        let options = Arc::clone(&options);
+        handles.push(thread::spawn(move || {
+            let result: mock_db::Result<Line> = DeserializableRow::try_into_with(row, &options);
+            match result {
+                Ok(line) => Some(line.id),
+                Err(e) => {
+                    sender.send((idx, e)).unwrap();
+                    None
+                }
+            }
+        }));
+        idx += 1;
+    }
+    drop(sender);
+    let ids = handles
+        .into_iter()
+        .filter_map(|h| h.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+    let errors = receiver.iter().collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 4);
+    assert!(matches!(
+        errors[0].1,
+        mock_db::Error::DESERIALIZATION(DeserializationError::ConversionError(_))
+    ));
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "name"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("name {i}")),
+        ]);
+    }
+    rs
+}
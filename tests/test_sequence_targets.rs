@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::DeserializableResultset;
+use std::collections::{BinaryHeap, LinkedList, VecDeque};
+
+#[derive(Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+struct Task {
+    priority: i64,
+    name: String,
+}
+
+#[test] // cargo test --test test_sequence_targets -- --nocapture
+pub fn test_sequence_targets() {
+    let _loghandle = util::init_logger();
+    info!("=== Other sequence targets than Vec ===");
+
+    info!("VecDeque and LinkedList keep the order of the rows");
+    let tasks: VecDeque<Task> = get_resultset().try_into().unwrap();
+    assert_eq!(tasks.front().unwrap().name, "write");
+    assert_eq!(tasks.back().unwrap().name, "test");
+    let tasks: LinkedList<(i64, String)> = get_resultset().try_into().unwrap();
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(tasks.front().unwrap().1, "write");
+
+    info!("BinaryHeap orders the rows");
+    let mut tasks: BinaryHeap<Task> = get_resultset().try_into().unwrap();
+    assert_eq!(tasks.pop().unwrap().name, "review");
+    assert_eq!(tasks.pop().unwrap().name, "write");
+
+    info!("Single-column resultsets");
+    let projection = get_resultset().project(&["priority"]).unwrap();
+    let priorities: BinaryHeap<i64> = DeserializableResultset::try_into(projection).unwrap();
+    assert_eq!(priorities.into_sorted_vec(), vec![1, 2, 3]);
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["priority", "name"]);
+    for (priority, name) in [(2, "write"), (3, "review"), (1, "test")] {
+        rs.push(vec![
+            MValue::new_bigint(priority),
+            MValue::new_string(name.to_string()),
+        ]);
+    }
+    rs
+}
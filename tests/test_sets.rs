@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use std::collections::{BTreeSet, HashSet};
+
+#[test] // cargo test --test test_sets -- --nocapture
+pub fn test_sets() {
+    let _loghandle = util::init_logger();
+    info!("=== Single-column resultsets into sets ===");
+
+    info!("BTreeSet and HashSet of values");
+    let ids: BTreeSet<i64> = get_ids(&[3, 1, 2, 1]).try_into().unwrap();
+    assert_eq!(ids.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    let ids: HashSet<i64> = get_ids(&[3, 1, 2, 1]).try_into().unwrap();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.contains(&2));
+
+    info!("Sets from a single row, and from an empty resultset");
+    let ids: BTreeSet<i64> = get_ids(&[7]).try_into().unwrap();
+    assert_eq!(ids.into_iter().collect::<Vec<_>>(), vec![7]);
+    let ids: HashSet<i64> = get_ids(&[]).try_into().unwrap();
+    assert!(ids.is_empty());
+
+    info!("Sets of tuples from multi-column resultsets");
+    let mut rs = Resultset::new(&["id", "name"]);
+    for (id, name) in [(2, "b"), (1, "a"), (2, "b")] {
+        rs.push(vec![
+            MValue::new_bigint(id),
+            MValue::new_string(name.to_string()),
+        ]);
+    }
+    let pairs: BTreeSet<(i64, String)> = rs.try_into().unwrap();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs.first(), Some(&(1, "a".to_string())));
+}
+
+fn get_ids(ids: &[i64]) -> Resultset {
+    let mut rs = Resultset::new(&["id"]);
+    for id in ids {
+        rs.push(vec![MValue::new_bigint(*id)]);
+    }
+    rs
+}
@@ -9,6 +9,7 @@ use bigdecimal::BigDecimal;
 #[allow(unused_imports)]
 use flexi_logger::{LogSpecification, LoggerHandle};
 use serde::Deserialize;
+use std::str::FromStr;
 
 const SIZE: usize = 20;
 
@@ -19,7 +20,7 @@ pub fn test_special_types() {
     match impl_test_special_types(&mut loghandle) {
         Err(e) => {
             error!("test_special_types() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_special_types() ended successful"),
     }
@@ -39,6 +40,9 @@ fn impl_test_special_types(loghandle: &mut LoggerHandle) -> mock_db::Result<()>
     rs_rows(loghandle)?;
     rs_resultset(loghandle)?;
     rs_single_value(loghandle)?;
+    rs_lossless_decimals(loghandle)?;
+    #[cfg(feature = "bigdecimal")]
+    db_value_into_bigdecimal(loghandle)?;
     Ok(())
 }
 
@@ -89,6 +93,54 @@ fn rs_single_value(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     Ok(())
 }
 
+fn rs_lossless_decimals(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
+    info!("Deserialization of decimals without loss of precision");
+    #[derive(Deserialize)]
+    struct Money {
+        amount: BigDecimal,
+        fee: Option<BigDecimal>,
+    }
+    let mut rs = Resultset::new(&["amount", "fee"]);
+    rs.push(vec![
+        MValue::new_decimal(12_345_678_901_234_567_890_123_456_789, -9),
+        MValue::new_decimal(-1, -20),
+    ]);
+    rs.push(vec![MValue::new_decimal(42, 3), MValue::Null]);
+    let vm: Vec<Money> = rs.try_into()?;
+    assert_eq!(
+        vm[0].amount,
+        BigDecimal::from_str("12345678901234567890.123456789").unwrap()
+    );
+    assert_eq!(
+        vm[0].fee,
+        Some(BigDecimal::from_str("-0.00000000000000000001").unwrap())
+    );
+    assert_eq!(vm[1].amount, BigDecimal::from(42_000));
+    assert_eq!(vm[1].fee, None);
+
+    let value: BigDecimal = MValue::new_decimal(1, -30).try_into()?;
+    assert_eq!(value, BigDecimal::from_str("1e-30").unwrap());
+    let value: f64 = MValue::new_decimal(25, -2).try_into()?;
+    assert!((value - 0.25).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[cfg(feature = "bigdecimal")]
+fn db_value_into_bigdecimal(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
+    use serde_db::de::DbValueInto;
+    info!("Conversion of individual values with DbValueInto<BigDecimal>");
+    let value: BigDecimal =
+        DbValueInto::try_into(MValue::new_decimal(-123_456_789_012_345_678_901, -20)).unwrap();
+    assert_eq!(
+        value,
+        BigDecimal::from_str("-1.23456789012345678901").unwrap()
+    );
+    let value: BigDecimal = DbValueInto::try_into(MValue::Double(0.5)).unwrap();
+    assert_eq!(value, BigDecimal::from_str("0.5").unwrap());
+    assert!(DbValueInto::<BigDecimal>::try_into(MValue::new_string("x".to_string())).is_err());
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////
 fn get_resultset_ooff(len: usize) -> Resultset {
     let mut rs = Resultset::new(&["f1", "f2", "f3", "f4"]);
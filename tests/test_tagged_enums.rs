@@ -0,0 +1,88 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+
+// Single-table inheritance: column "kind" identifies the variant,
+// the other columns fill the variant struct.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind")]
+enum Party {
+    #[serde(rename = "P")]
+    Person {
+        name: String,
+        nickname: Option<String>,
+    },
+    #[serde(rename = "O")]
+    Organization { name: String, city: String },
+}
+
+#[test] // cargo test --test test_tagged_enums -- --nocapture
+pub fn test_tagged_enums() {
+    let _loghandle = util::init_logger();
+    info!("=== Internally tagged enum rows ===");
+
+    info!("Resultset into Vec of enums");
+    let parties: Vec<Party> = get_resultset().try_into().unwrap();
+    debug!("{:?}", parties);
+    assert_eq!(
+        parties,
+        vec![
+            Party::Person {
+                name: "Alice".to_string(),
+                nickname: None,
+            },
+            Party::Organization {
+                name: "ACME".to_string(),
+                city: "Berlin".to_string(),
+            },
+            Party::Person {
+                name: "Bob".to_string(),
+                nickname: Some("Bobby".to_string()),
+            },
+        ]
+    );
+
+    info!("Single row");
+    let party: Party = get_resultset().next().unwrap().try_into().unwrap();
+    assert!(matches!(party, Party::Person { .. }));
+
+    info!("Unknown tag");
+    let mut rs = get_resultset();
+    rs.push(vec![
+        MValue::new_string("X".to_string()),
+        MValue::new_string("?".to_string()),
+        MValue::new_null(),
+        MValue::new_null(),
+    ]);
+    let result: mock_db::Result<Vec<Party>> = rs.try_into();
+    debug!("{:?}", result);
+    assert!(result.is_err());
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["kind", "name", "nickname", "city"]);
+    rs.push(vec![
+        MValue::new_string("P".to_string()),
+        MValue::new_string("Alice".to_string()),
+        MValue::new_null(),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_string("O".to_string()),
+        MValue::new_string("ACME".to_string()),
+        MValue::new_null(),
+        MValue::new_string("Berlin".to_string()),
+    ]);
+    rs.push(vec![
+        MValue::new_string("P".to_string()),
+        MValue::new_string("Bob".to_string()),
+        MValue::new_string("Bobby".to_string()),
+        MValue::new_null(),
+    ]);
+    rs
+}
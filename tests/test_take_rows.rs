@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::DeserializableResultset;
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    name: String,
+}
+
+#[test] // cargo test --test test_take_rows -- --nocapture
+pub fn test_take_rows() {
+    let _loghandle = util::init_logger();
+    info!("=== Taking rows out of a resultset ===");
+
+    info!("take_row()");
+    let mut rs = get_resultset();
+    let row = DeserializableResultset::take_row(&mut rs, 2)
+        .unwrap()
+        .unwrap();
+    let taken: TestData = row.try_into().unwrap();
+    assert_eq!(taken.id, 2);
+    assert!(DeserializableResultset::take_row(&mut rs, 4)
+        .unwrap()
+        .is_none());
+    assert_eq!(DeserializableResultset::remaining_rows(&rs), Some(4));
+
+    let row = DeserializableResultset::take_row(&mut rs, 0)
+        .unwrap()
+        .unwrap();
+    let first: TestData = row.try_into().unwrap();
+    assert_eq!(first.id, 0);
+
+    info!("Conversion of a borrowed resultset");
+    let rest: Vec<TestData> = DeserializableResultset::try_into(&mut rs).unwrap();
+    assert_eq!(rest.iter().map(|d| d.id).collect::<Vec<_>>(), vec![1, 3, 4]);
+    assert_eq!(rest[2].name, "name 4");
+    assert_eq!(DeserializableResultset::remaining_rows(&rs), Some(0));
+
+    info!("Failing conversion leaves the remaining rows intact");
+    let mut rs = get_resultset();
+    rs.push(vec![MValue::new_null(), MValue::new_null()]);
+    rs.push(vec![
+        MValue::new_short(6),
+        MValue::new_string("name 6".to_string()),
+    ]);
+    let result: mock_db::Result<Vec<TestData>> = DeserializableResultset::try_into(&mut rs);
+    assert!(result.is_err());
+    let rest: Vec<TestData> = DeserializableResultset::try_into(&mut rs).unwrap();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].id, 6);
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "name"]);
+    for i in 0..5 {
+        rs.push(vec![
+            MValue::new_short(i),
+            MValue::new_string(format!("name {i}")),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Parameter, ParameterType as PT, Timestamp};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::Serialize;
+use serde_db::ser::{to_named_params, to_params, SerializationError};
+
+#[test] // cargo test --test test_temporal_parameters -- --nocapture
+pub fn test_temporal_parameters() {
+    let _loghandle = util::init_logger();
+    info!("=== temporal parameters ===");
+
+    let date = NaiveDate::from_ymd_opt(2012, 12, 24).unwrap();
+    let ts: NaiveDateTime = date.and_hms_opt(18, 30, 0).unwrap();
+    let utc: DateTime<Utc> = ts.and_utc();
+
+    info!("Parameters with native temporal types get the parsed value");
+    let values: Vec<MValue> = to_params(
+        &(ts, utc, date),
+        &mut [PT::Timestamp, PT::Timestamp, PT::NullableTimestamp].iter(),
+    )
+    .unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::Timestamp(Timestamp(ts)),
+            MValue::Timestamp(Timestamp(ts)),
+            MValue::Timestamp(Timestamp(date.and_time(NaiveTime::MIN))),
+        ]
+    );
+
+    info!("Text parameters can expect a specific format");
+    #[derive(Serialize)]
+    struct Booking {
+        day: NaiveDate,
+        at: NaiveDateTime,
+        note: String,
+    }
+    let parameters = [
+        Parameter::new("day", PT::String).with_temporal_format("%Y%m%d"),
+        Parameter::new("at", PT::String).with_temporal_format("%d.%m.%Y %H:%M"),
+        Parameter::new("note", PT::String).with_temporal_format("%Y%m%d"),
+    ];
+    let booking = Booking {
+        day: date,
+        at: ts,
+        note: "no date".to_string(),
+    };
+    let values: Vec<MValue> = to_named_params(&booking, &mut parameters.iter()).unwrap();
+    assert_eq!(
+        values,
+        vec![
+            MValue::String("20121224".to_string()),
+            MValue::String("24.12.2012 18:30".to_string()),
+            MValue::String("no date".to_string()),
+        ]
+    );
+
+    info!("Formats that do not fit to the value produce an error");
+    let parameters = [Parameter::new("day", PT::String).with_temporal_format("%H:%M")];
+    let result = to_params(&(date,), &mut parameters.iter());
+    debug!("{result:?}");
+    assert!(matches!(
+        result,
+        Err(SerializationError::AtParameter { ref source, .. })
+            if matches!(**source, SerializationError::Type { value_type: "temporal", .. })
+    ));
+
+    info!("Without a temporal encoding, the ISO 8601 string is handed over");
+    let values: Vec<MValue> = to_params(&(date,), &mut [PT::String].iter()).unwrap();
+    assert_eq!(values, vec![MValue::String("2012-12-24".to_string())]);
+}
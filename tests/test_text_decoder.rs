@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{ConversionError, DeserializationOptions, TextDecoder};
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct Greeting {
+    id: i32,
+    text: String,
+}
+
+// Decodes UTF-16 in little-endian byte order.
+struct Utf16Le;
+impl TextDecoder for Utf16Le {
+    fn decode(&self, bytes: &[u8]) -> Result<String, ConversionError> {
+        if !bytes.len().is_multiple_of(2) {
+            return Err(ConversionError::ValueType(
+                "UTF-16 text has an odd number of bytes".to_string(),
+            ));
+        }
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        String::from_utf16(&units).map_err(|e| ConversionError::Other(Box::new(e)))
+    }
+}
+
+#[test] // cargo test --test test_text_decoder -- --nocapture
+pub fn test_text_decoder() {
+    let _loghandle = util::init_logger();
+    info!("=== Text decoder ===");
+
+    info!("Without decoder, latin1 is invalid UTF-8");
+    let result: mock_db::Result<Vec<Greeting>> = get_resultset(b"Gr\xfc\xdfe").try_into();
+    assert!(result.is_err());
+
+    info!("latin1 closure");
+    let latin1 = |bytes: &[u8]| Ok(bytes.iter().copied().map(char::from).collect::<String>());
+    let options = DeserializationOptions::default().with_text_decoder(Arc::new(latin1));
+    let data: Vec<Greeting> = get_resultset(b"Gr\xfc\xdfe")
+        .try_into_with(&options)
+        .unwrap();
+    debug!("{:?}", data);
+    assert_eq!(data[0].id, 1);
+    assert_eq!(data[0].text, "Grüße");
+    assert_eq!(data[1].text, "plain");
+
+    info!("UTF-16 decoder");
+    let options = DeserializationOptions::default().with_text_decoder(Arc::new(Utf16Le));
+    let s: String = MValue::new_raw_text(&[0x48, 0, 0x69, 0, 0x3a, 0xd8, 0x00, 0xde])
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(s, "Hi\u{1ea00}");
+    let result: Result<String, _> = MValue::new_raw_text(b"odd").try_into_with(&options);
+    assert!(result.is_err());
+
+    info!("Other values are not touched");
+    let s: String = MValue::new_string("Grüße".to_string())
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(s, "Grüße");
+}
+
+fn get_resultset(text: &[u8]) -> Resultset {
+    let mut rs = Resultset::new(&["id", "text"]);
+    rs.push(vec![MValue::new_short(1), MValue::new_raw_text(text)]);
+    rs.push(vec![MValue::new_short(2), MValue::new_raw_text(b"plain")]);
+    rs
+}
@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_db::de::{DeserializationOptions, TimezonePolicy};
+
+#[derive(Debug, Deserialize)]
+struct Event {
+    id: i32,
+    #[serde(deserialize_with = "serde_db::de::timezone_aware")]
+    created_at: DateTime<Utc>,
+    #[serde(deserialize_with = "serde_db::de::timezone_aware")]
+    updated_at: DateTime<FixedOffset>,
+    #[serde(deserialize_with = "serde_db::de::timezone_aware")]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[test] // cargo test --test test_timezone_policy -- --nocapture
+pub fn test_timezone_policy() {
+    let _loghandle = util::init_logger();
+    info!("=== Timezone policy ===");
+    let naive = NaiveDate::from_ymd_opt(2024, 2, 29)
+        .unwrap()
+        .and_hms_opt(12, 30, 0)
+        .unwrap();
+
+    info!("Reject");
+    let result: mock_db::Result<Vec<Event>> = get_resultset().try_into();
+    assert!(result.is_err());
+
+    info!("AssumeUtc");
+    let options = DeserializationOptions::default().with_timezone_policy(TimezonePolicy::AssumeUtc);
+    let events: Vec<Event> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", events);
+    assert_eq!(events[0].id, 1);
+    assert_eq!(events[0].created_at, naive.and_utc());
+    assert_eq!(events[0].updated_at, naive.and_utc());
+    assert!(events[0].deleted_at.is_none());
+    assert_eq!(events[1].deleted_at, Some(naive.and_utc()));
+
+    info!("AssumeOffset");
+    let options = DeserializationOptions::default()
+        .with_timezone_policy(TimezonePolicy::AssumeOffset(-5_400));
+    let events: Vec<Event> = get_resultset().try_into_with(&options).unwrap();
+    debug!("{:?}", events);
+    assert_eq!(events[0].updated_at.offset().local_minus_utc(), -5_400);
+    assert_eq!(events[0].updated_at.naive_local(), naive);
+    assert_eq!(
+        events[0].created_at.naive_utc(),
+        naive + chrono::Duration::minutes(90)
+    );
+
+    info!("Single value");
+    #[derive(Deserialize)]
+    struct Wrapper(
+        #[serde(deserialize_with = "serde_db::de::timezone_aware")] DateTime<FixedOffset>,
+    );
+    let options = DeserializationOptions::default().with_timezone_policy(TimezonePolicy::AssumeUtc);
+    let value: Wrapper = MValue::new_ts(naive).try_into_with(&options).unwrap();
+    assert_eq!(value.0, naive.and_utc());
+
+    info!("Values with timezone information are not touched");
+    let value: Wrapper = MValue::new_string("2024-02-29T12:30:00+02:00".to_string())
+        .try_into_with(&options)
+        .unwrap();
+    assert_eq!(value.0.offset().local_minus_utc(), 7_200);
+}
+
+fn get_resultset() -> Resultset {
+    let naive = NaiveDate::from_ymd_opt(2024, 2, 29)
+        .unwrap()
+        .and_hms_opt(12, 30, 0)
+        .unwrap();
+    let mut rs = Resultset::new(&["id", "created_at", "updated_at", "deleted_at"]);
+    rs.push(vec![
+        MValue::new_short(1),
+        MValue::new_ts(naive),
+        MValue::new_ts(naive),
+        MValue::new_null(),
+    ]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_ts(naive),
+        MValue::new_ts(naive),
+        MValue::new_ts(naive),
+    ]);
+    rs
+}
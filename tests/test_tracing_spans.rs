@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+#[test] // cargo test --test test_tracing_spans --features tracing -- --nocapture
+pub fn test_tracing_spans() {
+    let _loghandle = util::init_logger();
+    info!("=== Tracing spans ===");
+
+    #[cfg(feature = "tracing")]
+    spans::resultset_and_row_spans();
+}
+
+#[cfg(feature = "tracing")]
+mod spans {
+    use crate::mock_db::{self, MValue, Resultset};
+    use std::fmt::Debug;
+    use std::sync::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    // Records the opened spans with their fields, and the recorded values.
+    #[derive(Default)]
+    struct SpanRecorder(Mutex<Vec<String>>);
+
+    struct FieldWriter<'a>(&'a mut String);
+    impl Visit for FieldWriter<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push_str(&format!(" {}={value:?}", field.name()));
+        }
+    }
+
+    impl Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut spans = self.0.lock().unwrap();
+            let mut line = span.metadata().name().to_string();
+            span.record(&mut FieldWriter(&mut line));
+            spans.push(line);
+            Id::from_u64(spans.len() as u64)
+        }
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            let idx = usize::try_from(span.into_u64()).unwrap() - 1;
+            values.record(&mut FieldWriter(&mut spans[idx]));
+        }
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &Event<'_>) {}
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    pub fn resultset_and_row_spans() {
+        info!("Spans around the conversion of resultsets and rows");
+        let recorder = std::sync::Arc::new(SpanRecorder::default());
+        let dispatch = tracing::Dispatch::from(std::sync::Arc::clone(&recorder));
+        tracing::dispatcher::with_default(&dispatch, || {
+            let data: Vec<(i16, String)> = get_resultset().try_into().unwrap();
+            assert_eq!(data.len(), 2);
+            let result: mock_db::Result<Vec<(i16, i16)>> = get_resultset().try_into();
+            assert!(result.is_err());
+            let row = get_resultset().next().unwrap();
+            let _: (i16, String) = row.try_into().unwrap();
+        });
+
+        let spans = recorder.0.lock().unwrap();
+        debug!("{spans:#?}");
+        assert_eq!(spans.len(), 6);
+        assert!(spans[0].starts_with("deserialize_resultset target="));
+        assert!(spans[0].ends_with("rows=2 succeeded=true"));
+        assert_eq!(spans[1], "deserialize_row row=0");
+        assert_eq!(spans[2], "deserialize_row row=1");
+        assert!(spans[3].ends_with("rows=1 succeeded=false"));
+        assert_eq!(spans[4], "deserialize_row row=0");
+        assert!(spans[5].starts_with("deserialize_row target=\"(i16, "));
+    }
+
+    fn get_resultset() -> Resultset {
+        let mut rs = Resultset::new(&["id", "name"]);
+        rs.push(vec![
+            MValue::new_short(1),
+            MValue::new_string("a".to_string()),
+        ]);
+        rs.push(vec![
+            MValue::new_short(2),
+            MValue::new_string("b".to_string()),
+        ]);
+        rs
+    }
+}
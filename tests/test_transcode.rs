@@ -0,0 +1,337 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::ser::{self, Serialize};
+use serde_db::de::{
+    transcode, transcode_with, DeserializationOptions, SerializableResultset, SerializableRow,
+};
+
+#[test] // cargo test --test test_transcode -- --nocapture
+pub fn test_transcode() {
+    let _loghandle = util::init_logger();
+    info!("=== transcoding into a serializer ===");
+
+    info!("Rows become maps of their columns");
+    let mut rs = Resultset::new(&["name", "age", "score", "tags"]);
+    rs.push(vec![
+        MValue::new_string("Ann".to_string()),
+        MValue::new_bigint(42),
+        MValue::new_double(1.5),
+        MValue::new_array(vec![MValue::new_short(1), MValue::new_short(2)]),
+    ]);
+    rs.push(vec![
+        MValue::new_string("Bob".to_string()),
+        MValue::new_null(),
+        MValue::new_double(-2.0),
+        MValue::new_array(vec![]),
+    ]);
+    let text = transcode(rs, TextSerializer).unwrap();
+    debug!("{text}");
+    assert_eq!(
+        text,
+        r#"[{"name":"Ann","age":42,"score":1.5,"tags":[1,2]},{"name":"Bob","age":null,"score":-2,"tags":[]}]"#
+    );
+
+    #[cfg(feature = "json")]
+    json_documents();
+
+    info!("Empty resultsets");
+    let text = transcode(Resultset::new(&["x"]), TextSerializer).unwrap();
+    assert_eq!(text, "[]");
+
+    info!("Errors are reported as errors of the serializer");
+    let mut rs = Resultset::new(&["x"]);
+    for i in 0..3 {
+        rs.push(vec![MValue::new_bigint(i)]);
+    }
+    let options = DeserializationOptions::default().with_max_rows(2);
+    let result = transcode_with(rs, TextSerializer, &options);
+    debug!("{result:?}");
+    assert!(result.unwrap_err().0.contains("limit"));
+
+    info!("Serializable wrappers");
+    let mut rs = Resultset::new(&["id", "name"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_string("x".to_string()),
+    ]);
+    rs.push(vec![MValue::new_bigint(2), MValue::new_null()]);
+    let wrapper = SerializableResultset::new(rs.clone());
+    let text = wrapper.serialize(TextSerializer).unwrap();
+    assert_eq!(text, r#"[{"id":1,"name":"x"},{"id":2,"name":null}]"#);
+    assert!(wrapper.serialize(TextSerializer).is_err());
+
+    let row = rs.next().unwrap();
+    let text = SerializableRow::new(row).serialize(TextSerializer).unwrap();
+    assert_eq!(text, r#"{"id":1,"name":"x"}"#);
+
+    let options = DeserializationOptions::default().with_max_rows(1);
+    let wrapper = SerializableResultset::with_options(rs, &options);
+    assert_eq!(
+        wrapper.serialize(TextSerializer).unwrap(),
+        r#"[{"id":2,"name":null}]"#
+    );
+}
+
+#[cfg(feature = "json")]
+fn json_documents() {
+    info!("JSON documents are transcoded as nested values");
+    let mut rs = Resultset::new(&["id", "extra"]);
+    rs.push(vec![
+        MValue::new_bigint(1),
+        MValue::new_json(r#"{"a": [true, null]}"#),
+    ]);
+    rs.push(vec![MValue::new_bigint(2), MValue::new_null()]);
+    let text = transcode(rs, TextSerializer).unwrap();
+    assert_eq!(
+        text,
+        r#"[{"id":1,"extra":{"a":[true,null]}},{"id":2,"extra":null}]"#
+    );
+}
+
+// Writes values as JSON-like text; sufficient for the values that the mock database produces.
+struct TextSerializer;
+
+#[derive(Debug)]
+struct TextError(String);
+impl std::fmt::Display for TextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl std::error::Error for TextError {}
+impl ser::Error for TextError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        TextError(msg.to_string())
+    }
+}
+
+struct Compound {
+    open: Option<char>,
+    close: char,
+    text: String,
+}
+impl Compound {
+    fn new(open: char, close: char) -> Self {
+        Compound {
+            open: Some(open),
+            close,
+            text: String::new(),
+        }
+    }
+    fn push(&mut self, separator: char, value: &impl Serialize) -> Result<(), TextError> {
+        self.text.push(self.open.take().unwrap_or(separator));
+        self.text.push_str(&value.serialize(TextSerializer)?);
+        Ok(())
+    }
+    fn finish(self) -> String {
+        format!(
+            "{}{}",
+            self.open.map(String::from).unwrap_or(self.text),
+            self.close
+        )
+    }
+}
+impl ser::SerializeSeq for Compound {
+    type Ok = String;
+    type Error = TextError;
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), TextError> {
+        self.push(',', &value)
+    }
+    fn end(self) -> Result<String, TextError> {
+        Ok(self.finish())
+    }
+}
+impl ser::SerializeMap for Compound {
+    type Ok = String;
+    type Error = TextError;
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), TextError> {
+        self.push(',', &key)
+    }
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), TextError> {
+        self.push(':', &value)
+    }
+    fn end(self) -> Result<String, TextError> {
+        Ok(self.finish())
+    }
+}
+
+macro_rules! unsupported_compound {
+    ($($trait:ident $method:ident),*) => {$(
+        impl ser::$trait for Compound {
+            type Ok = String;
+            type Error = TextError;
+            fn $method<T: ?Sized + Serialize>(&mut self, _value: &T) -> Result<(), TextError> {
+                Err(TextError("not supported".to_string()))
+            }
+            fn end(self) -> Result<String, TextError> {
+                Err(TextError("not supported".to_string()))
+            }
+        }
+    )*};
+}
+unsupported_compound!(
+    SerializeTuple serialize_element,
+    SerializeTupleStruct serialize_field,
+    SerializeTupleVariant serialize_field
+);
+impl ser::SerializeStruct for Compound {
+    type Ok = String;
+    type Error = TextError;
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        _value: &T,
+    ) -> Result<(), TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn end(self) -> Result<String, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+}
+impl ser::SerializeStructVariant for Compound {
+    type Ok = String;
+    type Error = TextError;
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        _value: &T,
+    ) -> Result<(), TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn end(self) -> Result<String, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+}
+
+impl ser::Serializer for TextSerializer {
+    type Ok = String;
+    type Error = TextError;
+    type SerializeSeq = Compound;
+    type SerializeTuple = Compound;
+    type SerializeTupleStruct = Compound;
+    type SerializeTupleVariant = Compound;
+    type SerializeMap = Compound;
+    type SerializeStruct = Compound;
+    type SerializeStructVariant = Compound;
+
+    fn serialize_bool(self, v: bool) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_i8(self, v: i8) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_i16(self, v: i16) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_i32(self, v: i32) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_i64(self, v: i64) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_u8(self, v: u8) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_u16(self, v: u16) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_u32(self, v: u32) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_u64(self, v: u64) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_f32(self, v: f32) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_f64(self, v: f64) -> Result<String, TextError> {
+        Ok(v.to_string())
+    }
+    fn serialize_char(self, v: char) -> Result<String, TextError> {
+        self.serialize_str(&v.to_string())
+    }
+    fn serialize_str(self, v: &str) -> Result<String, TextError> {
+        Ok(format!("{v:?}"))
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<String, TextError> {
+        Ok(format!("{v:?}"))
+    }
+    fn serialize_none(self) -> Result<String, TextError> {
+        Ok("null".to_string())
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<String, TextError> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<String, TextError> {
+        self.serialize_none()
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, TextError> {
+        self.serialize_none()
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, TextError> {
+        self.serialize_str(variant)
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, TextError> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound, TextError> {
+        Ok(Compound::new('[', ']'))
+    }
+    fn serialize_tuple(self, _len: usize) -> Result<Compound, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound, TextError> {
+        Ok(Compound::new('{', '}'))
+    }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Compound, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound, TextError> {
+        Err(TextError("not supported".to_string()))
+    }
+}
@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializableRow, Row};
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct Details {
+    id: i64,
+    name: String,
+}
+
+#[test] // cargo test --test test_try_to -- --nocapture
+pub fn test_try_to() {
+    let _loghandle = util::init_logger();
+    info!("=== Non-consuming conversions ===");
+
+    info!("Rows");
+    let mut rs = get_resultset(2);
+    let row = rs.next().unwrap();
+    let (id, name): (i64, String) = row.try_to().unwrap();
+    assert_eq!((id, name.as_str()), (1, "name 1"));
+    let details: Details = row.try_to().unwrap();
+    assert_eq!(details.name, "name 1");
+    assert_eq!(row.len(), 2);
+    let details: Details = DeserializableRow::try_into(row).unwrap();
+    assert_eq!(details.id, 1);
+
+    info!("Generic rows");
+    let row: Row<MValue> = Row::new(
+        Arc::new(vec!["id".to_string(), "name".to_string()]),
+        vec![
+            MValue::new_bigint(7),
+            MValue::new_string("seven".to_string()),
+        ],
+    );
+    let details: Details = row.try_to().unwrap();
+    assert_eq!(details.name, "seven");
+    assert_eq!(
+        row.get_by_name("name"),
+        Some(&MValue::new_string("seven".to_string()))
+    );
+
+    info!("Resultsets");
+    let rs = get_resultset(3);
+    let tuples: Vec<(i64, String)> = rs.try_to().unwrap();
+    assert_eq!(tuples.len(), 3);
+    let details: Vec<Details> = DeserializableResultset::try_into(rs).unwrap();
+    assert_eq!(details[2].id, 3);
+    assert_eq!(details[2].name, "name 3");
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "name"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("name {i}")),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::DeserializableResultset;
+
+#[derive(Debug, Deserialize)]
+struct Line {
+    id: i64,
+    amount: i16,
+}
+
+#[test] // cargo test --test test_typed_rows -- --nocapture
+pub fn test_typed_rows() {
+    let _loghandle = util::init_logger();
+    info!("=== Iterator of typed rows ===");
+
+    info!("Chaining combinators");
+    let rows = get_resultset(false).typed_rows::<Line>();
+    assert_eq!(rows.size_hint(), (4, Some(4)));
+    let total = rows
+        .filter(|line| line.as_ref().map_or(true, |line| line.id % 2 == 0))
+        .map(|line| line.map(|line| line.amount))
+        .sum::<mock_db::Result<i16>>()
+        .unwrap();
+    assert_eq!(total, 60);
+
+    info!("Conversion errors are reported per row");
+    let results: Vec<mock_db::Result<Line>> = get_resultset(true).typed_rows().collect();
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok());
+    assert!(results[2].is_err());
+    assert!(results[3].is_ok());
+
+    info!("Collecting into a Result");
+    let result: mock_db::Result<Vec<Line>> = get_resultset(true).typed_rows().collect();
+    assert!(result.is_err());
+
+    #[cfg(feature = "fallible-iterator")]
+    fallible_typed_rows();
+}
+
+#[cfg(feature = "fallible-iterator")]
+fn fallible_typed_rows() {
+    use fallible_iterator::FallibleIterator;
+    info!("Typed rows as FallibleIterator");
+    let rows = get_resultset(false).typed_rows::<Line>();
+    let amounts: Vec<i16> = FallibleIterator::map(rows, |line| Ok(line.amount))
+        .collect()
+        .unwrap();
+    assert_eq!(amounts, vec![10, 20, 30, 40]);
+
+    let mut rows = get_resultset(true).typed_rows::<Line>();
+    assert_eq!(FallibleIterator::size_hint(&rows), (4, Some(4)));
+    let result: mock_db::Result<Vec<Line>> = FallibleIterator::collect(&mut rows);
+    assert!(result.is_err());
+    // the iteration continues after a conversion error
+    assert_eq!(FallibleIterator::next(&mut rows).unwrap().unwrap().id, 4);
+    assert!(FallibleIterator::next(&mut rows).unwrap().is_none());
+}
+
+fn get_resultset(with_bad_row: bool) -> Resultset {
+    let mut rs = Resultset::new(&["id", "amount"]);
+    for id in 1..=4 {
+        let amount = if with_bad_row && id == 3 {
+            MValue::new_null()
+        } else {
+            MValue::new_short(i16::try_from(id * 10).unwrap())
+        };
+        rs.push(vec![MValue::new_bigint(id), amount]);
+    }
+    rs
+}
@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{
+    DeserializableRow, DeserializationError, DeserializationObserver, DeserializationOptions,
+    UnknownColumnPolicy,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Deserialize)]
+struct Narrow {
+    id: i64,
+    name: String,
+}
+
+#[derive(Default)]
+struct UnusedCollector(Mutex<Vec<Vec<String>>>);
+impl DeserializationObserver for UnusedCollector {
+    fn unused_columns(&self, columns: &[&str]) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(columns.iter().map(ToString::to_string).collect());
+    }
+}
+
+#[test] // cargo test --test test_unused_columns -- --nocapture
+pub fn test_unused_columns() {
+    let _loghandle = util::init_logger();
+    info!("=== Unused columns ===");
+
+    info!("Unknown columns are an error by default");
+    let result: mock_db::Result<Vec<Narrow>> = get_resultset(2).try_into();
+    assert!(matches!(
+        result,
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::UnknownField(_)
+        ))
+    ));
+
+    info!("Ignored columns are reported once per resultset");
+    let collector = Arc::new(UnusedCollector::default());
+    let options = DeserializationOptions::default()
+        .with_unknown_column_policy(UnknownColumnPolicy::Ignore)
+        .with_observer(Arc::clone(&collector) as Arc<dyn DeserializationObserver>);
+    let lines: Vec<Narrow> = get_resultset(3).try_into_with(&options).unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[2].name, "name 3");
+    assert_eq!(
+        *collector.0.lock().unwrap(),
+        vec![vec!["remark".to_string(), "created".to_string()]]
+    );
+
+    info!("Rows");
+    let collector = Arc::new(UnusedCollector::default());
+    let options = options.with_observer(Arc::clone(&collector) as Arc<dyn DeserializationObserver>);
+    let mut rs = get_resultset(2);
+    for _ in 0..2 {
+        let row = rs.next().unwrap();
+        let _: Narrow = DeserializableRow::try_into_with(row, &options).unwrap();
+    }
+    assert_eq!(collector.0.lock().unwrap().len(), 2);
+
+    info!("Nothing is reported if all columns are used");
+    let collector = Arc::new(UnusedCollector::default());
+    let options = options.with_observer(Arc::clone(&collector) as Arc<dyn DeserializationObserver>);
+    let _: Vec<(i64, String, String, String)> = get_resultset(2).try_into_with(&options).unwrap();
+    assert!(collector.0.lock().unwrap().is_empty());
+}
+
+fn get_resultset(rows: i64) -> Resultset {
+    let mut rs = Resultset::new(&["id", "remark", "name", "created"]);
+    for i in 1..=rows {
+        rs.push(vec![
+            MValue::new_bigint(i),
+            MValue::new_string(format!("remark {i}")),
+            MValue::new_string(format!("name {i}")),
+            MValue::new_string(format!("created {i}")),
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,91 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{ConversionError, DeserializationError, DeserializationOptions, Utf8Policy};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    text: String,
+}
+
+// Accepts both valid text and raw bytes.
+#[derive(Debug, PartialEq)]
+enum Text {
+    Valid(String),
+    Invalid(Vec<u8>),
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TextVisitor;
+        impl<'de> serde::de::Visitor<'de> for TextVisitor {
+            type Value = Text;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string or bytes")
+            }
+            fn visit_str<E>(self, s: &str) -> Result<Text, E> {
+                Ok(Text::Valid(s.to_string()))
+            }
+            fn visit_string<E>(self, s: String) -> Result<Text, E> {
+                Ok(Text::Valid(s))
+            }
+            fn visit_byte_buf<E>(self, b: Vec<u8>) -> Result<Text, E> {
+                Ok(Text::Invalid(b))
+            }
+        }
+        deserializer.deserialize_string(TextVisitor)
+    }
+}
+
+#[test] // cargo test --test test_utf8_policy -- --nocapture
+pub fn test_utf8_policy() {
+    let _loghandle = util::init_logger();
+    info!("=== UTF-8 policy ===");
+
+    info!("Strict (default): error with byte offset");
+    let result: mock_db::Result<Vec<TestData>> = get_resultset().try_into();
+    debug!("{:?}", result);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(DeserializationError::ConversionError(
+            ConversionError::InvalidUtf8 { valid_up_to },
+        ))) => assert_eq!(valid_up_to, 2),
+        _ => panic!("expected InvalidUtf8"),
+    }
+
+    info!("Lossy: replacement character");
+    let options = DeserializationOptions::default().with_utf8_policy(Utf8Policy::Lossy);
+    let data: Vec<TestData> = get_resultset().try_into_with(&options).unwrap();
+    assert_eq!(data[0].id, 1);
+    assert_eq!(data[0].text, "fine");
+    assert_eq!(data[1].text, "ab\u{fffd}cd");
+
+    info!("Bytes: invalid text is exposed as bytes");
+    let options = DeserializationOptions::default().with_utf8_policy(Utf8Policy::Bytes);
+    let data: Vec<(i32, Text)> = get_resultset().try_into_with(&options).unwrap();
+    assert_eq!(data[0].1, Text::Valid("fine".to_string()));
+    assert_eq!(data[1].1, Text::Invalid(b"ab\xffcd".to_vec()));
+    let result: mock_db::Result<Vec<TestData>> = get_resultset().try_into_with(&options);
+    assert!(result.is_err());
+
+    info!("Single values");
+    let s: String = MValue::new_raw_text(b"ok")
+        .try_into_with(&DeserializationOptions::default())
+        .unwrap();
+    assert_eq!(s, "ok");
+}
+
+fn get_resultset() -> Resultset {
+    let mut rs = Resultset::new(&["id", "text"]);
+    rs.push(vec![MValue::new_short(1), MValue::new_raw_text(b"fine")]);
+    rs.push(vec![
+        MValue::new_short(2),
+        MValue::new_raw_text(b"ab\xffcd"),
+    ]);
+    rs
+}
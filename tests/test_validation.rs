@@ -0,0 +1,91 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultset, DeserializationError};
+use serde_db::message::DefaultMessageFormatter;
+
+#[derive(Debug, Deserialize)]
+struct Booking {
+    start_date: i64,
+    end_date: i64,
+}
+
+fn validate(booking: &Booking) -> Result<(), String> {
+    if booking.end_date >= booking.start_date {
+        Ok(())
+    } else {
+        Err("end_date is before start_date".to_string())
+    }
+}
+
+#[test] // cargo test --test test_validation -- --nocapture
+pub fn test_validation() {
+    let _loghandle = util::init_logger();
+    info!("=== Validation ===");
+
+    info!("Valid rows");
+    let bookings: Vec<Booking> = get_resultset(&[(1, 2), (3, 3), (4, 8)])
+        .try_into_validated(validate)
+        .unwrap();
+    assert_eq!(bookings.len(), 3);
+    assert_eq!(bookings[2].end_date, 8);
+
+    info!("All failures are collected");
+    let result = get_resultset(&[(1, 2), (3, 1), (4, 8), (9, 0)]).try_into_validated(validate);
+    match result {
+        Err(mock_db::Error::DESERIALIZATION(e)) => {
+            let DeserializationError::ValidationFailed(ref failures) = e else {
+                panic!("unexpected error: {e:?}");
+            };
+            assert_eq!(
+                failures.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+                vec![1, 3]
+            );
+            assert_eq!(
+                e.render(&DefaultMessageFormatter),
+                "validation failed for row 1 (end_date is before start_date), \
+                 row 3 (end_date is before start_date)"
+            );
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    info!("Closures with state");
+    let mut checked = 0;
+    let bookings: Vec<Booking> = get_resultset(&[(1, 2)])
+        .try_into_validated(|_: &Booking| {
+            checked += 1;
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!((bookings.len(), checked), (1, 1));
+
+    info!("Conversion errors take precedence");
+    let mut rs = Resultset::new(&["start_date", "end_date"]);
+    rs.push(vec![
+        MValue::new_string("x".to_string()),
+        MValue::new_bigint(1),
+    ]);
+    assert!(matches!(
+        rs.try_into_validated(validate),
+        Err(mock_db::Error::DESERIALIZATION(
+            DeserializationError::ConversionError(_)
+        ))
+    ));
+}
+
+fn get_resultset(dates: &[(i64, i64)]) -> Resultset {
+    let mut rs = Resultset::new(&["start_date", "end_date"]);
+    for (start_date, end_date) in dates {
+        rs.push(vec![
+            MValue::new_bigint(*start_date),
+            MValue::new_bigint(*end_date),
+        ]);
+    }
+    rs
+}
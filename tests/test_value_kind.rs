@@ -0,0 +1,46 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum Scalar {
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Null(Option<()>),
+}
+
+#[test] // cargo test --test test_value_kind -- --nocapture
+pub fn test_value_kind() {
+    let _loghandle = util::init_logger();
+    info!("=== Value kinds for deserialize_any() ===");
+
+    let mut rs = Resultset::new(&["short", "big", "double", "text", "empty"]);
+    rs.push(vec![
+        MValue::new_short(7),
+        MValue::new_bigint(-12_345_678_901),
+        MValue::new_double(2.5),
+        MValue::new_string("7".to_string()),
+        MValue::new_null(),
+    ]);
+
+    info!("Untagged enums get numbers as numbers, and text as text");
+    let row: BTreeMap<String, Scalar> = rs.clone().try_into().unwrap();
+    assert_eq!(row["short"], Scalar::Int(7));
+    assert_eq!(row["big"], Scalar::Int(-12_345_678_901));
+    assert_eq!(row["double"], Scalar::Float(2.5));
+    assert_eq!(row["text"], Scalar::Text("7".to_string()));
+    assert_eq!(row["empty"], Scalar::Null(None));
+
+    info!("Tuples of untagged enums work as well");
+    let row: (Scalar, Scalar, Scalar, Scalar, Scalar) = rs.try_into().unwrap();
+    assert_eq!(row.0, Scalar::Int(7));
+    assert_eq!(row.2, Scalar::Float(2.5));
+}
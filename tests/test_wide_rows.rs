@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, Resultset};
+
+type Wide = (
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    String,
+);
+
+#[test] // cargo test --test test_wide_rows -- --nocapture
+pub fn test_wide_rows() {
+    let _loghandle = util::init_logger();
+    info!("=== Wide rows ===");
+
+    info!("Tuples with 16 elements");
+    let rows: Vec<Wide> = get_resultset(16, 2).try_into().unwrap();
+    assert_eq!(rows[1].0, 100);
+    assert_eq!(rows[1].14, 114);
+    assert_eq!(rows[1].15, "115");
+
+    info!("Wider rows into arrays");
+    let rows: Vec<[String; 24]> = get_resultset(24, 2).try_into().unwrap();
+    assert_eq!(rows[1][23], "123");
+
+    info!("Rows of any width into a Vec");
+    let mut rs = get_resultset(40, 1);
+    let row: Vec<String> = rs.next().unwrap().try_into().unwrap();
+    assert_eq!(row.len(), 40);
+    assert_eq!(row[39], "39");
+}
+
+// Creates a resultset whose values are strings, except for the first 15 columns of wide rows,
+// and whose value in row r and column c is 100 * r + c.
+fn get_resultset(columns: usize, rows: i64) -> Resultset {
+    let names: Vec<&'static str> = (0..columns)
+        .map(|c| &*Box::leak(format!("C{c}").into_boxed_str()))
+        .collect();
+    let mut rs = Resultset::new(&names);
+    for r in 0..rows {
+        rs.push(
+            (0..columns)
+                .map(|c| {
+                    let value = 100 * r + i64::try_from(c).unwrap();
+                    if columns == 16 && c < 15 {
+                        MValue::new_bigint(value)
+                    } else {
+                        MValue::new_string(value.to_string())
+                    }
+                })
+                .collect(),
+        );
+    }
+    rs
+}